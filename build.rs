@@ -1,7 +1,14 @@
 use std::path::PathBuf;
+use std::process::Command;
 
 fn main() {
 	let path: PathBuf = ["tree-sitter-lucent", "src"].iter().collect();
 	cc::Build::new().include(&path).file(path.join("parser.c"))
 		.file(path.join("scanner.c")).compile("tree-sitter-lucent");
+
+	let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+	let version = Command::new(rustc).arg("--version").output().ok()
+		.and_then(|output| String::from_utf8(output.stdout).ok())
+		.unwrap_or_default();
+	println!("cargo:rustc-env=LUCENT_RUSTC={}", version.trim());
 }
@@ -0,0 +1,30 @@
+use crate::context::Context;
+use crate::error::Diagnostic;
+use crate::node::{Function, Value, ValueIndex, ValueNode};
+
+/// Verifies static variables annotated
+/// with `@constant` are never written to.
+pub fn constants(context: &Context, function: &Function) -> crate::Result<()> {
+	let value = &function.value;
+	value.values.iter().filter_map(|node| match &node.node {
+		ValueNode::Set(target, _) => Some(target),
+		ValueNode::Compound(_, target, _) => Some(target),
+		_ => None,
+	}).map(|target| self::target(context, value, target))
+		.filter(Result::is_err).last().unwrap_or(Ok(()))
+}
+
+fn target(context: &Context, value: &Value, index: &ValueIndex) -> crate::Result<()> {
+	let span = &value[*index].span;
+	match &value[*index].node {
+		ValueNode::Field(index, _) => target(context, value, index),
+		ValueNode::Index(index, _) => target(context, value, index),
+		ValueNode::Path(path) => match context.statics.get(path) {
+			Some(variable) if variable.constant(context)? => context.pass(Diagnostic::error()
+				.message("cannot write to constant static variable").label(span.label())
+				.label(variable.identifier.span.other().with_message("declared here"))),
+			_ => Ok(()),
+		},
+		_ => Ok(()),
+	}
+}
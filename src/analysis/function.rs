@@ -6,6 +6,9 @@ use crate::span::Span;
 pub fn function(context: &Context, parent: Option<Key>, path: &FunctionPath,
 				function: &Function, span: Option<Span>) -> crate::Result<()> {
 	let key = Key::Analyze(path.clone());
-	context.unit.scope(parent, key, span, ||
-		super::loops(context, function)).map(std::mem::drop)
+	context.unit.scope(parent, key, span, || {
+		let loops = super::loops(context, function);
+		let constants = super::constants(context, function);
+		loops.and(constants)
+	}).map(std::mem::drop)
 }
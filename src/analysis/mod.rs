@@ -2,8 +2,10 @@
 //! Analysis occurs before type inference so no
 //! pass may use type information for verification.
 
+pub use constant::*;
 pub use function::*;
 pub use loops::*;
 
+mod constant;
 mod function;
 mod loops;
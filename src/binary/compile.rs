@@ -1,21 +1,45 @@
+use std::convert::TryFrom;
 use std::fs::File;
 use std::io::Write;
 
 use crate::context::Context;
 use crate::error::Diagnostic;
+use crate::node::{Identifier, ValueNode};
+use crate::node::address::Address;
 
 pub fn compile(context: &Context) -> crate::Result<()> {
 	// TODO: derive from global annotation
 	let path = "binary.bin";
 
 	// TODO: verify no overlaps
-	let mut entries = super::entries(context);
-	super::patch(context, &mut entries);
+	let (mut entries, strings) = loop {
+		let mut entries = super::entries(context);
+		let strings = super::strings(&entries);
+		let far = super::patch(context, &mut entries, &strings);
+		if crate::context::failed(context) {
+			return Err(crate::query::QueryError::Failure);
+		} else if far.is_empty() {
+			break (entries, strings);
+		}
+
+		// Calls to functions out of range are lowered as absolute
+		// calls which changes the size and address of every symbol.
+		context.far.write().extend(far);
+		context.sections.clear();
+		context.address.clear();
+	};
+
+	crate::node::regions(context)?;
+	crate::node::assertions(context)?;
+	entries.extend(strings.entry());
+	let relocations = super::relocations(context, &entries)?;
 	if crate::context::failed(context) {
 		return Err(crate::query::QueryError::Failure);
 	}
 
-	let segments = super::segments(entries);
+	let mut segments = super::segments(entries);
+	let entry = super::startup(context, &mut segments,
+		entry(context)?, relocations.as_deref())?;
 	for segment in &segments {
 		match &segment.kind {
 			super::SegmentKind::Text(data) => {
@@ -28,8 +52,10 @@ pub fn compile(context: &Context) -> crate::Result<()> {
 		}
 	}
 
-	// TODO: derive format from global annotation
-	let data = super::format::mach::compile(segments);
+	let data = match format(context)? {
+		Format::Mach => super::format::mach::compile(segments, entry, relocations),
+		Format::Elf => super::format::elf::compile(segments, entry, relocations),
+	};
 
 	let data = data.map_err(|error| context.error(Diagnostic::error()
 		.message("failed to compile binary").note(format!("error: {}", error))))?;
@@ -38,3 +64,38 @@ pub fn compile(context: &Context) -> crate::Result<()> {
 			.message("failed to write binary to file")
 			.note(format!("error: {}", error))))
 }
+
+/// The file format of the compiled binary.
+enum Format {
+	Mach,
+	Elf,
+}
+
+fn format(context: &Context) -> crate::Result<Format> {
+	let identifier = Identifier("binary".to_string());
+	let annotation = match context.annotations.get(&identifier) {
+		Some(annotation) => annotation,
+		None => return Ok(Format::Mach),
+	};
+
+	let value = &annotation.node;
+	match &value[value.root].node {
+		ValueNode::String(format) if format == "mach-o" => Ok(Format::Mach),
+		ValueNode::String(format) if format == "elf" => Ok(Format::Elf),
+		_ => context.pass(Diagnostic::error().label(annotation.span.label())
+			.message("invalid binary format")
+			.note("expected: \"mach-o\" or \"elf\"")),
+	}
+}
+
+fn entry(context: &Context) -> crate::Result<Address> {
+	let identifier = Identifier("entry".to_string());
+	let annotation = context.annotations.get(&identifier).ok_or_else(||
+		context.error(Diagnostic::error().message("missing entry point")
+			.note("add a global annotation: @@entry <address>")))?;
+	let value = &annotation.node;
+	let entry = crate::evaluate::integral(context, None, value, &value.root)?;
+	Address::try_from(entry).map_err(|_| context.error(Diagnostic::error()
+		.message(format!("invalid entry point: {}", entry))
+		.label(annotation.span.label())))
+}
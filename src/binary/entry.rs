@@ -8,32 +8,58 @@ pub struct Entry {
 	pub load: Address,
 	pub address: Address,
 	pub size: SymbolSize,
+	pub symbol: Option<Symbol>,
+	pub section: Option<String>,
 	pub entity: Entity,
 }
 
 #[derive(Debug)]
 pub enum Entity {
 	Function(Section),
-	Variable(Option<Vec<u8>>),
+	Variable(Option<Section>),
+	Constant(Section),
 }
 
 #[derive(Debug)]
 pub struct Segment {
+	pub load: Address,
 	pub address: Address,
+	pub name: Option<String>,
 	pub kind: SegmentKind,
 }
 
+impl Segment {
+	pub fn size(&self) -> usize {
+		match &self.kind {
+			SegmentKind::Text(data) | SegmentKind::Data(data) |
+			SegmentKind::Constant(data) => data.iter().map(Vec::len).sum(),
+			SegmentKind::Reserve(size) => *size,
+		}
+	}
+}
+
 #[derive(Debug)]
 pub enum SegmentKind {
 	Text(Vec<Vec<u8>>),
 	Data(Vec<Vec<u8>>),
+	Constant(Vec<Vec<u8>>),
 	Reserve(usize),
 }
 
 pub fn entries(context: &Context) -> Vec<Entry> {
 	let mut entries = Vec::new();
-	context.items.read().iter().for_each(|item|
-		std::mem::drop(entry(context, &mut entries, item)));
+	let items = context.items.read();
+	let functions: Vec<_> = items.iter().filter_map(|item| match item {
+		Item::Symbol(Symbol::Function(path)) => Some(path),
+		_ => None,
+	}).collect();
+
+	std::mem::drop(crate::context::parallel(context, &functions, |path| std::mem::drop((|| {
+		if !crate::node::present(context, None, path, None)? { return Ok(()); }
+		crate::generate::x86::lower(context, None, path, None).map(std::mem::drop)
+	})())));
+
+	items.iter().for_each(|item| std::mem::drop(entry(context, &mut entries, item)));
 	entries
 }
 
@@ -47,46 +73,68 @@ fn entry(context: &Context, entries: &mut Vec<Entry>,
 			let load = crate::node::address::load(context, None, symbol, None)?;
 			let size = crate::node::address::size(context, None, symbol, None)?;
 			let entity = Entity::Function(section.as_ref().clone());
-			Ok(entries.push(Entry { load, address, size, entity }))
+			let section = crate::node::address::section(context, None, symbol, None)?;
+			let symbol = Some(symbol.clone());
+			Ok(entries.push(Entry { load, address, size, symbol, section, entity }))
+		}
+		Item::Symbol(symbol @ Symbol::Variable(path)) => {
+			let variable = context.statics.get(path).unwrap();
+			let node_type = crate::inference::type_variable(context, None, path.clone(), None)?;
+			let data = variable.value.as_ref().map(|value| crate::evaluate::bytes(context,
+				None, value, &value.root, &node_type.node)).transpose()?;
+
+			let address = crate::node::address::start(context, None, symbol, None)?;
+			let load = crate::node::address::load(context, None, symbol, None)?;
+			let size = crate::node::address::size(context, None, symbol, None)?;
+			let section = crate::node::address::section(context, None, symbol, None)?;
+			let entity = match variable.constant(context)? {
+				true => Entity::Constant(data.unwrap_or_else(||
+					Section { bytes: vec![0; size], ..Section::default() })),
+				false => Entity::Variable(data),
+			};
+
+			let symbol = Some(symbol.clone());
+			Ok(entries.push(Entry { load, address, size, symbol, section, entity }))
 		}
-		Item::Symbol(Symbol::Variable(_)) => unimplemented!(),
 		Item::Symbol(Symbol::Module(_)) => Ok(()),
-		Item::ModuleEnd => Ok(()),
+		Item::Import(_) | Item::ModuleEnd => Ok(()),
 	}
 }
 
 pub fn segments(mut entries: Vec<Entry>) -> Vec<Segment> {
 	let mut segments = Vec::new();
 	let segment: &mut Option<Segment> = &mut None;
-	let mut last_address: Option<Address> = None;
+	let mut last: Option<(Address, Address, Option<String>)> = None;
 
 	entries.sort_unstable_by_key(|entry| entry.load);
-	for Entry { load: address, size, entity, .. } in entries {
+	for Entry { load, address, size, section: name, entity, .. } in entries {
 		let kind = segment.as_mut().map(|segment| &mut segment.kind);
+		let adjacent = last == Some((load, address, name.clone()));
 		let push = &mut |segment: &mut Option<Segment>, kind| {
 			if let Some(segment) = segment.take() { segments.push(segment); }
-			*segment = Some(Segment { address, kind });
+			*segment = Some(Segment { load, address, name: name.clone(), kind });
 		};
 
 		match entity {
 			Entity::Function(section) => match kind {
-				Some(SegmentKind::Text(sections)) if last_address ==
-					Some(address) => sections.push(section.bytes),
+				Some(SegmentKind::Text(sections)) if adjacent => sections.push(section.bytes),
 				_ => push(segment, SegmentKind::Text(vec![section.bytes]))
 			},
 			Entity::Variable(Some(other)) => match kind {
-				Some(SegmentKind::Data(data)) if last_address ==
-					Some(address) => data.push(other),
-				_ => push(segment, SegmentKind::Data(vec![other])),
+				Some(SegmentKind::Data(data)) if adjacent => data.push(other.bytes),
+				_ => push(segment, SegmentKind::Data(vec![other.bytes])),
+			},
+			Entity::Constant(other) => match kind {
+				Some(SegmentKind::Constant(data)) if adjacent => data.push(other.bytes),
+				_ => push(segment, SegmentKind::Constant(vec![other.bytes])),
 			},
 			Entity::Variable(None) => match kind {
-				Some(SegmentKind::Reserve(reserve)) if last_address ==
-					Some(address) => *reserve += size,
+				Some(SegmentKind::Reserve(reserve)) if adjacent => *reserve += size,
 				_ => push(segment, SegmentKind::Reserve(size)),
 			}
 		}
 
-		last_address = Some(address + size);
+		last = Some((load + size, address + size, name));
 	}
 
 	segments.extend(segment.take());
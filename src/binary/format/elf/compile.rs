@@ -0,0 +1,146 @@
+use std::io;
+
+use goblin::elf::header::{EI_CLASS, EI_DATA, EI_VERSION, ELFCLASS64, ELFDATA2LSB,
+	ELFMAG, EM_X86_64, ET_DYN, ET_EXEC, EV_CURRENT, SELFMAG};
+use goblin::elf::program_header::{PF_R, PF_W, PF_X, PT_DYNAMIC, PT_LOAD};
+use goblin::elf64::header::{Header, SIZEOF_EHDR};
+use goblin::elf64::program_header::{ProgramHeader, SIZEOF_PHDR};
+use scroll::Pwrite;
+
+use crate::binary::{Segment, SegmentKind};
+use crate::node::address::Address;
+use crate::other::ceiling;
+
+// TODO: derive from architecture
+pub const PAGE_SIZE: usize = 4096;
+
+/// Compiles segments into an executable. Position independent executables
+/// include a dynamic segment with a relative relocation for each address.
+/// No interpreter is requested as the startup routine applies the relocations.
+/// Segments that contain relocations are writable.
+pub fn compile(segments: Vec<Segment>, entry: Address,
+			   relocations: Option<Vec<Address>>) -> io::Result<Vec<u8>> {
+	let dynamic = relocations.as_ref().map(|relocations|
+		super::dynamic(&segments, relocations)).transpose()?;
+	let count = segments.len() + dynamic.as_ref().map(|_| 2).unwrap_or(0);
+	let mut offset = SIZEOF_EHDR + count * SIZEOF_PHDR;
+	let mut headers = Vec::new();
+	let mut bytes = Vec::new();
+
+	let end = segments.iter().map(|segment| segment.load + segment.size()).max();
+	for segment in segments {
+		fill_page(&mut offset, &mut bytes, segment.load);
+		let (size, file_size) = (segment.size(), match &segment.kind {
+			SegmentKind::Reserve(_) => 0,
+			_ => segment.size(),
+		});
+
+		let relocated = relocations.iter().flatten().any(|address|
+			segment.load <= *address && *address < segment.load + size);
+		headers.push(ProgramHeader {
+			p_type: PT_LOAD,
+			p_flags: match segment.kind {
+				SegmentKind::Text(_) => PF_R | PF_X,
+				SegmentKind::Data(_) | SegmentKind::Reserve(_) => PF_R | PF_W,
+				SegmentKind::Constant(_) => PF_R,
+			} | if relocated { PF_W } else { 0 },
+			p_offset: offset as u64,
+			p_vaddr: segment.load as u64,
+			p_paddr: segment.load as u64,
+			p_filesz: file_size as u64,
+			p_memsz: size as u64,
+			p_align: PAGE_SIZE as u64,
+		});
+
+		match segment.kind {
+			SegmentKind::Text(data) | SegmentKind::Data(data) |
+			SegmentKind::Constant(data) => data.iter().for_each(|data| bytes.extend(data)),
+			SegmentKind::Reserve(_) => (),
+		}
+		offset += file_size;
+	}
+
+	if let Some(dynamic) = &dynamic {
+		let address = ceiling(end.unwrap_or_default(), PAGE_SIZE);
+		fill_page(&mut offset, &mut bytes, address);
+		let table = dynamic.table(address as u64);
+		let size = table.len() as u64;
+		headers.push(ProgramHeader {
+			p_type: PT_LOAD,
+			p_flags: PF_R | PF_W,
+			p_offset: offset as u64,
+			p_vaddr: address as u64,
+			p_paddr: address as u64,
+			p_filesz: size,
+			p_memsz: size,
+			p_align: PAGE_SIZE as u64,
+		});
+
+		let start = dynamic.entries_offset() as u64;
+		headers.push(ProgramHeader {
+			p_type: PT_DYNAMIC,
+			p_flags: PF_R | PF_W,
+			p_offset: offset as u64 + start,
+			p_vaddr: address as u64 + start,
+			p_paddr: address as u64 + start,
+			p_filesz: size - start,
+			p_memsz: size - start,
+			p_align: 8,
+		});
+
+		bytes.extend(table);
+	}
+
+	let mut identifier = [0; 16];
+	identifier[..SELFMAG].copy_from_slice(ELFMAG);
+	identifier[EI_CLASS] = ELFCLASS64;
+	identifier[EI_DATA] = ELFDATA2LSB;
+	identifier[EI_VERSION] = EV_CURRENT;
+
+	let header = Header {
+		e_ident: identifier,
+		e_type: match dynamic.is_some() {
+			true => ET_DYN,
+			false => ET_EXEC,
+		},
+		e_machine: EM_X86_64,
+		e_version: EV_CURRENT as u32,
+		e_entry: entry as u64,
+		e_phoff: SIZEOF_EHDR as u64,
+		e_shoff: 0,
+		e_flags: 0,
+		e_ehsize: SIZEOF_EHDR as u16,
+		e_phentsize: SIZEOF_PHDR as u16,
+		e_phnum: headers.len() as u16,
+		e_shentsize: 0,
+		e_shnum: 0,
+		e_shstrndx: 0,
+	};
+
+	let mut target = vec![0; SIZEOF_EHDR + headers.len() * SIZEOF_PHDR];
+	target.pwrite_with(header, 0, scroll::LE).map_err(invalid)?;
+	for (index, header) in headers.into_iter().enumerate() {
+		let offset = SIZEOF_EHDR + index * SIZEOF_PHDR;
+		target.pwrite_with(header, offset, scroll::LE).map_err(invalid)?;
+	}
+
+	target.extend(bytes);
+	Ok(target)
+}
+
+/// Pads the file such that the offset is congruent
+/// to the address modulo the page size.
+fn fill_page(offset: &mut usize, bytes: &mut Vec<u8>, address: Address) {
+	let target = ceiling(*offset, PAGE_SIZE) + address % PAGE_SIZE;
+	let target = match target.checked_sub(PAGE_SIZE) {
+		Some(previous) if previous >= *offset => previous,
+		_ => target,
+	};
+
+	bytes.resize(bytes.len() + (target - *offset), 0);
+	*offset = target;
+}
+
+fn invalid(error: impl ToString) -> io::Error {
+	io::Error::new(io::ErrorKind::InvalidInput, error.to_string())
+}
@@ -0,0 +1,76 @@
+use std::io;
+
+use goblin::elf::dynamic::{DT_NULL, DT_RELA, DT_RELAENT, DT_RELASZ};
+use goblin::elf::reloc::R_X86_64_RELATIVE;
+use goblin::elf64::dynamic::{Dyn, SIZEOF_DYN};
+use goblin::elf64::reloc::{Rela, SIZEOF_RELA};
+use scroll::Pwrite;
+
+use crate::binary::{Segment, SegmentKind};
+use crate::node::address::Address;
+
+/// The relocations and dynamic entries of a position independent executable.
+#[derive(Debug)]
+pub struct Dynamic {
+	relocations: Vec<Rela>,
+}
+
+impl Dynamic {
+	/// Returns the offset of the dynamic entries within the table.
+	pub fn entries_offset(&self) -> usize {
+		self.relocations.len() * SIZEOF_RELA
+	}
+
+	/// Encodes the relocations followed by the
+	/// dynamic entries for a table at the address.
+	pub fn table(&self, address: u64) -> Vec<u8> {
+		let size = self.entries_offset() as u64;
+		let entries = vec![(DT_RELA, address), (DT_RELASZ, size),
+			(DT_RELAENT, SIZEOF_RELA as u64), (DT_NULL, 0)];
+
+		let mut bytes = vec![0; self.entries_offset() + entries.len() * SIZEOF_DYN];
+		for (index, relocation) in self.relocations.iter().enumerate() {
+			bytes.pwrite_with(*relocation, index * SIZEOF_RELA, scroll::LE).unwrap();
+		}
+
+		let offset = self.entries_offset();
+		for (index, (d_tag, d_val)) in entries.into_iter().enumerate() {
+			let entry = Dyn { d_tag, d_val };
+			bytes.pwrite_with(entry, offset + index * SIZEOF_DYN, scroll::LE).unwrap();
+		}
+		bytes
+	}
+}
+
+/// Creates a relative relocation for the pointer at each address.
+/// The addend is the address that the pointer currently holds.
+pub fn dynamic(segments: &[Segment], relocations: &[Address]) -> io::Result<Dynamic> {
+	let mut dynamic = Dynamic { relocations: Vec::new() };
+	for address in relocations {
+		let segment = segments.iter().find(|segment| segment.load <= *address &&
+			*address < segment.load + segment.size()).ok_or_else(|| invalid(format!(
+			"relocation at: {:#x}, is not within a segment", address)))?;
+
+		let data: Vec<u8> = match &segment.kind {
+			SegmentKind::Text(data) | SegmentKind::Data(data) |
+			SegmentKind::Constant(data) => data.iter().flatten().cloned().collect(),
+			SegmentKind::Reserve(_) => return Err(invalid(format!(
+				"relocation at: {:#x}, is within a reserved segment", address))),
+		};
+
+		let offset = address - segment.load;
+		let mut pointer = [0; 8];
+		pointer.copy_from_slice(data.get(offset..offset + 8).ok_or_else(||
+			invalid(format!("relocation at: {:#x}, exceeds its segment", address)))?);
+		dynamic.relocations.push(Rela {
+			r_offset: *address as u64,
+			r_info: R_X86_64_RELATIVE as u64,
+			r_addend: i64::from_le_bytes(pointer),
+		});
+	}
+	Ok(dynamic)
+}
+
+fn invalid(message: String) -> io::Error {
+	io::Error::new(io::ErrorKind::InvalidInput, message)
+}
@@ -0,0 +1,5 @@
+pub use compile::*;
+pub use dynamic::*;
+
+mod compile;
+mod dynamic;
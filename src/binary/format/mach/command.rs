@@ -1,4 +1,4 @@
-use std::io::{self, Cursor};
+use std::io::{self, Cursor, Write};
 
 use goblin::mach::{constants, load_command};
 use scroll::{IOwrite, Pwrite, SizeWith};
@@ -44,6 +44,8 @@ pub struct LoadCommands {
 	pub header: load_command::SegmentCommand64,
 	pub segments: Vec<load_command::SegmentCommand64>,
 	pub thread: UnixThreadCommand,
+	pub link: Option<load_command::SegmentCommand64>,
+	pub dyld: Option<load_command::DyldInfoCommand>,
 }
 
 impl LoadCommands {
@@ -51,7 +53,19 @@ impl LoadCommands {
 		let zero = BinarySegment::default().name(b"__PAGEZERO").size(PAGE_SIZE as u64).build();
 		let header = BinarySegment::default().name(b"__TEXT").address(PAGE_SIZE as u64)
 			.protections(constants::VM_PROT_EXECUTE | constants::VM_PROT_READ).build();
-		LoadCommands { zero, header, segments, thread }
+		LoadCommands { zero, header, segments, thread, link: None, dyld: None }
+	}
+
+	/// Adds the commands that describe the rebase information.
+	/// The offsets and sizes are assigned after the layout.
+	pub fn rebase(&mut self) {
+		self.link = Some(BinarySegment::default().name(b"__LINKEDIT")
+			.protections(constants::VM_PROT_READ).build());
+		self.dyld = Some(load_command::DyldInfoCommand {
+			cmd: load_command::LC_DYLD_INFO_ONLY,
+			cmdsize: load_command::SIZEOF_DYLIB_INFO_COMMAND as u32,
+			..load_command::DyldInfoCommand::default()
+		});
 	}
 
 	pub fn size_count(&self) -> (u32, usize) {
@@ -59,12 +73,21 @@ impl LoadCommands {
 			.chain(std::iter::once(self.header.cmdsize))
 			.chain(std::iter::once(self.thread.command_size))
 			.chain(self.segments.iter().map(|command| command.cmdsize))
+			.chain(self.link.iter().map(|command| command.cmdsize))
+			.chain(self.dyld.iter().map(|command| command.cmdsize))
 			.fold((0, 0), |(size, count), command_size| (size + command_size, count + 1))
 	}
 
 	pub fn write(self, target: &mut Cursor<Vec<u8>>) -> io::Result<()> {
 		Iterator::chain(std::array::IntoIter::new([self.zero, self.header]),
-			self.segments).try_for_each(|segment| target.iowrite(segment))?;
-		target.iowrite(self.thread)
+			self.segments).chain(self.link).try_for_each(|segment| target.iowrite(segment))?;
+		target.iowrite(self.thread)?;
+		if let Some(dyld) = self.dyld {
+			let mut buffer = [0; load_command::SIZEOF_DYLIB_INFO_COMMAND];
+			buffer.pwrite(dyld, 0).map_err(|error|
+				io::Error::new(io::ErrorKind::Other, error.to_string()))?;
+			target.write_all(&buffer)?;
+		}
+		Ok(())
 	}
 }
@@ -4,6 +4,7 @@ use goblin::mach::{constants, header, load_command};
 use scroll::IOwrite;
 
 use crate::binary::{Segment, SegmentKind};
+use crate::node::address::Address;
 use crate::other::ceiling;
 
 use super::{BinarySegment, LoadCommands, UnixThreadCommand};
@@ -11,11 +12,23 @@ use super::{BinarySegment, LoadCommands, UnixThreadCommand};
 // TODO: derive from architecture
 pub const PAGE_SIZE: usize = 4096;
 
-pub fn compile(segments: Vec<Segment>) -> io::Result<Vec<u8>> {
-	let entry = 1024 * 1024; // TODO: derive from annotation
-	let thread = UnixThreadCommand::new(entry);
-	let binary_segments = self::segments(&segments);
+/// Compiles segments into an executable. Position independent
+/// executables include rebase information for the relocations.
+/// The dynamic linker is not requested as the startup routine
+/// applies the relocations.
+pub fn compile(segments: Vec<Segment>, entry: Address,
+			   relocations: Option<Vec<Address>>) -> io::Result<Vec<u8>> {
+	let thread = UnixThreadCommand::new(entry as u64);
+	let binary_segments = self::segments(&segments, relocations.as_deref())?;
+	let rebase = relocations.map(|relocations|
+		super::rebase(&segments, &relocations)).transpose()?;
+	let end = segments.iter().map(|segment| segment.load + segment.size()).max();
+
 	let mut commands = LoadCommands::new(binary_segments, thread);
+	if rebase.is_some() {
+		commands.rebase();
+	}
+
 	let (size, command_count) = commands.size_count();
 
 	let mut bytes = Vec::new();
@@ -30,7 +43,8 @@ pub fn compile(segments: Vec<Segment>) -> io::Result<Vec<u8>> {
 		binary_segment.fileoff = offset as u64;
 
 		match segment.kind {
-			SegmentKind::Text(data) | SegmentKind::Data(data) => data
+			SegmentKind::Text(data) | SegmentKind::Data(data) |
+			SegmentKind::Constant(data) => data
 				.into_iter().try_for_each(|data| bytes.write(&data)
 				.map(|bytes| offset += bytes))?,
 			SegmentKind::Reserve(_) => (),
@@ -38,6 +52,21 @@ pub fn compile(segments: Vec<Segment>) -> io::Result<Vec<u8>> {
 	}
 
 	fill_page(&mut offset, &mut bytes);
+	if let Some(rebase) = &rebase {
+		let link = commands.link.as_mut().unwrap();
+		link.vmaddr = ceiling(end.unwrap_or_default(), PAGE_SIZE) as u64;
+		link.vmsize = ceiling(rebase.len(), PAGE_SIZE) as u64;
+		link.fileoff = offset as u64;
+		link.filesize = rebase.len() as u64;
+
+		let dyld = commands.dyld.as_mut().unwrap();
+		dyld.rebase_off = offset as u32;
+		dyld.rebase_size = rebase.len() as u32;
+		bytes.write_all(rebase)?;
+		offset += rebase.len();
+		fill_page(&mut offset, &mut bytes);
+	}
+
 	let mut target = Cursor::new(Vec::new());
 	target.iowrite(header::Header {
 		magic: header::MH_MAGIC_64,
@@ -46,7 +75,10 @@ pub fn compile(segments: Vec<Segment>) -> io::Result<Vec<u8>> {
 		filetype: header::MH_EXECUTE,
 		ncmds: command_count,
 		sizeofcmds: size,
-		flags: header::MH_NOUNDEFS,
+		flags: match rebase.is_some() {
+			true => header::MH_NOUNDEFS | header::MH_PIE,
+			false => header::MH_NOUNDEFS,
+		},
 		reserved: 0,
 	})?;
 
@@ -61,22 +93,47 @@ fn fill_page(offset: &mut usize, bytes: &mut Vec<u8>) {
 	*offset += padding;
 }
 
-fn segments(segments: &[Segment]) -> Vec<load_command::SegmentCommand64> {
-	segments.iter().map(|segment| match &segment.kind {
+/// Segments that contain relocations are writable
+/// as the startup routine applies the relocations.
+fn segments(segments: &[Segment], relocations: Option<&[Address]>)
+			-> io::Result<Vec<load_command::SegmentCommand64>> {
+	segments.iter().map(|segment| Ok(match &segment.kind {
 		SegmentKind::Text(data) => {
 			let size = data.iter().map(Vec::len).sum::<usize>() as u64;
-			BinarySegment::default().name(b"__TEXT")
-				.address(segment.address as u64).size(size).file_size(size)
+			BinarySegment::default().name(name(segment, "__TEXT")?)
+				.address(segment.load as u64).size(size).file_size(size)
 				.protections(constants::VM_PROT_EXECUTE | constants::VM_PROT_READ)
 		}
 		SegmentKind::Data(data) => {
 			let size = data.iter().map(Vec::len).sum::<usize>() as u64;
-			BinarySegment::default().name(b"__DATA")
-				.address(segment.address as u64).size(size).file_size(size)
+			BinarySegment::default().name(name(segment, "__DATA")?)
+				.address(segment.load as u64).size(size).file_size(size)
 				.protections(constants::VM_PROT_READ | constants::VM_PROT_WRITE)
 		}
-		SegmentKind::Reserve(size) => BinarySegment::default().name(b"__DATA")
-			.address(segment.address as u64).size(*size as u64).file_size(0)
+		SegmentKind::Constant(data) => {
+			let size = data.iter().map(Vec::len).sum::<usize>() as u64;
+			BinarySegment::default().name(name(segment, "__DATA_CONST")?)
+				.address(segment.load as u64).size(size).file_size(size)
+				.protections(constants::VM_PROT_READ)
+		}
+		SegmentKind::Reserve(size) => BinarySegment::default().name(name(segment, "__DATA")?)
+			.address(segment.load as u64).size(*size as u64).file_size(0)
 			.protections(constants::VM_PROT_READ | constants::VM_PROT_WRITE),
-	}.build()).collect()
+	}.protections(match relocations.iter().copied().flatten().any(|address|
+		segment.load <= *address && *address < segment.load + segment.size()) {
+		true => constants::VM_PROT_WRITE,
+		false => 0,
+	}).build())).collect()
+}
+
+/// Returns the segment name from its output
+/// section or otherwise the default name.
+fn name<'a>(segment: &'a Segment, default: &'a str) -> io::Result<&'a [u8]> {
+	const NAME_SIZE: usize = 16;
+	let name = segment.name.as_deref().unwrap_or(default);
+	match name.len() <= NAME_SIZE {
+		false => Err(io::Error::new(io::ErrorKind::InvalidInput, format!(
+			"section name: {}, exceeds {} bytes", name, NAME_SIZE))),
+		true => Ok(name.as_bytes()),
+	}
 }
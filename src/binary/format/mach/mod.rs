@@ -1,7 +1,9 @@
 pub use command::*;
 pub use compile::*;
+pub use rebase::*;
 pub use segment::*;
 
 mod compile;
+mod rebase;
 mod segment;
 mod command;
\ No newline at end of file
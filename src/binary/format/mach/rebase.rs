@@ -0,0 +1,52 @@
+use std::io;
+
+use crate::binary::Segment;
+use crate::node::address::Address;
+
+const REBASE_TYPE_POINTER: u8 = 1;
+const REBASE_OPCODE_DONE: u8 = 0x00;
+const REBASE_OPCODE_SET_TYPE_IMM: u8 = 0x10;
+const REBASE_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB: u8 = 0x20;
+const REBASE_OPCODE_DO_REBASE_IMM_TIMES: u8 = 0x50;
+
+/// The number of segments preceding the
+/// compiled segments in the load commands.
+pub const SEGMENT_OFFSET: usize = 2;
+
+/// Encodes the rebase opcodes that adjust the pointer
+/// at each address when the image is loaded at another base.
+pub fn rebase(segments: &[Segment], relocations: &[Address]) -> io::Result<Vec<u8>> {
+	let mut bytes = vec![REBASE_OPCODE_SET_TYPE_IMM | REBASE_TYPE_POINTER];
+	for address in relocations {
+		let index = segments.iter().position(|segment| segment.load <= *address &&
+			*address < segment.load + segment.size()).ok_or_else(|| invalid(format!(
+			"relocation at: {:#x}, is not within a segment", address)))?;
+
+		let segment = index + SEGMENT_OFFSET;
+		if segment > 0xf {
+			return Err(invalid(format!("relocation in segment: {}, cannot be encoded", segment)));
+		}
+
+		bytes.push(REBASE_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB | segment as u8);
+		uleb(&mut bytes, address - segments[index].load);
+		bytes.push(REBASE_OPCODE_DO_REBASE_IMM_TIMES | 1);
+	}
+
+	bytes.push(REBASE_OPCODE_DONE);
+	Ok(bytes)
+}
+
+fn uleb(bytes: &mut Vec<u8>, mut value: usize) {
+	loop {
+		let byte = (value & 0x7f) as u8;
+		value >>= 7;
+		match value {
+			0 => return bytes.push(byte),
+			_ => bytes.push(byte | 0x80),
+		}
+	}
+}
+
+fn invalid(message: String) -> io::Error {
+	io::Error::new(io::ErrorKind::InvalidInput, message)
+}
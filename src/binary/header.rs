@@ -0,0 +1,312 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::fs::File;
+
+use crate::context::Context;
+use crate::error::Diagnostic;
+use crate::generate::x86::Mode;
+use crate::node::*;
+
+/// Writes a C header declaring the functions, structures
+/// and static variables annotated with `@export`.
+pub fn header(context: &Context, path: &std::path::Path) -> crate::Result<()> {
+	let header = self::declarations(context)?;
+	if crate::context::failed(context) {
+		return Err(crate::query::QueryError::Failure);
+	}
+
+	File::create(path).and_then(|mut file| std::io::Write::write_all(&mut file,
+		header.as_bytes())).map_err(|error| context.error(Diagnostic::error()
+		.message("failed to write header to file")
+		.note(format!("error: {}", error))))
+}
+
+fn declarations(context: &Context) -> crate::Result<String> {
+	let (mut structures, mut visited) = (Vec::new(), HashSet::new());
+	let mut functions = Vec::new();
+	for entry in context.functions.iter() {
+		for (kind, function) in entry.value().iter().enumerate() {
+			let path = FunctionPath(entry.key().clone(), kind);
+			let symbol = Symbol::Function(path.clone());
+			if context.imports.contains_key(&symbol) { continue; }
+			if let Some(name) = export(context, &function.annotations, entry.key())? {
+				function.parameters.iter().for_each(|parameter| match &parameter.node {
+					Parameter::Variable(_, node_type) => self::structures(context,
+						&node_type.node, &mut structures, &mut visited),
+					Parameter::Register(_) => (),
+				});
+
+				if let ReturnType::Type(node_type) = &function.return_type.node {
+					self::structures(context, &node_type.node, &mut structures, &mut visited);
+				}
+				functions.push((name, path, function.clone()));
+			}
+		}
+	}
+
+	let mut statics = Vec::new();
+	for entry in context.statics.iter() {
+		let symbol = Symbol::Variable(entry.key().clone());
+		if context.imports.contains_key(&symbol) { continue; }
+		if let Some(name) = export(context, &entry.annotations, entry.key())? {
+			let path = entry.key().clone();
+			let node_type = crate::inference::type_variable(context, None, path.clone(), None)?;
+			self::structures(context, &node_type.node, &mut structures, &mut visited);
+			statics.push((name, path, node_type.node));
+		}
+	}
+
+	for entry in context.structures.iter() {
+		if export(context, &entry.annotations, entry.key())?.is_some() {
+			let structure = Type::Structure(entry.key().clone());
+			self::structures(context, &structure, &mut structures, &mut visited);
+		}
+	}
+
+	let header = &mut String::new();
+	writeln!(header, "#pragma once\n").unwrap();
+	writeln!(header, "#include <stddef.h>").unwrap();
+	writeln!(header, "#include <stdint.h>\n").unwrap();
+	for path in &structures {
+		writeln!(header, "struct {};", name(context, path)?).unwrap();
+	}
+
+	for path in &structures {
+		writeln!(header).unwrap();
+		structure(context, header, path)?;
+	}
+
+	functions.sort_by(|(left, _, _), (right, _, _)| left.cmp(right));
+	for (name, path, function) in functions {
+		let symbol = Symbol::Function(path);
+		let address = address::start(context, None, &symbol, None)?;
+		writeln!(header, "\n/* {} */", symbol).unwrap();
+		writeln!(header, "#define {}_ADDRESS {:#x}", name, address).unwrap();
+		if let Some(prototype) = prototype(context, &name, &function)? {
+			writeln!(header, "{};", prototype).unwrap();
+		}
+	}
+
+	statics.sort_by(|(left, _, _), (right, _, _)| left.cmp(right));
+	for (name, path, node_type) in statics {
+		let symbol = Symbol::Variable(path);
+		let address = address::start(context, None, &symbol, None)?;
+		writeln!(header, "\n/* {} */", symbol).unwrap();
+		writeln!(header, "#define {}_ADDRESS {:#x}", name, address).unwrap();
+		writeln!(header, "extern {};", declare(context, &node_type, name)?).unwrap();
+	}
+	Ok(header.clone())
+}
+
+/// Returns the exported name of an item. The annotation `@export true`
+/// exports the path joined with underscores and `@export "name"`
+/// exports the item with the provided name.
+fn export(context: &Context, annotations: &Annotations,
+		  path: &Path) -> crate::Result<Option<String>> {
+	let annotation = match annotations.get(&Identifier("export".to_string())) {
+		Some(annotation) => &annotation.node,
+		None => return Ok(None),
+	};
+
+	Ok(match &annotation[annotation.root].node {
+		ValueNode::String(name) => Some(name.clone()),
+		_ => match crate::evaluate::integral(context, None, annotation, &annotation.root)? {
+			0 => None,
+			_ => Some(mangle(path)),
+		}
+	})
+}
+
+fn mangle(Path(elements): &Path) -> String {
+	let elements: Vec<_> = elements.iter().map(|Identifier(element)| element.as_str()).collect();
+	elements.join("_")
+}
+
+fn name(context: &Context, path: &Path) -> crate::Result<String> {
+	let structure = context.structures.get(path).unwrap();
+	Ok(export(context, &structure.annotations, path)?.unwrap_or_else(|| mangle(path)))
+}
+
+/// Collects the structures referenced by a type such
+/// that structures are ordered after their fields.
+fn structures(context: &Context, node_type: &Type,
+			  structures: &mut Vec<Path>, visited: &mut HashSet<Path>) {
+	match node_type {
+		Type::Pointer(node_type) | Type::Slice(node_type) | Type::Array(node_type, _) =>
+			self::structures(context, &node_type.node, structures, visited),
+		Type::Structure(path) if !visited.contains(path) => {
+			visited.insert(path.clone());
+			if let Some(structure) = context.structures.get(path) {
+				structure.fields.values().for_each(|field|
+					self::structures(context, &field.node, structures, visited));
+			}
+			structures.push(path.clone());
+		}
+		_ => (),
+	}
+}
+
+/// Writes a structure definition with static assertions
+/// that the layout matches the derived field offsets. Bit
+/// fields are padded to the end of their storage unit.
+fn structure(context: &Context, header: &mut String, path: &Path) -> crate::Result<()> {
+	let name = name(context, path)?;
+	let offsets = offsets(context, None, path, None)?;
+	let structure = context.structures.get(path).unwrap();
+	let aligned = representation(&structure);
+	writeln!(header, "struct {} {{", name).unwrap();
+	for (index, (identifier, node_type)) in structure.fields.iter().enumerate() {
+		let Identifier(field) = identifier;
+		if let Some(Bits { unit, shift, width }) = offsets.bits.get(identifier) {
+			let (size, unit) = (*unit as u8, Type::Unsigned(*unit));
+			if *shift == 0 && aligned {
+				writeln!(header, "\t{} : 0;", declare(context, &unit, String::new())?).unwrap();
+			}
+
+			let field = format!("{} : {}", field, width);
+			writeln!(header, "\t{};", declare(context, &unit, field)?).unwrap();
+			let next = structure.fields.get_index(index + 1)
+				.and_then(|(next, _)| offsets.bits.get(next));
+			let end = shift + width;
+			if next.map_or(true, |next| next.shift == 0) && end < size {
+				let padding = format!(": {}", size - end);
+				writeln!(header, "\t{};", declare(context, &unit, padding)?).unwrap();
+			}
+			continue;
+		}
+
+		let declaration = declare(context, &node_type.node, field.clone())?;
+		writeln!(header, "\t{};", declaration).unwrap();
+	}
+
+	match aligned {
+		true => writeln!(header, "}};"),
+		false => writeln!(header, "}} __attribute__((packed));"),
+	}.unwrap();
+
+	let size = offsets.size;
+	writeln!(header, "_Static_assert(sizeof(struct {}) == {}, \"size of {}\");",
+		name, size, name).unwrap();
+	for (identifier, _) in &structure.fields {
+		if offsets.bits.contains_key(identifier) { continue; }
+		let Identifier(field) = identifier;
+		let offset = offsets.fields[&Identifier(field.clone())];
+		writeln!(header, "_Static_assert(offsetof(struct {}, {}) == {}, \"offset of {}.{}\");",
+			name, field, offset, name, field).unwrap();
+	}
+	Ok(())
+}
+
+/// Writes a function prototype. Functions use the stack based calling
+/// convention which is only representable in C for protected mode or
+/// for functions without parameters that return integral values.
+/// Named calling conventions are not yet generated so such functions
+/// also use the stack based convention.
+fn prototype(context: &Context, name: &str,
+			 function: &Function) -> crate::Result<Option<String>> {
+	let mode = crate::node::MODE;
+	let mut parameters = Vec::new();
+	let mut reason = function.convention.as_ref()
+		.map(|_| "named calling conventions are not generated");
+	for parameter in &function.parameters {
+		match &parameter.node {
+			Parameter::Register(_) => {
+				convention(context, function, "register parameters");
+				return Ok(None);
+			}
+			Parameter::Variable(variable, node_type) => {
+				let Variable(Identifier(identifier), _) = &variable.node;
+				let size = size(context, None, &node_type.node, None)?;
+				let stack = !matches!(mode, Mode::Protected) || size % 4 != 0;
+				if stack {
+					reason = Some("stack parameters");
+				}
+				parameters.push(declare(context, &node_type.node, identifier.clone())?);
+			}
+		}
+	}
+
+	let return_type = match &function.return_type.node {
+		ReturnType::Register(_) => {
+			convention(context, function, "register return values");
+			return Ok(None);
+		}
+		ReturnType::Type(node_type) => {
+			if node_type.node.composite() {
+				reason = Some("composite return values");
+			}
+			&node_type.node
+		}
+	};
+
+	if let Some(reason) = reason {
+		if !convention(context, function, reason) {
+			return Ok(None);
+		}
+	}
+
+	let parameters = match parameters.is_empty() {
+		true => "void".to_string(),
+		false => parameters.join(", "),
+	};
+
+	let declarator = format!("{}({}){}", name, parameters, attribute(mode));
+	declare(context, return_type, declarator).map(Some)
+}
+
+/// Returns the attribute declaring the stack based calling
+/// convention which matches `cdecl` in protected mode.
+fn attribute(mode: Mode) -> &'static str {
+	match mode {
+		Mode::Protected => " __attribute__((cdecl))",
+		_ => "",
+	}
+}
+
+/// Reports a function whose calling convention cannot be represented
+/// in C. Returns whether the function is annotated with `@admit convention`
+/// in which case the prototype is exported if its types can be declared.
+fn convention(context: &Context, function: &Function, reason: &str) -> bool {
+	let admitted = admitted(&function.annotations, "convention");
+	if !admitted {
+		context.emit(Diagnostic::warning()
+			.message(format!("calling convention is not representable in C: {}", reason))
+			.label(function.identifier.span.label()).note("the prototype is not exported")
+			.note("suppress with the annotation: @admit convention"));
+	}
+	admitted
+}
+
+/// Returns a C declaration of an identifier with a type.
+fn declare(context: &Context, node_type: &Type, inner: String) -> crate::Result<String> {
+	Ok(match node_type {
+		Type::Pointer(node_type) => match &node_type.node {
+			Type::Array(_, _) => declare(context, &node_type.node, format!("(*{})", inner))?,
+			_ => declare(context, &node_type.node, format!("*{}", inner))?,
+		},
+		Type::Array(node_type, length) => {
+			let length = crate::node::length(context, None, length)?;
+			declare(context, &node_type.node, format!("{}[{}]", inner, length))?
+		}
+		Type::Slice(node_type) => {
+			let address = declare(context, &node_type.node, "*address".to_string())?;
+			format!("struct __attribute__((packed)) {{ {}; uint64_t length; }} {}", address, inner)
+		}
+		other => {
+			let base = match other {
+				Type::Void | Type::Never => "void".to_string(),
+				Type::Truth => "_Bool".to_string(),
+				Type::Rune => "uint32_t".to_string(),
+				Type::Signed(size) => format!("int{}_t", size.bytes() * 8),
+				Type::Unsigned(size) => format!("uint{}_t", size.bytes() * 8),
+				Type::Structure(path) => format!("struct {}", name(context, path)?),
+				_ => unreachable!(),
+			};
+
+			match inner.is_empty() {
+				true => base,
+				false => format!("{} {}", base, inner),
+			}
+		}
+	})
+}
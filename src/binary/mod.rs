@@ -1,9 +1,17 @@
 pub use compile::*;
 pub use entry::*;
+pub use header::*;
 pub use patch::*;
+pub use relocation::*;
+pub use startup::*;
+pub use string::*;
 
 pub mod format;
 
 mod compile;
 mod entry;
+mod header;
 mod patch;
+mod relocation;
+mod startup;
+mod string;
@@ -1,33 +1,85 @@
+use std::collections::HashSet;
+
 use crate::context::Context;
-use crate::generate::Relative;
-use crate::node::{Size, Symbol};
-use crate::node::address::Address;
+use crate::error::Diagnostic;
+use crate::generate::{Absolute, Form, Literal, Offset, Relative};
+use crate::node::{FunctionPath, Size, Symbol};
+use crate::span::Span;
 
-use super::{Entity, Entry};
+use super::{Entity, Entry, Strings};
 
-pub fn patch(context: &Context, entries: &mut [Entry]) {
-	entries.iter_mut().map(|entry| entity(context,
-		&mut entry.entity, &entry.address)).for_each(std::mem::drop)
+/// Resolves relative calls and absolute addresses in code and data.
+/// Returns the functions that are out of range of a relative call.
+pub fn patch(context: &Context, entries: &mut [Entry],
+			 strings: &Strings) -> HashSet<FunctionPath> {
+	let mut far = HashSet::new();
+	entries.iter_mut().map(|entry| self::entry(context,
+		entry, strings, &mut far)).for_each(std::mem::drop);
+	far
 }
 
-fn entity(context: &Context, entity: &mut Entity,
-		  address: &Address) -> crate::Result<()> {
-	Ok(match entity {
-		Entity::Function(section) => {
-			for Relative { size, offset, target, path } in &section.relative {
-				let symbol = Symbol::Function(path.clone());
-				let other = crate::node::address::start(context, None, &symbol, None)?;
-				let slice = &mut section.bytes[*offset..*offset + size.bytes()];
-				let relative = other as isize - (*address + *target) as isize;
-
-				match size {
-					Size::Byte => slice.copy_from_slice(&(relative as i8).to_le_bytes()),
-					Size::Word => slice.copy_from_slice(&(relative as i16).to_le_bytes()),
-					Size::Double => slice.copy_from_slice(&(relative as i32).to_le_bytes()),
-					Size::Quad => slice.copy_from_slice(&(relative as i64).to_le_bytes()),
-				}
+fn entry(context: &Context, entry: &mut Entry, strings: &Strings,
+		 far: &mut HashSet<FunctionPath>) -> crate::Result<()> {
+	let Entry { address, symbol: caller, entity, .. } = entry;
+	let section = match entity {
+		Entity::Function(section) | Entity::Constant(section) => section,
+		Entity::Variable(Some(section)) => section,
+		Entity::Variable(None) => return Ok(()),
+	};
+
+	for Relative { size, offset, target, symbol, addend, span } in &section.relative {
+		let other = crate::node::address::start(context, None, symbol, None)? as i128 + addend;
+		let relative = other - (*address + *target) as i128;
+		match (signed(relative, *size), symbol) {
+			(true, _) => write(&mut section.bytes, *offset, relative, *size),
+			(false, Symbol::Function(path)) => std::mem::drop(far.insert(path.clone())),
+			(false, _) => range(context, symbol, caller, other, span),
+		}
+	}
+
+	for Absolute { form, offset, symbol, addend, span } in &section.absolute {
+		let other = crate::node::address::start(context, None, symbol, None)? as i128 + addend;
+		match form {
+			Form::Direct(size) if unsigned(other, *size) =>
+				write(&mut section.bytes, *offset, other, *size),
+			Form::Split(size, shift) =>
+				write(&mut section.bytes, *offset, other >> shift, *size),
+			Form::Masked(size, shift, mask) => write(&mut section.bytes,
+				*offset, (other >> shift) & ((1 << mask) - 1), *size),
+			Form::Segmented if unsigned(other >> 4, Size::Word) => {
+				write(&mut section.bytes, *offset, other & 0xf, Size::Word);
+				write(&mut section.bytes, *offset + 2, other >> 4, Size::Word);
 			}
+			_ => range(context, symbol, caller, other, span),
 		}
-		Entity::Variable(_) => (),
-	})
+	}
+
+	for Literal { size, offset, bytes } in &section.strings {
+		let address = strings.address(bytes) as i128;
+		write(&mut section.bytes, *offset, address, *size);
+	}
+
+	Ok(())
+}
+
+fn range(context: &Context, symbol: &Symbol, caller: &Option<Symbol>,
+		 address: i128, span: &Span) {
+	context.emit(Diagnostic::error().label(span.label())
+		.message(format!("address of: {}, is out of range", symbol))
+		.note(format!("referenced from: {}", caller.as_ref().unwrap()))
+		.note(format!("with address: {:#x}", address)))
+}
+
+fn write(bytes: &mut [u8], offset: Offset, value: i128, size: Size) {
+	let slice = &mut bytes[offset..offset + size.bytes()];
+	slice.copy_from_slice(&value.to_le_bytes()[..size.bytes()]);
+}
+
+fn signed(value: i128, size: Size) -> bool {
+	let limit = 1 << (size as u32 - 1);
+	-limit <= value && value < limit
+}
+
+fn unsigned(value: i128, size: Size) -> bool {
+	0 <= value && value < 1 << size as u32
 }
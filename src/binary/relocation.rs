@@ -0,0 +1,42 @@
+use crate::context::Context;
+use crate::error::Diagnostic;
+use crate::generate::{Absolute, Form, Literal};
+use crate::generate::x86::Mode;
+use crate::node::address::Address;
+
+use super::{Entity, Entry};
+
+/// Returns the load addresses of absolute addresses that must be
+/// adjusted when position independent code is loaded at another base.
+/// Returns nothing if the code is not position independent.
+pub fn relocations(context: &Context, entries: &[Entry])
+				   -> crate::Result<Option<Vec<Address>>> {
+	if !crate::generate::x86::independent(context)? {
+		return Ok(None);
+	}
+
+	// TODO: derive mode from architecture
+	let size = Mode::Long.size();
+	let mut relocations = Vec::new();
+	for Entry { load, entity, .. } in entries {
+		let section = match entity {
+			Entity::Function(section) | Entity::Constant(section) => section,
+			Entity::Variable(Some(section)) => section,
+			Entity::Variable(None) => continue,
+		};
+
+		for Absolute { form, offset, span, .. } in &section.absolute {
+			match form {
+				Form::Direct(other) if *other == size => relocations.push(load + offset),
+				_ => context.emit(Diagnostic::error().label(span.label())
+					.message("address cannot be relocated")
+					.note(format!("position independent addresses must be {} bits", size))),
+			}
+		}
+
+		relocations.extend(section.strings.iter()
+			.map(|Literal { offset, .. }| load + offset));
+	}
+
+	Ok(Some(relocations))
+}
@@ -0,0 +1,88 @@
+use crate::context::Context;
+use crate::error::Diagnostic;
+use crate::generate::x86::Mode;
+use crate::node::Identifier;
+use crate::node::address::Address;
+
+use super::{Segment, SegmentKind};
+
+// TODO: derive from architecture
+const ALIGNMENT: usize = 4 * 1024;
+
+/// Synthesises a routine that copies segments from their load address
+/// to their virtual address and clears reserved segments before
+/// jumping to the entry point. The routine is placed before the first
+/// segment such that it begins the image. Returns the address execution starts at.
+/// The routine is omitted if the global annotation `@@startup false` exists.
+/// Position independent code is instead relocated by the routine as no
+/// loader is requested to apply the relocations.
+pub fn startup(context: &Context, segments: &mut Vec<Segment>, entry: Address,
+			   relocations: Option<&[Address]>) -> crate::Result<Address> {
+	let identifier = Identifier("startup".to_string());
+	if let Some(annotation) = context.annotations.get(&identifier) {
+		let value = &annotation.node;
+		let parent = None;
+		if crate::evaluate::integral(context, parent, value, &value.root)? == 0 {
+			return Ok(entry);
+		}
+	}
+
+	if crate::generate::x86::independent(context)? {
+		return match segments.iter().any(|segment| segment.load != segment.address) {
+			true => context.pass(Diagnostic::error()
+				.message("position independent code cannot be loaded at a virtual address")
+				.note("remove the @virtual annotations or @@independent")),
+			false => match relocations {
+				Some(relocations) if !relocations.is_empty() => {
+					// TODO: derive mode from architecture
+					let routine = |address| crate::generate::x86::relocate(Mode::Long,
+						address, relocations, entry);
+					place(context, segments, routine)
+				}
+				_ => Ok(entry),
+			},
+		};
+	}
+
+	let (mut copies, mut clears) = (Vec::new(), Vec::new());
+	for segment in segments.iter() {
+		match segment.kind {
+			SegmentKind::Reserve(size) => clears.push((segment.address, size)),
+			_ if segment.load != segment.address => copies.push((segment.load,
+				segment.address, segment.size())),
+			_ => (),
+		}
+	}
+
+	if copies.is_empty() && clears.is_empty() {
+		return Ok(entry);
+	}
+
+	// TODO: derive mode from architecture
+	let routine = |address| crate::generate::x86::startup(Mode::Long,
+		address, &copies, &clears, entry);
+	place(context, segments, routine)
+}
+
+/// Places a routine on the page boundary before the first
+/// segment. Returns the address of the routine.
+fn place(context: &Context, segments: &mut Vec<Segment>,
+		 routine: impl Fn(Address) -> Vec<u8>) -> crate::Result<Address> {
+	let size = routine(0).len();
+	let start = segments.iter().map(|segment| segment.load).min().unwrap_or_default();
+	let overlaps = |address: Address| segments.iter().any(|segment| segment.address <
+		address + size && address < segment.address + segment.size());
+	let address = start.checked_sub(size).map(|address| address / ALIGNMENT * ALIGNMENT);
+	let address = match address.filter(|address| !overlaps(*address)) {
+		Some(address) => address,
+		None => return context.pass(Diagnostic::error()
+			.message("startup routine cannot be placed before the first segment")
+			.note(format!("the routine requires: {} bytes, before address: {:#x}", size, start))
+			.note("omit the routine with: @@startup false")),
+	};
+
+	let bytes = routine(address);
+	let kind = SegmentKind::Text(vec![bytes]);
+	segments.insert(0, Segment { load: address, address, name: None, kind });
+	Ok(address)
+}
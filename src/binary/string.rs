@@ -0,0 +1,54 @@
+use std::collections::HashMap;
+
+use crate::generate::{Literal, Offset, Section};
+use crate::node::address::Address;
+use crate::other::ceiling;
+
+use super::{Entity, Entry};
+
+// TODO: derive from architecture
+const ALIGNMENT: usize = 4 * 1024;
+
+/// A deduplicated table of string literals placed in
+/// read only memory after the last entry in the binary.
+#[derive(Debug, Default)]
+pub struct Strings {
+	pub load: Address,
+	pub address: Address,
+	offsets: HashMap<Vec<u8>, Offset>,
+	bytes: Vec<u8>,
+}
+
+impl Strings {
+	pub fn address(&self, bytes: &[u8]) -> Address {
+		self.address + self.offsets[bytes]
+	}
+
+	pub fn entry(self) -> Option<Entry> {
+		let Strings { load, address, bytes, .. } = self;
+		let size = bytes.len();
+		let entity = Entity::Constant(Section { bytes, ..Section::default() });
+		let (symbol, section) = (None, None);
+		(size > 0).then(|| Entry { load, address, size, symbol, section, entity })
+	}
+}
+
+pub fn strings(entries: &[Entry]) -> Strings {
+	let mut strings = Strings::default();
+	let end = |address: Address, entry: &Entry| address.max(entry.address + entry.size);
+	strings.address = ceiling(entries.iter().fold(0, end), ALIGNMENT);
+	let end = |load: Address, entry: &Entry| load.max(entry.load + entry.size);
+	strings.load = ceiling(entries.iter().fold(0, end), ALIGNMENT);
+
+	for entry in entries {
+		if let Entity::Function(section) = &entry.entity {
+			for Literal { bytes, .. } in &section.strings {
+				if !strings.offsets.contains_key(bytes) {
+					strings.offsets.insert(bytes.clone(), strings.bytes.len());
+					strings.bytes.extend(bytes);
+				}
+			}
+		}
+	}
+	strings
+}
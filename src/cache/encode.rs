@@ -0,0 +1,100 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::Range;
+
+use codespan::FileId;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error;
+
+use crate::span::Span;
+
+thread_local! {
+	/// The files and relocations of the spans being written or read.
+	static SPANS: RefCell<Spans> = RefCell::new(Spans::default());
+}
+
+/// The previous and current range of a function body.
+pub type Relocation = (Range<usize>, Range<usize>);
+
+/// Spans are written as indices into a table of files such that they can
+/// be restored in a compilation that reads files in another order. Spans
+/// are read relocated by the change in length of the function bodies that
+/// precede them in their file.
+#[derive(Debug, Default)]
+pub struct Spans {
+	pub files: Vec<FileId>,
+	/// The relocations of function bodies ordered by position.
+	pub bodies: HashMap<FileId, Vec<Relocation>>,
+}
+
+impl Spans {
+	fn file(&mut self, file: FileId) -> usize {
+		match self.files.iter().position(|other| *other == file) {
+			Some(index) => index,
+			None => {
+				self.files.push(file);
+				self.files.len() - 1
+			}
+		}
+	}
+
+	fn relocate(&self, file: FileId, position: usize) -> usize {
+		let mut relocated = position;
+		for (previous, current) in self.bodies.get(&file).into_iter().flatten() {
+			if position < previous.start { break; }
+			if position <= previous.end {
+				let offset = position - previous.start;
+				return usize::min(current.start + offset, current.end);
+			}
+
+			relocated = current.end + (position - previous.end);
+		}
+		relocated
+	}
+}
+
+/// Writes or reads spans with the files and relocations in `spans`.
+pub fn spans<T>(spans: &mut Spans, function: impl FnOnce() -> T) -> T {
+	SPANS.with(|other| std::mem::swap(spans, &mut other.borrow_mut()));
+	let value = function();
+	SPANS.with(|other| std::mem::swap(spans, &mut other.borrow_mut()));
+	value
+}
+
+impl Serialize for Span {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let range = self.range();
+		let file = SPANS.with(|spans| spans.borrow_mut().file(self.file()));
+		(file, range.start, range.end).serialize(serializer)
+	}
+}
+
+impl<'de> Deserialize<'de> for Span {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let (file, start, end) = <(usize, usize, usize)>::deserialize(deserializer)?;
+		SPANS.with(|spans| {
+			let spans = spans.borrow();
+			let file = *spans.files.get(file).ok_or_else(||
+				D::Error::custom(format!("file index: {}, is invalid", file)))?;
+			let (start, end) = (spans.relocate(file, start), spans.relocate(file, end));
+			Ok(Span::new(start..end, file))
+		})
+	}
+}
+
+/// Writes a map as a list of entries for maps
+/// with keys that are not written as strings.
+pub mod entries {
+	use super::*;
+
+	pub fn serialize<K, V, S>(map: &HashMap<K, V>, serializer: S) -> Result<S::Ok, S::Error>
+		where K: Serialize, V: Serialize, S: Serializer {
+		serializer.collect_seq(map.iter())
+	}
+
+	pub fn deserialize<'de, K, V, D>(deserializer: D) -> Result<HashMap<K, V>, D::Error>
+		where K: Deserialize<'de> + Eq + Hash, V: Deserialize<'de>, D: Deserializer<'de> {
+		Vec::<(K, V)>::deserialize(deserializer).map(|entries| entries.into_iter().collect())
+	}
+}
@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use crate::context::Context;
+use crate::node::FunctionPath;
+use crate::query::Key;
+use crate::span::Span;
+
+/// The Fowler–Noll–Vo hash. Unlike the default hasher its algorithm
+/// is fixed such that fingerprints are stable across compilations.
+#[derive(Debug)]
+pub struct Stable(u64);
+
+impl Default for Stable {
+	fn default() -> Self {
+		Stable(0xcbf2_9ce4_8422_2325)
+	}
+}
+
+impl Hasher for Stable {
+	fn finish(&self) -> u64 {
+		self.0
+	}
+
+	fn write(&mut self, bytes: &[u8]) {
+		for byte in bytes {
+			self.0 ^= *byte as u64;
+			self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+		}
+	}
+}
+
+/// The body of a function and the hash of its text.
+#[derive(Debug)]
+pub struct Body {
+	pub span: Span,
+	pub hash: u64,
+}
+
+/// Returns the bodies of every function. Imported
+/// functions are present without a body.
+pub fn bodies(context: &Context) -> HashMap<FunctionPath, Option<Body>> {
+	let files = context.files.read();
+	context.functions.iter().flat_map(|entry| entry.value().iter().enumerate()
+		.map(|(kind, function)| {
+			let path = FunctionPath(entry.key().clone(), kind);
+			let value = &function.value;
+			(path, value.values.get(value.root).map(|node| {
+				let source = files.source(node.span.file());
+				let hash = hash(&source[node.span.range()]);
+				Body { span: node.span.clone(), hash }
+			}))
+		}).collect::<Vec<_>>()).collect()
+}
+
+/// Hashes the text of every source file excluding function bodies.
+/// Every query depends on the interface as items are read directly.
+pub fn interface(context: &Context, bodies: &HashMap<FunctionPath, Option<Body>>) -> u64 {
+	let files = context.files.read();
+	let mut sources: Vec<_> = files.sources().collect();
+	sources.sort_by_key(|(path, _)| *path);
+
+	let hasher = &mut Stable::default();
+	for (path, source) in sources {
+		let file = files.file(path).unwrap();
+		let mut ranges: Vec<_> = bodies.values().flatten()
+			.filter(|body| body.span.file() == file)
+			.map(|body| body.span.range()).collect();
+		ranges.sort_by_key(|range| range.start);
+
+		let mut start = 0;
+		path.hash(hasher);
+		for range in ranges {
+			source[start..range.start].hash(hasher);
+			start = range.end;
+		}
+		source[start..].hash(hasher);
+	}
+	hasher.finish()
+}
+
+/// Returns the hash of the inputs a query reads that are not
+/// otherwise queries or the interface. This is `None` if the
+/// item the query refers to no longer exists.
+pub fn own(bodies: &HashMap<FunctionPath, Option<Body>>, key: &Key) -> Option<u64> {
+	match key {
+		Key::TypeFunction(path) | Key::Generate(path) | Key::Analyze(path) =>
+			bodies.get(path).map(|body| body.as_ref().map(|body| body.hash).unwrap_or(0)),
+		_ => Some(0),
+	}
+}
+
+/// Derives the fingerprint of every query from the interface, its own inputs
+/// and the fingerprints of its dependencies. A query has no fingerprint if its
+/// own inputs are absent or if any dependency has no fingerprint.
+pub fn fingerprints(interface: u64, dependencies: &HashMap<Key, Vec<Key>>,
+					own: impl Fn(&Key) -> Option<u64>) -> HashMap<Key, Option<u64>> {
+	let mut fingerprints = HashMap::new();
+	for key in dependencies.keys() {
+		fingerprint(interface, dependencies, &own, &mut fingerprints, key);
+	}
+
+	fingerprints.into_iter().map(|(key, fingerprint)|
+		(key, fingerprint.flatten())).collect()
+}
+
+/// Entries that are being derived are `None` such
+/// that dependency cycles have no fingerprint.
+fn fingerprint(interface: u64, dependencies: &HashMap<Key, Vec<Key>>,
+			   own: &impl Fn(&Key) -> Option<u64>, fingerprints:
+			   &mut HashMap<Key, Option<Option<u64>>>, key: &Key) -> Option<u64> {
+	if let Some(fingerprint) = fingerprints.get(key) {
+		return fingerprint.flatten();
+	}
+
+	fingerprints.insert(key.clone(), None);
+	let fingerprint = (|| {
+		let mut others = Vec::new();
+		for dependency in dependencies.get(key)? {
+			others.push(fingerprint(interface, dependencies,
+				own, fingerprints, dependency)?);
+		}
+
+		others.sort();
+		let hasher = &mut Stable::default();
+		(interface, key, own(key)?, others).hash(hasher);
+		Some(hasher.finish())
+	})();
+
+	fingerprints.insert(key.clone(), Some(fingerprint));
+	fingerprint
+}
+
+pub fn hash<T: Hash + ?Sized>(value: &T) -> u64 {
+	let hasher = &mut Stable::default();
+	value.hash(hasher);
+	hasher.finish()
+}
@@ -0,0 +1,6 @@
+pub use encode::entries;
+pub use store::*;
+
+mod encode;
+mod fingerprint;
+mod store;
@@ -0,0 +1,255 @@
+use std::collections::{HashMap, HashSet};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use serde::de::DeserializeOwned;
+
+use crate::context::Context;
+use crate::error::Diagnostic;
+use crate::node::FunctionPath;
+use crate::query::{Key, Table};
+use crate::span::Span;
+
+use super::encode::{self, Spans};
+use super::fingerprint::{self, Body};
+
+// TODO: derive from global annotation
+const DIRECTORY: &str = ".lucent";
+const FILE: &str = "queries";
+const MAGIC: &str = "lucent";
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+const COMPILER: &str = env!("LUCENT_RUSTC");
+
+type Reader<'a> = serde_json::Deserializer<serde_json::de::SliceRead<'a>>;
+
+/// Identifies the compilation that wrote the cache. Fingerprints
+/// and the representation of values depend on the version of
+/// this compiler and the compiler that built it.
+#[derive(Debug, Serialize, Deserialize)]
+struct Header {
+	magic: String,
+	version: String,
+	compiler: String,
+	interface: u64,
+	/// The hash of every binary file read.
+	binaries: Vec<(PathBuf, u64)>,
+	/// The paths of the files that spans refer to. Internal
+	/// files such as intrinsics do not have a path.
+	files: Vec<Option<PathBuf>>,
+}
+
+/// Returns the cache directory of the project
+/// that contains the root source file.
+fn directory(root: &Path) -> PathBuf {
+	root.parent().unwrap_or_else(|| Path::new("")).join(DIRECTORY)
+}
+
+/// Removes the cache directory and every stored query.
+pub fn clean(context: &Context, root: &Path) -> crate::Result<()> {
+	match std::fs::remove_dir_all(directory(root)) {
+		Err(error) if error.kind() != ErrorKind::NotFound =>
+			context.pass(Diagnostic::error().message("failed to remove cache")
+				.note(format!("error: {}", error))),
+		_ => Ok(()),
+	}
+}
+
+/// Loads the queries of a previous compilation whose fingerprints are
+/// unchanged and returns the functions present in that compilation.
+/// Returns `None` if the cache is absent or if the interface or any
+/// binary file has changed in which case no queries are loaded.
+pub fn load(context: &Context, root: &Path) -> Option<HashSet<FunctionPath>> {
+	let bytes = std::fs::read(directory(root).join(FILE)).ok()?;
+	let bodies = fingerprint::bodies(context);
+	let interface = fingerprint::interface(context, &bodies);
+	let reader = &mut serde_json::Deserializer::from_slice(&bytes);
+	let header: Header = read(reader)?;
+	if header.magic != MAGIC || header.version != VERSION { return None; }
+	if header.compiler != COMPILER || header.interface != interface { return None; }
+
+	for (path, hash) in header.binaries {
+		let bytes = std::fs::read(&path).ok()?;
+		if fingerprint::hash(&bytes[..]) != hash { return None; }
+	}
+
+	let files = context.files.read();
+	let spans = &mut Spans::default();
+	spans.files = header.files.iter().map(|path| match path {
+		Some(path) => files.file(path),
+		None => Some(files.internal.file()),
+	}).collect::<Option<_>>()?;
+	std::mem::drop(files);
+
+	for (path, span) in encode::spans(spans, || read::<Vec<(FunctionPath, Span)>>(reader))? {
+		let body = bodies.get(&path)?.as_ref()?;
+		let bodies = spans.bodies.entry(span.file()).or_default();
+		bodies.push((span.range(), body.span.range()));
+	}
+
+	spans.bodies.values_mut().for_each(|bodies|
+		bodies.sort_by_key(|(previous, _)| previous.start));
+	encode::spans(spans, || {
+		let present = read(reader)?;
+		let far: HashSet<FunctionPath> = read(reader)?;
+
+		let mut fingerprints = HashMap::new();
+		let mut dependencies = HashMap::new();
+		for (key, fingerprint, others) in read::<Vec<(Key, u64, Vec<Key>)>>(reader)? {
+			fingerprints.insert(key.clone(), fingerprint);
+			dependencies.insert(key, others);
+		}
+
+		let current = fingerprint::fingerprints(interface, &dependencies,
+			|key| fingerprint::own(&bodies, key));
+		let valid: HashSet<_> = current.into_iter().filter(|(key, fingerprint)|
+			fingerprint.as_ref() == fingerprints.get(key))
+			.map(|(key, _)| key).collect();
+
+		let mut dependents: HashMap<Key, Vec<Key>> = HashMap::new();
+		for key in &valid {
+			for dependency in &dependencies[key] {
+				dependents.entry(dependency.clone()).or_default().push(key.clone());
+			}
+		}
+
+		let valid = &(valid, dependents);
+		restore(reader, &context.type_contexts, valid)?;
+		restore(reader, &context.sections, valid)?;
+		restore(reader, &context.offsets, valid)?;
+		restore(reader, &context.address, valid)?;
+		restore(reader, &context.names, valid)?;
+		context.far.write().extend(far);
+		Some(present)
+	})
+}
+
+/// Reads the next value. Returns `None` if
+/// the representation is invalid.
+fn read<T: DeserializeOwned>(reader: &mut Reader) -> Option<T> {
+	T::deserialize(reader).ok()
+}
+
+fn restore<V: DeserializeOwned>(reader: &mut Reader, table: &Table<V>,
+								(valid, dependents): &(HashSet<Key>, HashMap<Key, Vec<Key>>)) -> Option<()> {
+	Some(for (key, value) in read::<Vec<(Key, V)>>(reader)? {
+		if valid.contains(&key) {
+			let dependents = dependents.get(&key).cloned();
+			table.load(key, value, dependents.unwrap_or_default());
+		}
+	})
+}
+
+/// Invalidates addresses and their dependents if the functions present
+/// in the binary differ from those of the compilation that derived them.
+pub fn verify(context: &Context, present: &HashSet<FunctionPath>) {
+	if *context.present.read() == *present { return; }
+	let addresses = context.address.entries().into_iter().map(|(key, _, _)| key);
+	let names = context.names.entries().into_iter().map(|(key, _, _)| key);
+	crate::query::invalidate(context, &addresses.chain(names).collect::<Vec<_>>());
+}
+
+/// Stores every query that has a fingerprint. Queries are only
+/// stored if the compilation has not issued any diagnostics as
+/// diagnostics are not issued again for queries that are loaded.
+pub fn save(context: &Context, root: &Path) {
+	if !crate::context::silent(context) { return; }
+	let bodies = fingerprint::bodies(context);
+	let interface = fingerprint::interface(context, &bodies);
+
+	let mut dependencies = HashMap::new();
+	edges(&context.unit, &mut dependencies);
+	edges(&context.type_contexts, &mut dependencies);
+	edges(&context.sections, &mut dependencies);
+	edges(&context.offsets, &mut dependencies);
+	edges(&context.address, &mut dependencies);
+	edges(&context.names, &mut dependencies);
+	let fingerprints = fingerprint::fingerprints(interface, &dependencies,
+		|key| fingerprint::own(&bodies, key));
+
+	let spans = &mut Spans::default();
+	let values = encode::spans(spans, || -> serde_json::Result<_> {
+		let writer = &mut Vec::new();
+		let functions: Vec<_> = bodies.iter().filter_map(|(path, body)| body.as_ref()
+			.map(|Body { span, .. }| (path, span))).collect();
+		write(writer, &functions)?;
+		write(writer, &*context.present.read())?;
+		write(writer, &*context.far.read())?;
+
+		let graph: Vec<_> = dependencies.iter().filter_map(|(key, others)|
+			fingerprints[key].map(|fingerprint| (key, fingerprint, others))).collect();
+		write(writer, &graph)?;
+		values(writer, &context.type_contexts, &fingerprints)?;
+		values(writer, &context.sections, &fingerprints)?;
+		values(writer, &context.offsets, &fingerprints)?;
+		values(writer, &context.address, &fingerprints)?;
+		values(writer, &context.names, &fingerprints)?;
+		Ok(std::mem::take(writer))
+	});
+
+	let files = context.files.read();
+	let header = Header {
+		magic: MAGIC.to_string(),
+		version: VERSION.to_string(),
+		compiler: COMPILER.to_string(),
+		interface,
+		binaries: files.binaries().map(|(path, bytes)| (path.to_owned(),
+			fingerprint::hash(&bytes[..]))).collect(),
+		files: spans.files.iter().map(|file| files.path(*file)
+			.map(Path::to_owned)).collect(),
+	};
+
+	let directory = directory(root);
+	let bytes = values.and_then(|values| {
+		let bytes = &mut Vec::new();
+		write(bytes, &header)?;
+		bytes.extend(values);
+		Ok(std::mem::take(bytes))
+	});
+
+	if let Err(error) = bytes.map_err(std::io::Error::from).and_then(|bytes|
+		std::fs::create_dir_all(&directory).and_then(|_|
+			std::fs::write(directory.join(FILE), bytes))) {
+		context.emit(Diagnostic::warning().message("failed to write cache")
+			.note(format!("error: {}", error)));
+	}
+}
+
+/// Writes a value on its own line.
+fn write<T: Serialize + ?Sized>(writer: &mut Vec<u8>, value: &T) -> serde_json::Result<()> {
+	serde_json::to_writer(&mut *writer, value)?;
+	writer.push(b'\n');
+	Ok(())
+}
+
+/// Collects the dependencies of every query from their dependents.
+fn edges<V>(table: &Table<V>, dependencies: &mut HashMap<Key, Vec<Key>>) {
+	for (key, _, dependents) in table.entries() {
+		dependencies.entry(key.clone()).or_default();
+		for dependent in dependents {
+			dependencies.entry(dependent).or_default().push(key.clone());
+		}
+	}
+}
+
+fn values<V: Serialize>(writer: &mut Vec<u8>, table: &Table<V>,
+						fingerprints: &HashMap<Key, Option<u64>>) -> serde_json::Result<()> {
+	let entries = table.entries();
+	write(writer, &entries.iter().filter(|(key, _, _)| fingerprints[key].is_some())
+		.filter_map(|(key, value, _)| value.as_ref().map(|value| (key, value.as_ref())))
+		.collect::<Vec<_>>())
+}
+
+/// Reports the number of queries loaded from the
+/// cache and the number of queries computed.
+pub fn report(context: &Context) {
+	let (hits, misses) = [
+		context.type_contexts.statistics(),
+		context.sections.statistics(),
+		context.offsets.statistics(),
+		context.address.statistics(),
+		context.names.statistics(),
+	].iter().fold((0, 0), |(hits, misses), (other_hits, other_misses)|
+		(hits + other_hits, misses + other_misses));
+	eprintln!("cache: {} hits, {} misses", hits, misses);
+}
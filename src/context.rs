@@ -11,8 +11,10 @@ use crate::error::Diagnostic;
 use crate::generate::Section;
 use crate::inference::Types;
 use crate::node::*;
+use crate::options::Diagnostics;
+use crate::parse::{Include, Reference};
 use crate::query::{QueryError, Table};
-use crate::span::Span;
+use crate::span::{S, Span};
 
 #[derive(Debug, Default)]
 pub struct Context {
@@ -22,13 +24,37 @@ pub struct Context {
 	pub modules: DashMap<Path, Module>,
 	pub statics: DashMap<Path, Static>,
 	pub structures: DashMap<Path, Structure>,
+	pub regions: DashMap<Identifier, Region>,
+	pub annotations: DashMap<Identifier, S<Value>>,
+	/// The conditions of item level static assertions.
+	pub assertions: RwLock<Vec<S<Value>>>,
 	pub functions: DashMap<Path, Vec<Arc<Function>>>,
+	pub libraries: DashMap<Path, Library>,
+	pub imports: DashMap<Symbol, Import>,
 	pub positions: RwLock<HashMap<Symbol, Position>>,
 	pub present: RwLock<HashSet<FunctionPath>>,
+	pub far: RwLock<HashSet<FunctionPath>>,
+	/// The paths in source files and the symbols they resolve to.
+	pub references: RwLock<Vec<Reference>>,
+	/// The spans of the identifiers that define each symbol.
+	pub definitions: RwLock<HashMap<Path, Span>>,
+	/// The includes within source files and the
+	/// spans of the modules they are within.
+	pub inclusions: RwLock<Vec<(Option<Span>, S<Include>)>>,
 	pub type_contexts: Table<Types>,
 	pub sections: Table<Section>,
 	pub offsets: Table<Offsets>,
 	pub address: Table<usize>,
+	pub names: Table<Option<String>>,
+	pub binaries: Table<crate::parse::Binary>,
+	pub included: Table<Arc<[u8]>>,
+	/// The syntax trees of source files retained for incremental parsing.
+	pub trees: Mutex<HashMap<FileId, tree_sitter::Tree>>,
+	/// The number of threads used to type check and lower
+	/// functions. Zero uses a thread for each processor.
+	pub threads: usize,
+	/// The thread pool built on first use.
+	pool: Mutex<Option<Arc<rayon::ThreadPool>>>,
 	diagnostics: Mutex<Vec<Diagnostic>>,
 }
 
@@ -45,12 +71,33 @@ impl Context {
 	pub fn emit(&self, diagnostic: Diagnostic) {
 		let _ = self.pass::<!>(diagnostic);
 	}
+
+	/// Returns a context for another compilation that retains the files,
+	/// syntax trees and queries of this compilation. The present functions
+	/// are retained as the retained queries are derived from them.
+	pub fn successor(self) -> Self {
+		let Context { unit, files, present, far, type_contexts,
+			sections, offsets, address, names, binaries, included, trees, threads, pool, .. } = self;
+		Context { unit, files, present, far, type_contexts, sections, offsets, address,
+			names, binaries, included, trees, threads, pool, ..Context::default() }
+	}
+
+	/// Returns the thread pool with the threads of the context.
+	pub fn pool(&self) -> crate::Result<Arc<rayon::ThreadPool>> {
+		let mut pool = self.pool.lock();
+		if let Some(pool) = pool.as_ref() { return Ok(pool.clone()); }
+		let other = rayon::ThreadPoolBuilder::new().num_threads(self.threads)
+			.build().map_err(|error| self.error(Diagnostic::error()
+			.message(format!("failed to create thread pool: {}", error))))?;
+		Ok(pool.get_or_insert(Arc::new(other)).clone())
+	}
 }
 
 #[derive(Debug)]
 pub struct Files {
 	files: codespan::Files<Arc<str>>,
 	paths: HashMap<PathBuf, FileId>,
+	binaries: HashMap<PathBuf, Arc<[u8]>>,
 	pub internal: Span,
 }
 
@@ -69,17 +116,94 @@ impl Files {
 			}
 		}
 	}
+
+	/// Reads a file again if it has been read before. Returns
+	/// whether the contents of the file have changed.
+	pub fn update(&mut self, path: &std::path::Path) -> std::io::Result<bool> {
+		if let Some(file) = self.paths.get(path) {
+			let string = std::fs::read_to_string(path)?;
+			let changed = *self.files.source(*file).as_ref() != string;
+			if changed { self.files.update(*file, string.into()); }
+			Ok(changed)
+		} else if let Some(bytes) = self.binaries.get_mut(path) {
+			let other: Arc<[u8]> = std::fs::read(path)?.into();
+			let changed = *bytes != other;
+			*bytes = other;
+			Ok(changed)
+		} else {
+			Ok(false)
+		}
+	}
+
+	/// Sets the text of a source file such that it is not read from the file
+	/// system when next queried. Returns whether the text of a file that has
+	/// been read before has changed.
+	pub fn insert(&mut self, path: &std::path::Path, text: Arc<str>) -> bool {
+		match self.paths.get(path) {
+			Some(file) => {
+				let changed = *self.files.source(*file) != text;
+				if changed { self.files.update(*file, text); }
+				changed
+			}
+			None => {
+				let file = self.files.add(path.file_name().unwrap(), text);
+				self.paths.insert(path.to_owned(), file);
+				false
+			}
+		}
+	}
+
+	/// Returns the path of a source file.
+	pub fn path(&self, file: FileId) -> Option<&std::path::Path> {
+		self.paths.iter().find(|(_, other)| **other == file)
+			.map(|(path, _)| path.as_path())
+	}
+
+	/// Returns the identifier of a source file that has been read.
+	pub fn file(&self, path: &std::path::Path) -> Option<FileId> {
+		self.paths.get(path).cloned()
+	}
+
+	/// Returns the text of a source file.
+	pub fn source(&self, file: FileId) -> &Arc<str> {
+		self.files.source(file)
+	}
+
+	/// Returns the source files that have been read.
+	pub fn sources(&self) -> impl Iterator<Item=(&std::path::Path, Arc<str>)> {
+		let files = &self.files;
+		self.paths.iter().map(move |(path, file)|
+			(path.as_path(), files.source(*file).clone()))
+	}
+
+	/// Returns the binary files that have been read.
+	pub fn binaries(&self) -> impl Iterator<Item=(&std::path::Path, &Arc<[u8]>)> {
+		self.binaries.iter().map(|(path, bytes)| (path.as_path(), bytes))
+	}
+
+	/// Reads a binary file such as a library or a file included as data.
+	pub fn bytes(&mut self, path: &std::path::Path) -> std::io::Result<Arc<[u8]>> {
+		let path = path.canonicalize()?;
+		match self.binaries.get(&path) {
+			Some(bytes) => Ok(bytes.clone()),
+			None => {
+				let bytes: Arc<[u8]> = std::fs::read(&path)?.into();
+				self.binaries.insert(path, bytes.clone());
+				Ok(bytes)
+			}
+		}
+	}
 }
 
 impl Default for Files {
 	fn default() -> Self {
-		let paths = HashMap::new();
+		let (paths, binaries) = (HashMap::new(), HashMap::new());
 		let mut files = codespan::Files::new();
 		let file = files.add("<internal>", "<compiler internal>".into());
 		let start = files.source_span(file).start().to_usize();
 		let end = files.source_span(file).end().to_usize();
 		let internal = Span::new(start..end, file);
-		Files { files, paths, internal }
+		Files { files, paths, binaries, internal }
 	}
 }
 
@@ -89,12 +213,81 @@ pub fn failed(context: &Context) -> bool {
 			codespan_reporting::diagnostic::Severity::Error)
 }
 
-pub fn display(context: &Context) -> std::io::Result<()> {
+/// Returns whether no diagnostics of any severity have been issued.
+pub fn silent(context: &Context) -> bool {
+	context.diagnostics.lock().is_empty()
+}
+
+/// Returns the issued diagnostics ordered by their first label
+/// as diagnostics issued by other threads arrive in any order.
+pub fn diagnostics(context: &Context) -> Vec<Diagnostic> {
+	let mut diagnostics = context.diagnostics.lock().clone();
+	diagnostics.sort_by(|Diagnostic(left), Diagnostic(right)| {
+		let label = |diagnostic: &codespan_reporting::diagnostic::Diagnostic<FileId>|
+			diagnostic.labels.first().map(|label| (label.file_id, label.range.start));
+		(label(left), &left.message).cmp(&(label(right), &right.message))
+	});
+	diagnostics
+}
+
+pub fn display(context: &Context, format: &Diagnostics) -> std::io::Result<()> {
 	use codespan_reporting::term;
+	if let Diagnostics::Json = format { return json(context); }
 	let files = &context.files.read().files;
 	let configuration = &term::Config::default();
 	let colors = term::termcolor::ColorChoice::Auto;
 	let writer = &mut term::termcolor::StandardStream::stderr(colors);
-	context.diagnostics.lock().iter().try_for_each(|Diagnostic(diagnostic)|
+	diagnostics(context).iter().try_for_each(|Diagnostic(diagnostic)|
 		term::emit(writer, configuration, files, diagnostic))
 }
+
+/// Writes each diagnostic as a JSON object on its own line. Diagnostics
+/// are ordered by the position of their first label as in `diagnostics`.
+/// Labels are resolved to the path of their file and one based line and
+/// column ranges and keep the order they were added to their diagnostic.
+fn json(context: &Context) -> std::io::Result<()> {
+	use codespan_reporting::diagnostic::{LabelStyle, Severity};
+	use std::io::Write;
+	let files = context.files.read();
+	let position = |file, index: usize| files.files.location(file, index as u32).ok()
+		.map(|location| serde_json::json!({
+			"line": location.line.to_usize() + 1,
+			"column": location.column.to_usize() + 1,
+		}));
+
+	let writer = &mut std::io::stderr();
+	for Diagnostic(diagnostic) in diagnostics(context) {
+		let labels: Vec<_> = diagnostic.labels.iter().map(|label| serde_json::json!({
+			"path": files.path(label.file_id),
+			"primary": label.style == LabelStyle::Primary,
+			"message": label.message,
+			"start": position(label.file_id, label.range.start),
+			"end": position(label.file_id, label.range.end),
+		})).collect();
+
+		let severity = match diagnostic.severity {
+			Severity::Bug => "bug",
+			Severity::Error => "error",
+			Severity::Warning => "warning",
+			Severity::Note => "note",
+			Severity::Help => "help",
+		};
+
+		writeln!(writer, "{}", serde_json::json!({
+			"severity": severity,
+			"message": diagnostic.message,
+			"notes": diagnostic.notes,
+			"labels": labels,
+		}))?;
+	}
+	Ok(())
+}
+
+/// Applies a function to each item on a thread pool with
+/// the threads of the context. Results are in item order.
+pub fn parallel<T, R, F>(context: &Context, items: &[T], function: F) -> crate::Result<Vec<R>>
+	where T: Sync, R: Send, F: Fn(&T) -> R + Sync + Send {
+	use rayon::prelude::*;
+	let pool = context.pool()?;
+	Ok(pool.install(|| items.par_iter().map(function).collect()))
+}
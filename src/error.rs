@@ -1,7 +1,7 @@
 use codespan::FileId;
 use codespan_reporting::diagnostic;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Diagnostic(pub diagnostic::Diagnostic<FileId>);
 
 impl Diagnostic {
@@ -9,6 +9,10 @@ impl Diagnostic {
 		Self(diagnostic::Diagnostic::error())
 	}
 
+	pub fn warning() -> Self {
+		Self(diagnostic::Diagnostic::warning())
+	}
+
 	pub fn message(mut self, message: impl Into<String>) -> Self {
 		let Self(diagnostic) = &mut self;
 		diagnostic.message = message.into();
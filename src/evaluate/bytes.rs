@@ -0,0 +1,159 @@
+use std::sync::Arc;
+
+use crate::context::Context;
+use crate::error::Diagnostic;
+use crate::generate::{Absolute, Form, Offset, Section};
+use crate::node::*;
+use crate::query::Key;
+use crate::span::Span;
+
+use super::Constant;
+
+/// Evaluates a value into its representation in memory as laid
+/// out for the provided type. Addresses of symbols are recorded
+/// as absolute relocations to be resolved when linking.
+pub fn bytes(context: &Context, parent: Option<Key>, value: &Value,
+			 index: &ValueIndex, node_type: &Type) -> crate::Result<Section> {
+	let span = &value[*index].span;
+	let size = crate::node::size(context, parent.clone(), node_type, Some(span.clone()))?;
+	let mut section = Section { bytes: vec![0; size], ..Section::default() };
+	match (node_type, &value[*index].node) {
+		(_, ValueNode::Compile(index)) =>
+			bytes(context, parent, value, index, node_type),
+		(_, ValueNode::Block(block)) if !block.is_empty() =>
+			bytes(context, parent, value, block.last().unwrap(), node_type),
+		(_, ValueNode::Call(path, arguments)) if path.node == ["Intrinsic", "include"][..] => {
+			let bytes = include(context, parent, value, arguments, span)?;
+			if bytes.len() != size {
+				return context.pass(Diagnostic::error().label(span.label())
+					.message(format!("included file of size: {}, does not match type size: {}",
+						bytes.len(), size)));
+			}
+
+			section.bytes.copy_from_slice(&bytes);
+			Ok(section)
+		}
+		(Type::Array(element, _), ValueNode::Array(elements)) => {
+			let mut offset = 0;
+			for index in elements {
+				let element = self::bytes(context, parent.clone(), value, index, &element.node)?;
+				let length = element.bytes.len();
+				place(&mut section, offset, element);
+				offset += length;
+			}
+			Ok(section)
+		}
+		(Type::Structure(path), ValueNode::Create(_, fields)) => {
+			let structure = context.structures.get(path).unwrap();
+			for (identifier, (index, span)) in fields {
+				let field = &structure.fields[identifier].node;
+				let offset = crate::node::offset(context, parent.clone(),
+					node_type, identifier, Some(span.clone()))?;
+				let bits = crate::node::bits(context, parent.clone(),
+					node_type, identifier, Some(span.clone()))?;
+				if let Some(Bits { unit, shift, width }) = bits {
+					let integral = match super::constant(context, parent.clone(), value, index)? {
+						Constant::Integral(integral) => integral,
+						Constant::Address { .. } => return context.pass(Diagnostic::error()
+							.message("bit field cannot contain an address")
+							.label(span.label())),
+					};
+
+					let range = offset..offset + unit.bytes();
+					let mut bytes = [0; 16];
+					bytes[..unit.bytes()].copy_from_slice(&section.bytes[range.clone()]);
+					let mask = (1 << width) - 1;
+					if !(0..=mask).contains(&integral) {
+						let span = &value[*index].span;
+						return context.pass(Diagnostic::error().label(span.label())
+							.message(format!("value: {}, does not fit in bit field of width: {}",
+								integral, width))
+							.note(format!("expected a value within: 0..={}", mask)));
+					}
+
+					let storage = i128::from_le_bytes(bytes) | (integral << shift);
+					section.bytes[range].copy_from_slice(&storage.to_le_bytes()[..unit.bytes()]);
+					continue;
+				}
+
+				let field = self::bytes(context, parent.clone(), value, index, field)?;
+				place(&mut section, offset, field);
+			}
+			Ok(section)
+		}
+		(other, _) if other.composite() => context.pass(Diagnostic::error()
+			.message("value cannot be evaluated at compilation time")
+			.label(span.label())),
+		_ => match super::constant(context, parent, value, index)? {
+			Constant::Integral(integral) => {
+				section.bytes.copy_from_slice(&integral.to_le_bytes()[..size]);
+				Ok(section)
+			}
+			Constant::Address { symbol, addend, shift, mask } => {
+				let width = match size {
+					1 => Size::Byte,
+					2 => Size::Word,
+					4 => Size::Double,
+					8 => Size::Quad,
+					_ => return context.pass(Diagnostic::error()
+						.message("address cannot be stored in type")
+						.label(span.label())),
+				};
+
+				let form = match (shift, mask) {
+					(0, None) => Form::Direct(width),
+					(_, Some(mask)) if mask < width as u32 => Form::Masked(width, shift, mask),
+					_ => Form::Split(width, shift),
+				};
+
+				let span = span.clone();
+				let absolute = Absolute { form, offset: 0, symbol, addend, span };
+				section.absolute.push(absolute);
+				Ok(section)
+			}
+		}
+	}
+}
+
+/// Reads the file included with `Intrinsic.include`. The
+/// path is resolved relative to the including file when parsed.
+pub fn include(context: &Context, parent: Option<Key>, value: &Value,
+			   arguments: &[ValueIndex], span: &Span) -> crate::Result<Arc<[u8]>> {
+	let path = match arguments.iter().map(|index| &value[*index].node).next() {
+		Some(ValueNode::String(path)) if arguments.len() == 1 => path,
+		_ => return context.pass(Diagnostic::error()
+			.message("expected string literal").label(span.label())),
+	};
+
+	let path = std::path::Path::new(path);
+	let path = path.canonicalize().map_err(|error| context.error(Diagnostic::error()
+		.message(format!("failed to read file: {}", path.display()))
+		.note(format!("error: {}", error)).label(span.label())))?;
+	included(context, parent, &path, Some(span.clone())).map(|bytes| bytes.as_ref().clone())
+}
+
+/// Reads a file included as data. The file is read within a query
+/// such that the queries that include it are invalidated when the
+/// file is modified.
+pub fn included(context: &Context, parent: Option<Key>, path: &std::path::Path,
+				span: Option<Span>) -> crate::Result<Arc<Arc<[u8]>>> {
+	let key = Key::Include(path.to_owned());
+	context.included.scope(parent, key, span.clone(), || {
+		context.files.write().bytes(path).map_err(|error| {
+			let mut diagnostic = Diagnostic::error()
+				.message(format!("failed to read file: {}", path.display()))
+				.note(format!("error: {}", error));
+			if let Some(span) = &span {
+				diagnostic = diagnostic.label(span.label());
+			}
+			context.error(diagnostic)
+		})
+	})
+}
+
+fn place(section: &mut Section, offset: Offset, other: Section) {
+	let Section { bytes, absolute, .. } = other;
+	section.bytes[offset..offset + bytes.len()].copy_from_slice(&bytes);
+	section.absolute.extend(absolute.into_iter().map(|mut absolute|
+		(absolute.offset += offset, absolute).1));
+}
@@ -0,0 +1,9 @@
+//! Evaluation of values at compilation time.
+//! Only values that do not depend on runtime
+//! state may be evaluated.
+
+pub use bytes::*;
+pub use value::*;
+
+mod bytes;
+mod value;
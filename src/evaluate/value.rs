@@ -0,0 +1,238 @@
+use crate::context::Context;
+use crate::error::Diagnostic;
+use crate::node::*;
+use crate::query::Key;
+use crate::span::Span;
+
+/// A value that is either integral or derived from the
+/// address of a symbol resolved when the binary is linked.
+#[derive(Debug)]
+pub enum Constant {
+	Integral(i128),
+	/// The address offset by the addend and then shifted right.
+	/// The mask is the number of low bits that are preserved.
+	Address { symbol: Symbol, addend: i128, shift: u32, mask: Option<u32> },
+}
+
+pub fn constant(context: &Context, parent: Option<Key>, value: &Value,
+				index: &ValueIndex) -> crate::Result<Constant> {
+	let span = &value[*index].span;
+	Ok(match &value[*index].node {
+		ValueNode::Cast(index, _) | ValueNode::Compile(index) =>
+			constant(context, parent, value, index)?,
+		ValueNode::Block(block) if !block.is_empty() =>
+			constant(context, parent, value, block.last().unwrap())?,
+		ValueNode::Call(path, arguments) if path.node == ["Intrinsic", "start"][..] ||
+			path.node == ["Intrinsic", "end"][..] => {
+			let symbol = match arguments.first().map(|index| &value[*index].node) {
+				Some(ValueNode::Path(path)) => symbol(context, path, span)?,
+				_ => return context.pass(Diagnostic::error()
+					.message("expected path").label(span.label())),
+			};
+
+			let addend = match path.node == ["Intrinsic", "end"][..] {
+				true => crate::node::address::size(context, parent,
+					&symbol, Some(span.clone()))? as i128,
+				false => 0,
+			};
+
+			Constant::Address { symbol, addend, shift: 0, mask: None }
+		}
+		ValueNode::Binary(Binary::Dual(dual), left, right) => {
+			let left = constant(context, parent.clone(), value, left)?;
+			let right = constant(context, parent, value, right)?;
+			match (dual, left, right) {
+				(dual, Constant::Integral(left), Constant::Integral(right)) =>
+					Constant::Integral(self::dual(context, dual, left, right, span)?),
+				(Dual::Add, Constant::Address { symbol, addend, shift: 0, mask: None },
+					Constant::Integral(other)) |
+				(Dual::Add, Constant::Integral(other),
+					Constant::Address { symbol, addend, shift: 0, mask: None }) =>
+					Constant::Address { symbol, addend: addend + other, shift: 0, mask: None },
+				(Dual::Minus, Constant::Address { symbol, addend, shift: 0, mask: None },
+					Constant::Integral(other)) =>
+					Constant::Address { symbol, addend: addend - other, shift: 0, mask: None },
+				(Dual::ShiftRight, Constant::Address { symbol, addend, shift, mask: None },
+					Constant::Integral(other)) if (0..64).contains(&other) =>
+					Constant::Address { symbol, addend, shift: shift + other as u32, mask: None },
+				(Dual::BinaryAnd, Constant::Address { symbol, addend, shift, mask },
+					Constant::Integral(other)) |
+				(Dual::BinaryAnd, Constant::Integral(other),
+					Constant::Address { symbol, addend, shift, mask })
+					if other > 0 && (other + 1).count_ones() == 1 => {
+					let bits = other.trailing_ones();
+					let mask = Some(mask.map_or(bits, |mask| mask.min(bits)));
+					Constant::Address { symbol, addend, shift, mask }
+				}
+				_ => return context.pass(Diagnostic::error()
+					.message("unsupported operation on symbol address")
+					.note("addresses may only be offset, shifted right or masked")
+					.label(span.label())),
+			}
+		}
+		_ => Constant::Integral(integral(context, parent, value, index)?),
+	})
+}
+
+pub fn integral(context: &Context, parent: Option<Key>, value: &Value,
+				index: &ValueIndex) -> crate::Result<i128> {
+	let span = &value[*index].span;
+	Ok(match &value[*index].node {
+		ValueNode::Integral(integral) => *integral,
+		ValueNode::Truth(truth) => *truth as i128,
+		ValueNode::Rune(rune) => *rune as i128,
+		ValueNode::Cast(index, _) | ValueNode::Compile(index) =>
+			integral(context, parent, value, index)?,
+		ValueNode::Block(block) if !block.is_empty() =>
+			integral(context, parent, value, block.last().unwrap())?,
+		ValueNode::Unary(Unary::Negate, index) =>
+			integral(context, parent, value, index)?.wrapping_neg(),
+		ValueNode::Unary(Unary::Not, index) =>
+			!integral(context, parent, value, index)?,
+		ValueNode::Binary(Binary::Dual(dual), left, right) => {
+			let left = integral(context, parent.clone(), value, left)?;
+			let right = integral(context, parent, value, right)?;
+			self::dual(context, dual, left, right, span)?
+		}
+		ValueNode::Binary(Binary::Compare(compare), left, right) => {
+			let left = integral(context, parent.clone(), value, left)?;
+			let right = integral(context, parent, value, right)?;
+			(match compare {
+				Compare::Less => left < right,
+				Compare::Greater => left > right,
+				Compare::LessEqual => left <= right,
+				Compare::GreaterEqual => left >= right,
+				Compare::NotEqual => left != right,
+				Compare::Equal => left == right,
+			}) as i128
+		}
+		ValueNode::Binary(Binary::And, left, right) =>
+			(integral(context, parent.clone(), value, left)? != 0 &&
+				integral(context, parent, value, right)? != 0) as i128,
+		ValueNode::Binary(Binary::Or, left, right) =>
+			(integral(context, parent.clone(), value, left)? != 0 ||
+				integral(context, parent, value, right)? != 0) as i128,
+		ValueNode::Path(path) => {
+			let variable = context.statics.get(path);
+			match variable.as_ref().and_then(|variable| variable.value.as_ref()) {
+				Some(other) => integral(context, parent, other, &other.root)?,
+				None => return context.pass(Diagnostic::error()
+					.message("static variable has no value").label(span.label())),
+			}
+		}
+		ValueNode::Call(path, arguments) if path.node == ["Intrinsic", "offset"][..] => {
+			let argument = arguments.get(1).map(|index| &value[*index].node);
+			match (arguments.first().map(|index| &value[*index].node), argument) {
+				(Some(ValueNode::Type(node_type @ Type::Structure(structure))),
+					Some(ValueNode::Field(_, field))) => {
+					let fields = &context.structures.get(structure)
+						.ok_or(crate::query::QueryError::Failure)?.fields;
+					if !fields.contains_key(&field.node) {
+						return context.pass(Diagnostic::error()
+							.message(format!("data structure has no field: {}", field.node))
+							.label(field.span.label()));
+					}
+
+					crate::node::offset(context, parent, node_type,
+						&field.node, Some(span.clone()))? as i128
+				}
+				_ => return context.pass(Diagnostic::error()
+					.message("expected data structure and field").label(span.label())),
+			}
+		}
+		ValueNode::Call(path, arguments) if path.node == ["Intrinsic", "align"][..] => {
+			match arguments.first().map(|index| &value[*index].node) {
+				Some(ValueNode::Type(node_type)) => crate::node::alignment(context,
+					parent, node_type, Some(span.clone()))? as i128,
+				_ => return context.pass(Diagnostic::error()
+					.message("expected type").label(span.label())),
+			}
+		}
+		ValueNode::Call(path, arguments) if path.node == ["Intrinsic", "length"][..] => {
+			let path = match arguments.first().map(|index| &value[*index].node) {
+				Some(ValueNode::Path(path)) if context.statics.contains_key(path) => path,
+				_ => return context.pass(Diagnostic::error()
+					.message("expected static variable").label(span.label())),
+			};
+
+			let node_type = crate::inference::type_variable(context,
+				parent.clone(), path.clone(), Some(span.clone()))?;
+			match &node_type.node {
+				Type::Array(_, length) => crate::node::length(context, parent, length)? as i128,
+				other => return context.pass(Diagnostic::error()
+					.message(format!("expected array, found: {}", other))
+					.label(span.label())),
+			}
+		}
+		ValueNode::Call(path, arguments) if path.node == ["Intrinsic", "assert"][..] => {
+			let condition = integral(context, parent, value, &arguments[0])?;
+			let message = match &value[arguments[1]].node {
+				ValueNode::String(message) => message,
+				_ => return context.pass(Diagnostic::error()
+					.message("expected string literal").label(span.label())),
+			};
+
+			match condition {
+				0 => return context.pass(Diagnostic::error()
+					.message(format!("assertion failed: {}", message))
+					.label(span.label())),
+				_ => 1,
+			}
+		}
+		ValueNode::Call(path, arguments) if path.node == ["Intrinsic", "size"][..] ||
+			path.node == ["Intrinsic", "start"][..] || path.node == ["Intrinsic", "end"][..] ||
+			path.node == ["Intrinsic", "load"][..] => {
+			let symbol = match arguments.first().map(|index| &value[*index].node) {
+				Some(ValueNode::Path(path)) => symbol(context, path, span)?,
+				_ => return context.pass(Diagnostic::error()
+					.message("expected path").label(span.label())),
+			};
+
+			let span = Some(span.clone());
+			let Path(elements) = &path.node;
+			(match elements.last().map(|Identifier(string)| string.as_str()) {
+				Some("size") => crate::node::address::size(context, parent, &symbol, span),
+				Some("start") => crate::node::address::start(context, parent, &symbol, span),
+				Some("load") => crate::node::address::load(context, parent, &symbol, span),
+				_ => crate::node::address::end(context, parent, &symbol, span),
+			})? as i128
+		}
+		_ => return context.pass(Diagnostic::error()
+			.message("value cannot be evaluated at compilation time")
+			.label(span.label())),
+	})
+}
+
+/// Resolves a path to a module, static or unambiguous function.
+pub fn symbol(context: &Context, path: &Path, span: &Span) -> crate::Result<Symbol> {
+	if context.modules.contains_key(path) {
+		return Ok(Symbol::Module(path.clone()));
+	} else if context.statics.contains_key(path) {
+		return Ok(Symbol::Variable(path.clone()));
+	}
+
+	match context.functions.get(path).map(|functions| functions.len()) {
+		Some(1) => Ok(Symbol::Function(FunctionPath(path.clone(), 0))),
+		_ => context.pass(Diagnostic::error()
+			.message("ambiguous function").label(span.label())),
+	}
+}
+
+fn dual(context: &Context, dual: &Dual, left: i128,
+		right: i128, span: &Span) -> crate::Result<i128> {
+	Ok(match dual {
+		Dual::Add => left.wrapping_add(right),
+		Dual::Minus => left.wrapping_sub(right),
+		Dual::Multiply => left.wrapping_mul(right),
+		Dual::Divide | Dual::Modulo if right == 0 =>
+			return context.pass(Diagnostic::error()
+				.message("division by zero").label(span.label())),
+		Dual::Divide => left.wrapping_div(right),
+		Dual::Modulo => left.wrapping_rem(right),
+		Dual::BinaryOr => left | right,
+		Dual::BinaryAnd => left & right,
+		Dual::ExclusiveOr => left ^ right,
+		Dual::ShiftLeft => left.wrapping_shl(right as u32),
+		Dual::ShiftRight => left.wrapping_shr(right as u32),
+	})
+}
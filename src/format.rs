@@ -0,0 +1,647 @@
+use std::ops::Range;
+
+use codespan::FileId;
+use tree_sitter::Node;
+
+use crate::context::Context;
+use crate::error::Diagnostic;
+use crate::options::Format;
+use crate::span::Span;
+
+/// Formats each source file in place or reports the files that are
+/// not formatted when checking. Returns whether every file is formatted.
+pub fn format(context: &Context, format: &Format) -> std::io::Result<bool> {
+	let mut formatted = true;
+	for path in &format.paths {
+		let canonical = path.canonicalize().ok();
+		let source = canonical.and_then(|path| context.files.write().query(&path));
+		let (file, text) = match source {
+			Some(source) => source,
+			None => {
+				context.emit(Diagnostic::error()
+					.message(format!("invalid file: {}", path.display())));
+				formatted = false;
+				continue;
+			}
+		};
+
+		match self::source(context, file, &text) {
+			None => formatted = false,
+			Some(other) if other == *text => (),
+			Some(other) if format.check => {
+				let span = Span::new(difference(&text, &other), file);
+				context.emit(Diagnostic::error().label(span.label())
+					.message(format!("unformatted file: {}", path.display()))
+					.note("format with: lucent fmt"));
+				formatted = false;
+			}
+			Some(other) => std::fs::write(path, other)?,
+		}
+	}
+	Ok(formatted)
+}
+
+/// Returns the formatted text of a source file. Returns `None` if the
+/// source file has syntax errors or if formatting would change its tokens.
+pub fn source(context: &Context, file: FileId, text: &str) -> Option<String> {
+	let tree = crate::parse::parser().parse(text.as_bytes(), None).unwrap();
+	if tree.root_node().has_error() {
+		crate::parse::recover(context, file, text, tree);
+		return None;
+	}
+
+	let mut formatter = Formatter::new(text, tree.root_node());
+	formatter.lines(named(tree.root_node()), Formatter::item, Formatter::separate);
+	if let Some(node) = formatter.invalid {
+		let span = Span::new(node.byte_range(), file);
+		return context.pass(Diagnostic::error().label(span.label())
+			.message(format!("cannot format item of kind: {}", node.kind()))).ok();
+	}
+
+	let other = formatter.finish();
+	let formatted = crate::parse::parser().parse(other.as_bytes(), None).unwrap();
+	if formatted.root_node().has_error() {
+		return context.pass(Diagnostic::error().label(Span::new(0..0, file).label())
+			.message("formatted source is invalid")).ok();
+	}
+
+	let (tokens, others) = (tokens(text, tree.root_node()), tokens(&other, formatted.root_node()));
+	let mismatch = (0..tokens.len().max(others.len())).find(|index|
+		tokens.get(*index).map(|node| &text[node.byte_range()]) !=
+			others.get(*index).map(|node| &other[node.byte_range()]));
+	match mismatch {
+		None => Some(other),
+		Some(index) => {
+			let range = tokens.get(index).map(Node::byte_range);
+			let span = Span::new(range.unwrap_or(text.len()..text.len()), file);
+			context.pass(Diagnostic::error().label(span.label())
+				.message("formatting changes the tokens of the source")).ok()
+		}
+	}
+}
+
+/// Returns the range of the first line that differs from the formatted text.
+fn difference(text: &str, other: &str) -> Range<usize> {
+	let mut start = 0;
+	for (line, other) in text.split('\n').zip(other.split('\n')) {
+		if line != other { return start..start + line.len(); }
+		start += line.len() + 1;
+	}
+	start.min(text.len())..text.len()
+}
+
+struct Formatter<'a> {
+	text: &'a str,
+	/// The ranges of every comment in order.
+	comments: Vec<Range<usize>>,
+	/// The index of the first comment that is not written.
+	next: usize,
+	lines: Vec<String>,
+	line: String,
+	/// The indentation of the current line.
+	indent: usize,
+	level: usize,
+	/// The end of the last node that is written.
+	last: usize,
+	/// The first item that cannot be formatted.
+	invalid: Option<Node<'a>>,
+}
+
+impl<'a> Formatter<'a> {
+	fn new(text: &'a str, root: Node) -> Self {
+		let (mut comments, mut last) = (Vec::new(), 0);
+		let leaf = &mut |leaf: Node| {
+			gap(text, last..leaf.start_byte(), &mut comments);
+			last = leaf.end_byte();
+		};
+
+		named(root).into_iter().for_each(|node| leaves(node, leaf));
+
+		gap(text, last..text.len(), &mut comments);
+		Formatter { text, comments, next: 0, lines: Vec::new(),
+			line: String::new(), indent: 0, level: 0, last: 0, invalid: None }
+	}
+
+	fn finish(mut self) -> String {
+		self.end_line();
+		self.comments(self.text.len());
+		while self.lines.last().filter(|line| line.is_empty()).is_some() {
+			self.lines.pop();
+		}
+
+		self.lines.iter().map(|line| line.clone() + "\n").collect()
+	}
+
+	fn write(&mut self, string: &str) {
+		if self.line.is_empty() { self.indent = self.level; }
+		self.line.push_str(string);
+	}
+
+	fn leaf(&mut self, node: Node) {
+		let text = self.text;
+		self.write(&text[node.byte_range()]);
+		self.last = self.last.max(node.end_byte());
+	}
+
+	/// Writes the anonymous child of a node with a kind.
+	fn token(&mut self, node: Node, kind: &str) {
+		let cursor = &mut node.walk();
+		let token = node.children(cursor).find(|child| child.kind() == kind);
+		token.into_iter().for_each(|token| self.leaf(token));
+	}
+
+	fn push(&mut self, indent: usize, line: &str) {
+		self.lines.push("\t".repeat(indent) + line);
+	}
+
+	fn blank(&mut self) {
+		if self.lines.last().filter(|line| !line.is_empty()).is_some() {
+			self.lines.push(String::new());
+		}
+	}
+
+	/// Ends the current line. Comments within the line are written
+	/// before the line unless there is only one comment in which case
+	/// it is written at the end of the line with any trailing comment.
+	fn end_line(&mut self) {
+		let mut inner = Vec::new();
+		while let Some(comment) = self.comments.get(self.next)
+			.filter(|comment| comment.start < self.last).cloned() {
+			inner.push(comment);
+			self.next += 1;
+		}
+
+		let trailing = self.comments.get(self.next).filter(|comment|
+			!self.text[self.last..comment.start].contains('\n')).cloned();
+		if trailing.is_some() { self.next += 1; }
+		let trailing = match (trailing, inner.len()) {
+			(None, 1) => inner.pop(),
+			(trailing, _) => trailing,
+		};
+
+		let indent = match self.line.is_empty() {
+			true => self.level,
+			false => self.indent,
+		};
+
+		let text = self.text;
+		inner.iter().for_each(|comment| self.push(indent, &text[comment.clone()]));
+		let mut line = std::mem::take(&mut self.line);
+		if let Some(comment) = trailing {
+			if !line.is_empty() { line.push(' '); }
+			line.push_str(&text[comment]);
+		}
+
+		if !line.is_empty() { self.push(indent, &line); }
+	}
+
+	/// Writes the comments before a position on their own lines.
+	fn comments(&mut self, position: usize) {
+		while let Some(comment) = self.comments.get(self.next)
+			.filter(|comment| comment.start < position).cloned() {
+			let text = self.text;
+			self.push(self.level, &text[comment.clone()]);
+			self.next += 1;
+
+			let end = self.comments.get(self.next).map(|other| other.start)
+				.filter(|start| *start < position).unwrap_or(position);
+			if blank(&text[comment.end..end]) { self.blank(); }
+		}
+	}
+
+	/// Writes each node on its own lines at the current level.
+	fn lines(&mut self, nodes: Vec<Node<'a>>, print: fn(&mut Self, Node<'a>),
+			 separate: fn(&Self, Node, Node) -> bool) {
+		let mut previous: Option<Node> = None;
+		for node in nodes {
+			if previous.filter(|previous| separate(self, *previous, node)).is_some() {
+				self.blank();
+			}
+
+			self.comments(node.start_byte());
+			print(self, node);
+			self.end_line();
+			previous = Some(node);
+		}
+	}
+
+	/// Items are separated by a blank line except for consecutive
+	/// global annotations and consecutive static variables or imports
+	/// that are only separated if they are separated in the source.
+	fn separate(&self, previous: Node, node: Node) -> bool {
+		match (previous.kind(), node.kind()) {
+			("global_annotation", "global_annotation") => false,
+			("use", "use") | ("static", "static") => self.preserve(previous, node),
+			_ => true,
+		}
+	}
+
+	/// Whether two nodes are separated by a blank line in the source.
+	fn preserve(&self, previous: Node, node: Node) -> bool {
+		let cursor = &mut previous.walk();
+		let newline = previous.children(cursor).last().filter(|child| child.kind() == "\n");
+		let end = newline.map(|newline| newline.start_byte()).unwrap_or(previous.end_byte());
+		blank(&self.text[end..node.start_byte()])
+	}
+
+	fn item(&mut self, node: Node<'a>) {
+		let cursor = &mut node.walk();
+		for annotation in node.children_by_field_name("annotation", cursor) {
+			self.comments(annotation.start_byte());
+			self.annotation(annotation);
+			self.end_line();
+		}
+
+		let cursor = &mut node.walk();
+		let start = node.children(cursor).find(|child| child.kind() != "annotation");
+		self.comments(start.map(|start| start.start_byte()).unwrap_or(node.end_byte()));
+		let field = |name: &str| node.child_by_field_name(name);
+		match node.kind() {
+			"global_annotation" => {
+				self.write("@@");
+				self.leaf(field("name").unwrap());
+				self.write(" ");
+				self.value(field("value").unwrap());
+			}
+			"module" => {
+				self.token(node, "module");
+				self.write(" ");
+				self.leaf(field("identifier").unwrap());
+				self.nested(fields(node, "item"), Self::item, Self::separate);
+			}
+			"function" => self.function(node),
+			"static" => {
+				self.token(node, "static");
+				self.write(" ");
+				self.leaf(field("identifier").unwrap());
+				if let Some(node_type) = field("type") {
+					self.write(": ");
+					self.value(node_type);
+				}
+
+				if let Some(value) = field("value") {
+					self.write(" = ");
+					self.value(value);
+				}
+			}
+			"data" => {
+				self.token(node, "data");
+				self.write(" ");
+				self.leaf(field("identifier").unwrap());
+				self.nested(fields(node, "field"), Self::parameter, Self::preserve);
+			}
+			"use" => {
+				self.token(node, "use");
+				self.write(" ");
+				self.value(field("path").unwrap());
+				if let Some(with) = field("with") {
+					self.write(" with ");
+					self.leaf(with);
+				}
+
+				if let Some(node_as) = field("as") {
+					self.write(" as ");
+					match node_as.kind() {
+						"static" => self.parameter(node_as),
+						_ => self.value(node_as),
+					}
+				}
+			}
+			_ => {
+				self.invalid = self.invalid.or(Some(node));
+				self.leaf(node);
+			}
+		}
+	}
+
+	fn annotation(&mut self, node: Node<'a>) {
+		self.write("@");
+		self.leaf(node.child_by_field_name("name").unwrap());
+		self.write(" ");
+		self.value(node.child_by_field_name("value").unwrap());
+	}
+
+	fn function(&mut self, node: Node<'a>) {
+		let field = |name: &str| node.child_by_field_name(name);
+		for node in field("root").into_iter().chain(field("convention")) {
+			self.leaf(node);
+			self.write(" ");
+		}
+
+		self.token(node, "fn");
+		self.write(" ");
+		self.leaf(field("identifier").unwrap());
+		self.write("(");
+		self.separated(fields(node, "parameter"), ", ", |formatter, node|
+			match node.kind() {
+				"parameter" => formatter.parameter(node),
+				_ => formatter.leaf(node),
+			});
+		self.write(")");
+
+		if let Some(node_type) = field("return") {
+			self.write(" ");
+			self.value(node_type);
+		}
+
+		let block = field("block").unwrap();
+		match block.kind() {
+			"block" => self.block(block),
+			_ => {
+				self.write(" = ");
+				self.value(block);
+			}
+		}
+	}
+
+	fn parameter(&mut self, node: Node<'a>) {
+		self.leaf(node.child_by_field_name("identifier").unwrap());
+		self.write(": ");
+		self.value(node.child_by_field_name("type").unwrap());
+	}
+
+	/// Writes the lines of a sequence indented within the current line.
+	fn nested(&mut self, nodes: Vec<Node<'a>>, print: fn(&mut Self, Node<'a>),
+			  separate: fn(&Self, Node, Node) -> bool) {
+		self.end_line();
+		self.level += 1;
+		self.lines(nodes, print, separate);
+		self.level -= 1;
+	}
+
+	fn block(&mut self, node: Node<'a>) {
+		self.nested(named(node), Self::statement, Self::preserve);
+	}
+
+	fn statement(&mut self, node: Node<'a>) {
+		let field = |name: &str| node.child_by_field_name(name);
+		match node.kind() {
+			"let" => {
+				self.token(node, "let");
+				self.write(" ");
+				self.leaf(field("identifier").unwrap());
+				if let Some(node_type) = field("type") {
+					self.write(": ");
+					self.value(node_type);
+				}
+
+				if let Some(value) = field("value") {
+					self.write(" = ");
+					self.statement(value);
+				}
+			}
+			"set" => {
+				self.value(field("target").unwrap());
+				self.write(" = ");
+				self.statement(field("value").unwrap());
+			}
+			"compound" => {
+				self.value(field("target").unwrap());
+				self.write(" ");
+				self.leaf(field("operator").unwrap());
+				self.write("= ");
+				self.statement(field("value").unwrap());
+			}
+			"return" => {
+				self.token(node, "return");
+				if let Some(value) = field("value") {
+					self.write(" ");
+					self.statement(value);
+				}
+			}
+			"while" => {
+				self.token(node, "while");
+				self.write(" ");
+				self.value(field("condition").unwrap());
+				self.token(node, ":");
+				self.body(field("block").unwrap());
+			}
+			"when" => match named(node).as_slice() {
+				[branch] if node.child(0).filter(|child| child.kind() == "if").is_some() => {
+					self.token(node, "if");
+					self.write(" ");
+					self.branch(*branch);
+				}
+				_ => {
+					self.token(node, "when");
+					self.token(node, ":");
+					self.nested(named(node), Self::branch, Self::preserve);
+				}
+			},
+			"block" => self.block(node),
+			_ => self.value(node),
+		}
+	}
+
+	fn branch(&mut self, node: Node<'a>) {
+		self.value(node.child_by_field_name("condition").unwrap());
+		self.token(node, ":");
+		self.body(node.child_by_field_name("branch").unwrap());
+	}
+
+	/// Writes the statement of a branch or loop. Blocks with a single
+	/// statement that fits on one line are collapsed onto the same line.
+	fn body(&mut self, node: Node<'a>) {
+		match node.kind() {
+			"block" => match named(node).as_slice() {
+				[statement] if self.collapse(*statement) => {
+					self.write(" ");
+					self.statement(*statement);
+				}
+				_ => self.block(node),
+			},
+			_ => {
+				self.write(" ");
+				self.statement(node);
+			}
+		}
+	}
+
+	fn collapse(&self, node: Node) -> bool {
+		let comment = self.comments[self.next..].iter()
+			.any(|comment| comment.start < node.end_byte());
+		!comment && !descendants(node).iter().any(|node|
+			["block", "when", "while"].contains(&node.kind()))
+	}
+
+	fn value(&mut self, node: Node<'a>) {
+		let field = |name: &str| node.child_by_field_name(name);
+		match node.kind() {
+			"binary" => {
+				self.value(field("left").unwrap());
+				self.write(" ");
+				self.leaf(field("operator").unwrap());
+				self.write(" ");
+				self.value(field("right").unwrap());
+			}
+			"unary" => {
+				let operator = field("operator").unwrap();
+				self.leaf(operator);
+				if operator.kind() == "inline" { self.write(" "); }
+				self.value(field("value").unwrap());
+			}
+			"call" => {
+				self.value(field("function").unwrap());
+				self.write("(");
+				self.separated(fields(node, "argument"), ", ", Self::value);
+				self.write(")");
+			}
+			"cast" => {
+				self.value(field("value").unwrap());
+				self.write(" as ");
+				self.value(field("type").unwrap());
+			}
+			"index" => {
+				self.value(field("value").unwrap());
+				self.write("[");
+				self.value(field("index").unwrap());
+				self.write("]");
+			}
+			"slice" => {
+				self.value(field("value").unwrap());
+				self.write("[");
+				field("left").into_iter().for_each(|node| self.value(node));
+				self.write(":");
+				field("right").into_iter().for_each(|node| self.value(node));
+				self.write("]");
+			}
+			"access" => {
+				self.value(field("value").unwrap());
+				self.write(".");
+				self.leaf(field("field").unwrap());
+			}
+			"create" => {
+				self.value(field("path").unwrap());
+				self.write(" ~ ");
+				self.separated(fields(node, "field"), ", ", |formatter, node|
+					match node.kind() {
+						"field" => {
+							formatter.leaf(node.child_by_field_name("name").unwrap());
+							formatter.write(" = ");
+							formatter.value(node.child_by_field_name("value").unwrap());
+						}
+						_ => formatter.leaf(node),
+					});
+			}
+			"group" => {
+				self.write("(");
+				self.value(node.named_child(0).unwrap());
+				self.write(")");
+			}
+			"array" => {
+				self.write("[");
+				self.separated(named(node), ", ", Self::value);
+				self.write("]");
+			}
+			"path" => self.separated(named(node), ".", Self::leaf),
+			"pointer" => {
+				self.write("*");
+				self.value(node.named_child(0).unwrap());
+			}
+			"slice_type" => {
+				self.write("[");
+				self.value(field("type").unwrap());
+				self.write(";]");
+			}
+			"array_type" => {
+				self.write("[");
+				self.value(field("type").unwrap());
+				self.write("; ");
+				self.value(field("size").unwrap());
+				self.write("]");
+			}
+			"signature" => {
+				if let Some(convention) = field("convention") {
+					self.leaf(convention);
+					self.write(" ");
+				}
+
+				self.token(node, "fn");
+				self.write(" ");
+				self.leaf(field("identifier").unwrap());
+				self.write("(");
+				self.separated(fields(node, "parameter"), ", ", Self::value);
+				self.write(")");
+				if let Some(node_type) = field("return") {
+					self.write(" ");
+					self.value(node_type);
+				}
+			}
+			_ => self.leaf(node),
+		}
+	}
+
+	fn separated(&mut self, nodes: Vec<Node<'a>>, separator: &str,
+				 mut print: impl FnMut(&mut Self, Node<'a>)) {
+		for (index, node) in nodes.into_iter().enumerate() {
+			if index > 0 { self.write(separator); }
+			print(self, node);
+		}
+	}
+}
+
+fn named(node: Node) -> Vec<Node> {
+	let cursor = &mut node.walk();
+	node.named_children(cursor).collect()
+}
+
+fn fields<'a>(node: Node<'a>, field: &str) -> Vec<Node<'a>> {
+	let cursor = &mut node.walk();
+	node.children_by_field_name(field, cursor).collect()
+}
+
+fn descendants(node: Node) -> Vec<Node> {
+	let mut nodes = Vec::new();
+	let mut stack = named(node);
+	while let Some(node) = stack.pop() {
+		stack.extend(named(node));
+		nodes.push(node);
+	}
+	nodes
+}
+
+fn leaves<'a>(node: Node<'a>, function: &mut impl FnMut(Node<'a>)) {
+	match node.child_count() {
+		0 => function(node),
+		_ => (0..node.child_count()).filter_map(|index| node.child(index))
+			.for_each(|child| leaves(child, function)),
+	}
+}
+
+/// Returns the leaves of a tree in order that are not whitespace
+/// or trailing commas as trailing commas are removed when formatting.
+fn tokens<'a>(text: &str, root: Node<'a>) -> Vec<Node<'a>> {
+	let empty = |node: Node| text[node.byte_range()].trim().is_empty();
+	let trailing = |mut node: Node| {
+		while let Some(next) = node.next_sibling() {
+			if !empty(next) { return false; }
+			node = next;
+		}
+		true
+	};
+
+	let mut tokens = Vec::new();
+	leaves(root, &mut |leaf| if !empty(leaf) &&
+		!(leaf.kind() == "," && trailing(leaf)) { tokens.push(leaf) });
+	tokens
+}
+
+/// Adds the comments within the text between two nodes.
+fn gap(text: &str, range: Range<usize>, comments: &mut Vec<Range<usize>>) {
+	let mut start = range.start;
+	while let Some(index) = text[start..range.end].find("//") {
+		let comment = start + index;
+		let end = text[comment..range.end].find('\n')
+			.map(|end| comment + end).unwrap_or(range.end);
+		let end = comment + text[comment..end].trim_end().len();
+		comments.push(comment..end);
+		start = end;
+	}
+}
+
+/// Whether the text between two nodes contains a blank line.
+fn blank(text: &str) -> bool {
+	let lines: Vec<_> = text.split('\n').collect();
+	lines.len() > 2 && lines[1..lines.len() - 1]
+		.iter().any(|line| line.trim().is_empty())
+}
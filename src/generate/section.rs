@@ -1,18 +1,61 @@
-use crate::node::{FunctionPath, Size};
+use serde::{Deserialize, Serialize};
+
+use crate::node::{Size, Symbol};
+use crate::span::Span;
 
 pub type Offset = usize;
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Section {
 	pub bytes: Vec<u8>,
 	// TODO: replace with compile time execution nodes
 	pub relative: Vec<Relative>,
+	pub absolute: Vec<Absolute>,
+	pub strings: Vec<Literal>,
 }
 
-#[derive(Debug, Clone)]
+/// A reference to the address of a symbol relative
+/// to the address of the target offset.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Relative {
 	pub size: Size,
 	pub offset: Offset,
 	pub target: Offset,
-	pub path: FunctionPath,
+	pub symbol: Symbol,
+	pub addend: i128,
+	pub span: Span,
+}
+
+/// An absolute reference to the address of a symbol.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Absolute {
+	pub form: Form,
+	pub offset: Offset,
+	pub symbol: Symbol,
+	pub addend: i128,
+	pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Form {
+	/// The address which must fit within the size.
+	Direct(Size),
+	/// The address shifted right and truncated to the size.
+	/// Used for addresses split across multiple fields.
+	Split(Size, u32),
+	/// The address shifted right and truncated to
+	/// a mask of low bits narrower than the size.
+	Masked(Size, u32, u32),
+	/// A real mode far pointer with the
+	/// offset preceding the segment.
+	Segmented,
+}
+
+/// An absolute reference to a string literal
+/// placed in the binary string table.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Literal {
+	pub size: Size,
+	pub offset: Offset,
+	pub bytes: Vec<u8>,
 }
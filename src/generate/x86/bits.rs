@@ -0,0 +1,55 @@
+use iced_x86::Code;
+use iced_x86::Instruction as I;
+use iced_x86::MemoryOperand as M;
+
+use crate::node::{Bits, Size};
+use crate::span::Span;
+
+use super::{Registers, Translation};
+
+/// Extracts a bit field from its storage unit in the
+/// registers such that the field is zero extended.
+pub fn extract(prime: &mut Translation, registers: &Registers, bits: Bits, span: &Span) {
+	define_note!(note, prime, span);
+	let Bits { unit, shift, width } = bits;
+	let register = registers[unit];
+	let left = unit as u8 - shift - width;
+	if left > 0 {
+		note(I::with_reg_i32(code_m!(unit, Shl_r, _imm8), register, left as i32));
+	}
+
+	if left + shift > 0 {
+		note(I::with_reg_i32(code_m!(unit, Shr_r, _imm8),
+			register, (left + shift) as i32));
+	}
+}
+
+/// Replaces a bit field in its storage unit in memory with the value
+/// in the registers. The storage unit is rotated such that the field
+/// is cleared and combined without requiring a mask or another register.
+pub fn insert(prime: &mut Translation, registers: &Registers,
+			  bits: Bits, memory: M, span: &Span) {
+	define_note!(note, prime, span);
+	let Bits { unit, shift, width } = bits;
+	let register = registers[unit];
+	let clear = (unit as u8 - width) as i32;
+	note(I::with_reg_i32(code_m!(unit, Shl_r, _imm8), register, clear));
+	note(I::with_reg_i32(code_m!(unit, Shr_r, _imm8), register, clear));
+
+	if shift > 0 {
+		note(I::with_mem_i32(code_m!(unit, Ror_r, _imm8), memory, shift as i32));
+	}
+
+	note(I::with_mem_i32(code_m!(unit, Shr_r, _imm8), memory, width as i32));
+	note(I::with_mem_i32(code_m!(unit, Shl_r, _imm8), memory, width as i32));
+	note(I::with_mem_reg(match unit {
+		Size::Byte => Code::Or_rm8_r8,
+		Size::Word => Code::Or_rm16_r16,
+		Size::Double => Code::Or_rm32_r32,
+		Size::Quad => Code::Or_rm64_r64,
+	}, memory, register));
+
+	if shift > 0 {
+		note(I::with_mem_i32(code_m!(unit, Rol_r, _imm8), memory, shift as i32));
+	}
+}
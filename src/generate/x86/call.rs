@@ -4,7 +4,8 @@ use iced_x86::MemoryOperand as M;
 
 use crate::context::Context;
 use crate::inference::Types;
-use crate::node::{FunctionPath, Path, Size, Type, Value, ValueIndex};
+use crate::generate::Form;
+use crate::node::{FunctionPath, Path, Size, Symbol, Type, Value, ValueIndex};
 use crate::span::{S, Span};
 
 use super::{Mode, Scene, Translation};
@@ -12,6 +13,10 @@ use super::{Mode, Scene, Translation};
 pub fn call(context: &Context, scene: &mut Scene, prime: &mut Translation,
 			types: &Types, value: &Value, index: &ValueIndex, path: &S<Path>,
 			arguments: &[ValueIndex], span: &Span) -> crate::Result<()> {
+	if super::intrinsic(context, scene, prime, value, index, path, arguments, span)? {
+		return Ok(());
+	}
+
 	let reserved: Vec<_> = scene.reserved.iter().cloned().collect();
 	reserved.iter().rev().for_each(|registers|
 		prime.push(I::with_reg(super::code_push(scene.mode.size()),
@@ -50,12 +55,10 @@ pub fn call(context: &Context, scene: &mut Scene, prime: &mut Translation,
 		note(I::with_reg(super::code_push(scene.mode.size()), scene.mode_primary()));
 	}
 
-	let call_index = prime.instructions.len();
 	let path = FunctionPath(path.node.clone(), types.functions[index]);
-	prime.calls.push((call_index, path));
+	invoke(context, scene, prime, path, span);
 
 	define_note!(note, prime, span);
-	note(I::with_branch(relative!(scene.mode, Call), 0));
 	note(I::with_reg_i32(match scene.mode {
 		Mode::Protected => Code::Add_rm32_imm32,
 		Mode::Long => Code::Add_rm64_imm32,
@@ -77,3 +80,32 @@ pub fn call(context: &Context, scene: &mut Scene, prime: &mut Translation,
 		prime.push(I::with_reg(super::code_pop(scene.mode.size()),
 			registers[scene.mode.size()]), span)))
 }
+
+/// Calls a function with a relative call or through its absolute
+/// address if the function is out of range of a relative call.
+/// The primary register is overwritten by an absolute call.
+pub fn invoke(context: &Context, scene: &Scene, prime: &mut Translation,
+			  path: FunctionPath, span: &Span) {
+	let index = prime.instructions.len();
+	if !context.far.read().contains(&path) {
+		prime.calls.push((index, path));
+		return prime.push(I::with_branch(relative!(scene.mode, Call), 0), span);
+	}
+
+	let symbol = Symbol::Function(path);
+	let size = scene.mode.size();
+	match scene.mode {
+		Mode::Real => {
+			prime.absolutes.push((index, Form::Segmented, symbol, 0));
+			prime.push(I::with_far_branch(Code::Call_ptr1616, 0, 0), span);
+		}
+		mode => {
+			super::absolute(scene, prime, symbol, 0, span);
+			define_note!(note, prime, span);
+			note(I::with_reg(match mode {
+				Mode::Protected => Code::Call_rm32,
+				_ => Code::Call_rm64,
+			}, scene.primary[size]));
+		}
+	}
+}
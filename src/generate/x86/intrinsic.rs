@@ -0,0 +1,66 @@
+use iced_x86::Code;
+use iced_x86::Instruction as I;
+
+use crate::context::Context;
+use crate::node::{Path, Size, Value, ValueIndex, ValueNode};
+use crate::span::{S, Span};
+
+use super::{Scene, Translation};
+
+/// Lowers a call to an intrinsic function. Returns
+/// false if the path is not a runtime intrinsic.
+pub fn intrinsic(context: &Context, scene: &mut Scene, prime: &mut Translation,
+				 value: &Value, index: &ValueIndex, path: &S<Path>,
+				 arguments: &[ValueIndex], span: &Span) -> crate::Result<bool> {
+	Ok(match &path.node {
+		path if path == &["Intrinsic", "terminated"][..] => {
+			let mut bytes = match &value[arguments[0]].node {
+				ValueNode::String(string) => string.as_bytes().to_vec(),
+				other => panic!("value: {:?}, is not a string", other),
+			};
+
+			bytes.push(0);
+			super::literal(scene, prime, bytes, span);
+			true
+		}
+		path if path == &["Intrinsic", "include"][..] => {
+			let parent = scene.parent.clone();
+			let bytes = crate::evaluate::include(context, parent, value, arguments, span)?;
+			super::literal(scene, prime, bytes.to_vec(), span);
+			true
+		}
+		path if path == &["Intrinsic", "start"][..] ||
+			path == &["Intrinsic", "end"][..] => {
+			let symbol = match &value[arguments[0]].node {
+				ValueNode::Path(path) => crate::evaluate::symbol(context, path, span)?,
+				other => panic!("value: {:?}, is not a path", other),
+			};
+
+			let addend = match path == &["Intrinsic", "end"][..] {
+				false => 0,
+				true => crate::node::address::size(context, scene.parent
+					.clone(), &symbol, Some(span.clone()))? as i128,
+			};
+
+			super::address(scene, prime, symbol, addend, span);
+			true
+		}
+		path if path == &["Intrinsic", "assert"][..] => {
+			let truth = crate::evaluate::integral(context,
+				scene.parent.clone(), value, index)?;
+			prime.push(I::with_reg_u32(Code::Mov_r8_imm8,
+				scene.primary[Size::Byte], (truth != 0) as u32), span);
+			true
+		}
+		path if ["size", "load", "length", "offset", "align"].iter()
+			.any(|intrinsic| path == &["Intrinsic", *intrinsic][..]) => {
+			let size = crate::evaluate::integral(context,
+				scene.parent.clone(), value, index)?;
+			let register = scene.mode.size();
+			prime.push(I::with_reg_i64(code_rm!(register, Mov_, _im),
+				scene.primary[register], size as i64), span);
+			true
+		}
+		_ => false,
+	})
+}
@@ -4,8 +4,9 @@ use std::sync::Arc;
 use iced_x86::{BlockEncoder, BlockEncoderOptions, Instruction, InstructionBlock};
 
 use crate::context::Context;
-use crate::generate::{Relative, Section};
-use crate::node::{FunctionPath, Size, Variable};
+use crate::generate::{Absolute, Form, Literal, Relative, Section};
+use crate::error::Diagnostic;
+use crate::node::{FunctionPath, Identifier, Size, Symbol, Variable};
 use crate::query::Key;
 use crate::span::Span;
 
@@ -23,6 +24,10 @@ pub struct Scene {
 	pub variables: HashMap<Variable, isize>,
 	pub loops: Vec<(Entry, Exit)>,
 	pub parent: Option<Key>,
+	pub independent: bool,
+	/// The frame offset of the instruction pointer stored by `anchor`
+	/// and the index of the instruction it is the address of.
+	pub anchor: Option<(isize, usize)>,
 	next_offset: isize,
 	next_label: u64,
 }
@@ -58,6 +63,9 @@ pub struct Translation {
 	pub pending_label: Option<u64>,
 	pub instructions: Vec<Instruction>,
 	pub calls: Vec<(usize, FunctionPath)>,
+	pub relatives: Vec<(usize, usize, Symbol, i128)>,
+	pub absolutes: Vec<(usize, Form, Symbol, i128)>,
+	pub strings: Vec<(usize, Vec<u8>)>,
 	pub spans: Vec<Span>,
 }
 
@@ -84,43 +92,78 @@ pub fn lower(context: &Context, parent: Option<Key>, path: &FunctionPath,
 			 span: Option<Span>) -> crate::Result<Arc<Section>> {
 	let key = Key::Generate(path.clone());
 	context.sections.scope(parent, key.clone(), span.clone(), || {
-		let parent = Some(key.clone());
-		let translation = translate(context, parent, path, Mode::Long, span)?;
+		let (parent, mode) = (Some(key.clone()), crate::node::MODE);
+		let translation = translate(context, parent, path, mode, span)?;
 		let block = InstructionBlock::new(&translation.instructions, 0);
 
-		// TODO: remove display code
-		use iced_x86::Formatter;
-		let buffer = &mut String::new();
-		let mut formatter = iced_x86::NasmFormatter::new();
-		for instruction in &translation.instructions {
-			formatter.format(instruction, buffer);
-			println!("{}", buffer);
-			buffer.clear();
-		}
-		println!();
-
 		let mut encoder = BlockEncoderOptions::RETURN_CONSTANT_OFFSETS;
 		encoder |= BlockEncoderOptions::RETURN_NEW_INSTRUCTION_OFFSETS;
-		let block = BlockEncoder::encode(64, block, encoder).unwrap_or_else(|error|
+		let block = BlockEncoder::encode(mode as u32, block, encoder).unwrap_or_else(|error|
 			panic!("encoding failure in: {:?}, where: {}", path, error));
 
 		let mut section = Section::default();
+		let target = |index: usize| block.new_instruction_offsets.get(index).cloned()
+			.unwrap_or(block.code_buffer.len() as u32) as usize;
 		for (index, path) in translation.calls {
 			let offset = block.new_instruction_offsets[index] as usize;
 			let offset = offset + block.constant_offsets[index].immediate_offset();
-			let target = block.new_instruction_offsets.get(index + 1).cloned()
-				.unwrap_or(block.code_buffer.len() as u32) as usize;
+			let (target, symbol) = (target(index + 1), Symbol::Function(path));
+
+			let span = translation.spans[index].clone();
+			let size = match mode {
+				Mode::Real => Size::Word,
+				Mode::Protected | Mode::Long => Size::Double,
+			};
+
+			let relative = Relative { size, offset, target, symbol, addend: 0, span };
+			section.relative.push(relative);
+		}
 
-			let size = Size::Double;
-			let relative = Relative { size, offset, path, target };
+		for (index, other, symbol, addend) in translation.relatives {
+			let constants = &block.constant_offsets[index];
+			let offset = block.new_instruction_offsets[index] as usize + match
+				constants.has_displacement() {
+				true => constants.displacement_offset(),
+				false => constants.immediate_offset(),
+			};
+
+			let (size, target) = (Size::Double, target(other));
+			let span = translation.spans[index].clone();
+			let relative = Relative { size, offset, target, symbol, addend, span };
 			section.relative.push(relative);
 		}
 
+		for (index, form, symbol, addend) in translation.absolutes {
+			let offset = block.new_instruction_offsets[index] as usize;
+			let offset = offset + block.constant_offsets[index].immediate_offset();
+			let span = translation.spans[index].clone();
+			section.absolute.push(Absolute { form, offset, symbol, addend, span });
+		}
+
+		for (index, bytes) in translation.strings {
+			let offset = block.new_instruction_offsets[index] as usize;
+			let offset = offset + block.constant_offsets[index].immediate_offset();
+			section.strings.push(Literal { size: mode.size(), offset, bytes });
+		}
+
 		section.bytes = block.code_buffer;
 		Ok(section)
 	})
 }
 
+/// Returns whether code is position independent as
+/// set by the global annotation `@@independent true`.
+pub fn independent(context: &Context) -> crate::Result<bool> {
+	let identifier = Identifier("independent".to_string());
+	Ok(match context.annotations.get(&identifier) {
+		None => false,
+		Some(annotation) => {
+			let value = &annotation.node;
+			crate::evaluate::integral(context, None, value, &value.root)? != 0
+		}
+	})
+}
+
 pub fn translate(context: &Context, parent: Option<Key>, path: &FunctionPath,
 				 mode: Mode, span: Option<Span>) -> crate::Result<Translation> {
 	let function = &crate::node::function(context, parent.clone(), path, span.clone())?;
@@ -129,6 +172,13 @@ pub fn translate(context: &Context, parent: Option<Key>, path: &FunctionPath,
 	let (primary, alternate) = super::registers(context,
 		&reserved, mode, &function.identifier.span)?;
 
+	let independent = super::independent(context)?;
+	if independent && matches!(mode, Mode::Real) {
+		return context.pass(Diagnostic::error()
+			.message("position independent code is unsupported in real mode")
+			.label(function.identifier.span.label()));
+	}
+
 	let (next_offset, next_label) = (0, 0);
 	let (variables, loops) = (HashMap::new(), Vec::new());
 	let scene = &mut Scene {
@@ -139,12 +189,17 @@ pub fn translate(context: &Context, parent: Option<Key>, path: &FunctionPath,
 		variables,
 		loops,
 		parent,
+		independent,
+		anchor: None,
 		next_offset,
 		next_label,
 	};
 
 	let mut translation = Translation::default();
 	super::entry(scene, &mut translation, &function.identifier.span);
+	if independent && matches!(mode, Mode::Protected) {
+		super::anchor(scene, &mut translation, &function.identifier.span);
+	}
 	super::parameters(context, function, scene)?;
 
 	// TODO: remove special main
@@ -172,6 +227,10 @@ pub fn translate(context: &Context, parent: Option<Key>, path: &FunctionPath,
 	} else {
 		translation.instructions.remove(2);
 		translation.calls.iter_mut().for_each(|(index, _)| *index -= 1);
+		translation.relatives.iter_mut().for_each(|(index, target, ..)|
+			(*index -= 1, *target -= 1).1);
+		translation.absolutes.iter_mut().for_each(|(index, ..)| *index -= 1);
+		translation.strings.iter_mut().for_each(|(index, _)| *index -= 1);
 	}
 
 	Ok(translation)
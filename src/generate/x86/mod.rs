@@ -1,10 +1,14 @@
 pub use binary::*;
+pub use bits::*;
 pub use call::*;
 pub use cast::*;
 pub use function::*;
+pub use intrinsic::*;
 pub use lower::*;
 pub use node::*;
 pub use register::*;
+pub use slice::*;
+pub use startup::*;
 pub use target::*;
 pub use value::*;
 
@@ -13,8 +17,12 @@ mod node;
 mod lower;
 mod value;
 mod binary;
+mod bits;
 mod target;
 mod register;
 mod function;
 mod call;
 mod cast;
+mod slice;
+mod intrinsic;
+mod startup;
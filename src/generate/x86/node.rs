@@ -31,9 +31,9 @@ macro_rules! relative {
     	use iced_x86::Code::*;
     	use super::Mode;
     	match $mode {
-			Mode::Protected => concat_idents!($prefix, _rel32_64),
+			Mode::Protected => concat_idents!($prefix, _rel32_32),
 			Mode::Long => concat_idents!($prefix, _rel32_64),
-			Mode::Real => concat_idents!($prefix, _rel32_32),
+			Mode::Real => concat_idents!($prefix, _rel16),
     	}
     }};
 }
@@ -40,6 +40,14 @@ impl Mode {
 		}
 	}
 
+	pub fn source(&self) -> Register {
+		match self {
+			Mode::Protected => Register::ESI,
+			Mode::Long => Register::RSI,
+			Mode::Real => Register::SI,
+		}
+	}
+
 	pub fn destination(&self) -> Register {
 		match self {
 			Mode::Protected => Register::EDI,
@@ -0,0 +1,140 @@
+use iced_x86::Code;
+use iced_x86::Instruction as I;
+use iced_x86::MemoryOperand as M;
+
+use crate::context::Context;
+use crate::inference::Types;
+use crate::node::{FunctionPath, Size, Type, Value, ValueIndex};
+use crate::span::Span;
+
+use super::{Mode, Scene, Translation};
+
+/// Constructs a slice from an array or another slice. The slice
+/// is stored on the stack and its address is placed in the
+/// primary register. The start is evaluated before the end.
+pub fn slice(context: &Context, scene: &mut Scene, prime: &mut Translation,
+			 types: &Types, value: &Value, base: &ValueIndex, start: &Option<ValueIndex>,
+			 end: &Option<ValueIndex>, span: &Span) -> crate::Result<()> {
+	let element = match &types[base] {
+		Type::Array(element, _) | Type::Slice(element) => element,
+		other => panic!("cannot slice type: {}", other),
+	};
+
+	let scale = crate::node::size(context, scene.parent
+		.clone(), &element.node, Some(span.clone()))?;
+	let size = scene.mode.size();
+	let offset = scene.reserve(crate::node::slice(scene.mode)) as i32;
+	let address = M::with_base_displ(scene.mode.base(), offset);
+	let length = offset + crate::node::slice_length(scene.mode) as i32;
+	let length = M::with_base_displ(scene.mode.base(), length);
+
+	super::value(context, scene, prime, types, value, base)?;
+	let (primary, alternate) = (scene.primary[size], scene.alternate[size]);
+	define_note!(note, prime, span);
+	match &types[base] {
+		Type::Array(_, node) => {
+			let node = crate::node::length(context, scene.parent.clone(), node)?;
+			note(I::with_mem_reg(store(scene.mode), address, primary));
+			note(I::with_reg_i64(code_rm!(size, Mov_, _im), primary, node as i64));
+			note(I::with_mem_reg(store(scene.mode), length, primary));
+		}
+		_ => {
+			let code = code_rm!(size, Mov_, _r);
+			note(I::with_reg_mem(code, alternate, M::with_base(primary)));
+			note(I::with_mem_reg(store(scene.mode), address, alternate));
+			let other = crate::node::slice_length(scene.mode) as i32;
+			let other = M::with_base_displ(primary, other);
+			note(I::with_reg_mem(code, alternate, other));
+			note(I::with_mem_reg(store(scene.mode), length, alternate));
+		}
+	}
+
+	match start {
+		None => prime.push(I::with_reg_reg(code_rm!(size,
+			Xor_, _r), primary, primary), span),
+		Some(start) => {
+			super::value(context, scene, prime, types, value, start)?;
+			super::extend(scene, prime, &types[start], span);
+		}
+	}
+
+	prime.push(I::with_reg(super::code_push(size), primary), span);
+	match end {
+		None => prime.push(I::with_reg_mem(code_rm!(size,
+			Mov_, _r), primary, length), span),
+		Some(end) => {
+			super::value(context, scene, prime, types, value, end)?;
+			super::extend(scene, prime, &types[end], span);
+		}
+	}
+
+	prime.push(I::with_reg(super::code_pop(size), alternate), span);
+	if let Some(handler) = crate::node::panic(context) {
+		let branch = relative!(scene.mode, Jbe);
+		let compare = I::with_reg_mem(code_rm!(size, Cmp_, _r), primary, length);
+		guard(context, scene, prime, &handler, compare, branch, span);
+		let compare = I::with_reg_reg(code_rm!(size, Cmp_, _r), alternate, primary);
+		guard(context, scene, prime, &handler, compare, branch, span);
+	}
+
+	define_note!(note, prime, span);
+	note(I::with_reg_reg(code_rm!(size, Sub_, _r), primary, alternate));
+	note(I::with_mem_reg(store(scene.mode), length, primary));
+	note(I::with_reg_reg_i32(match scene.mode {
+		Mode::Protected => Code::Imul_r32_rm32_imm32,
+		Mode::Long => Code::Imul_r64_rm64_imm32,
+		Mode::Real => Code::Imul_r16_rm16_imm16,
+	}, alternate, alternate, scale as i32));
+	note(I::with_mem_reg(match scene.mode {
+		Mode::Protected => Code::Add_rm32_r32,
+		Mode::Long => Code::Add_rm64_r64,
+		Mode::Real => Code::Add_rm16_r16,
+	}, address, alternate));
+	Ok(note(I::with_reg_mem(super::load(scene.mode), primary, address)))
+}
+
+/// Constructs a slice referencing a string literal in
+/// the string table and places its address in the primary register.
+pub fn string(scene: &mut Scene, prime: &mut Translation, string: &str, span: &Span) {
+	let size = scene.mode.size();
+	let offset = scene.reserve(crate::node::slice(scene.mode)) as i32;
+	let address = M::with_base_displ(scene.mode.base(), offset);
+	let length = offset + crate::node::slice_length(scene.mode) as i32;
+	let length = M::with_base_displ(scene.mode.base(), length);
+
+	let primary = scene.primary[size];
+	literal(scene, prime, string.as_bytes().to_vec(), span);
+	define_note!(note, prime, span);
+	note(I::with_mem_reg(store(scene.mode), address, primary));
+	note(I::with_reg_i64(code_rm!(size, Mov_, _im), primary, string.len() as i64));
+	note(I::with_mem_reg(store(scene.mode), length, primary));
+	note(I::with_reg_mem(super::load(scene.mode), primary, address));
+}
+
+/// Places the address of a byte sequence in the
+/// string table into the primary register.
+pub fn literal(scene: &Scene, prime: &mut Translation, bytes: Vec<u8>, span: &Span) {
+	let size = scene.mode.size();
+	prime.strings.push((prime.instructions.len(), bytes));
+	prime.push(I::with_reg_i64(code_rm!(size, Mov_, _im), scene.primary[size], 0), span);
+}
+
+/// Invokes the panic handler unless the branch after
+/// the comparison is taken. The handler must not return.
+pub fn guard(context: &Context, scene: &mut Scene, prime: &mut Translation,
+			 handler: &FunctionPath, compare: I, branch: Code, span: &Span) {
+	let label = scene.label();
+	define_note!(note, prime, span);
+	note(compare);
+	note(I::with_branch(branch, label));
+	super::invoke(context, scene, prime, handler.clone(), span);
+	prime.set_pending_label(label, span);
+}
+
+fn store(mode: Mode) -> Code {
+	match mode {
+		Mode::Protected => Code::Mov_rm32_r32,
+		Mode::Long => Code::Mov_rm64_r64,
+		Mode::Real => Code::Mov_rm16_r16,
+	}
+}
@@ -0,0 +1,201 @@
+use iced_x86::{BlockEncoder, BlockEncoderOptions, Code, InstructionBlock};
+use iced_x86::Instruction as I;
+use iced_x86::MemoryOperand as M;
+
+use crate::node::Size;
+use crate::node::address::Address;
+
+use super::Mode;
+
+/// A segment copied from its load address to its virtual address.
+pub type Copy = (Address, Address, usize);
+/// A segment filled with zeroes at its virtual address.
+pub type Clear = (Address, usize);
+
+/// Generates a routine that processes the copy and clear tables
+/// before jumping to the entry point. The tables are placed
+/// directly after the instructions of the routine.
+pub fn startup(mode: Mode, address: Address, copies: &[Copy],
+			   clears: &[Clear], entry: Address) -> Vec<u8> {
+	let word = mode.size().bytes();
+	let code = |tables| encode(mode, address, tables, (copies.len(), clears.len()), entry);
+	let length = crate::other::ceiling(code((0, 0)).len(), word);
+	let copy_table = address + length;
+	let clear_table = copy_table + copies.len() * 3 * word;
+
+	let mut bytes = code((copy_table, clear_table));
+	bytes.resize(length, 0);
+	let mut push = |value: usize| bytes.extend(&value.to_le_bytes()[..word]);
+	copies.iter().for_each(|(load, address, size)|
+		[*load, *address, *size].iter().for_each(|value| push(*value)));
+	clears.iter().for_each(|(address, size)|
+		[*address, *size].iter().for_each(|value| push(*value)));
+	bytes
+}
+
+/// Generates a routine that adds the difference between the address it
+/// is executed at and the address it is linked at to the pointer at each
+/// address in the relocation table before jumping to the entry point.
+/// The table is placed directly after the instructions of the routine.
+pub fn relocate(mode: Mode, address: Address, relocations: &[Address],
+				entry: Address) -> Vec<u8> {
+	let word = mode.size().bytes();
+	let code = |table| encode_relocate(mode, address, table, relocations.len(), entry);
+	let length = crate::other::ceiling(code(0).len(), word);
+	let mut bytes = code(address + length);
+	bytes.resize(length, 0);
+	relocations.iter().for_each(|relocation|
+		bytes.extend(&relocation.to_le_bytes()[..word]));
+	bytes
+}
+
+fn encode_relocate(mode: Mode, address: Address, relocation_table: Address,
+				   relocations: usize, entry: Address) -> Vec<u8> {
+	let size = mode.size();
+	let word = size.bytes() as i32;
+	let (table, count) = (register!(size, B), register!(size, D));
+	let (target, difference) = (register!(size, C), register!(size, A));
+	let (base, relocate, exit) = (1, 2, 3);
+
+	let mut instructions = Vec::new();
+	let mut note = |instruction| instructions.push(instruction);
+	let immediate = |register, value: Address| I::with_reg_i64(
+		code_rm!(size, Mov_, _im), register, value as i64);
+	let add_register = |register, other|
+		I::with_reg_reg(code_rm!(size, Add_, _r), register, other);
+
+	// The call to the next instruction pushes the address it is executed at.
+	let call = match mode {
+		Mode::Real => 3,
+		_ => 5,
+	};
+
+	note(I::with_branch(relative!(mode, Call), base));
+	let mut label = I::with_reg(super::code_pop(size), difference);
+	label.set_ip(base);
+	note(label);
+	note(immediate(target, address + call));
+	note(I::with_reg_reg(code_rm!(size, Sub_, _r), difference, target));
+	note(immediate(table, relocation_table));
+	note(add_register(table, difference));
+	note(immediate(count, relocations));
+	let mut label = I::with_reg_reg(test(size), count, count);
+	label.set_ip(relocate);
+	note(label);
+	note(I::with_branch(relative!(mode, Je), exit));
+	note(I::with_reg_mem(code_rm!(size, Mov_, _r), target, M::with_base_displ(table, 0)));
+	note(add_register(target, difference));
+	note(I::with_mem_reg(add_memory(size), M::with_base_displ(target, 0), difference));
+	note(I::with_reg_i32(add(size), table, word));
+	note(I::with_reg(dec(size), count));
+	note(I::with_branch(relative!(mode, Jmp), relocate));
+
+	let mut label = immediate(target, entry);
+	label.set_ip(exit);
+	note(label);
+	note(add_register(target, difference));
+	note(I::with_reg(jump(size), target));
+
+	let block = InstructionBlock::new(&instructions, address as u64);
+	let options = BlockEncoderOptions::NONE;
+	BlockEncoder::encode(mode as u32, block, options).unwrap_or_else(|error|
+		panic!("encoding failure in relocation routine: {}", error)).code_buffer
+}
+
+fn encode(mode: Mode, address: Address, (copy_table, clear_table): (Address, Address),
+		  (copies, clears): (usize, usize), entry: Address) -> Vec<u8> {
+	let size = mode.size();
+	let word = size.bytes() as i32;
+	let (table, count) = (register!(size, B), register!(size, D));
+	let (counter, value) = (register!(size, C), register!(size, A));
+	let (copy, clear, zero, exit) = (1, 2, 3, 4);
+
+	let mut instructions = Vec::new();
+	let mut note = |instruction| instructions.push(instruction);
+	let load = |target, displacement| I::with_reg_mem(code_rm!(size, Mov_, _r),
+		target, M::with_base_displ(table, displacement));
+
+	note(I::with_reg_i64(code_rm!(size, Mov_, _im), table, copy_table as i64));
+	note(I::with_reg_i64(code_rm!(size, Mov_, _im), count, copies as i64));
+	let mut label = I::with_reg_reg(test(size), count, count);
+	label.set_ip(copy);
+	note(label);
+	note(I::with_branch(relative!(mode, Je), clear));
+	note(load(mode.source(), 0));
+	note(load(mode.destination(), word));
+	note(load(counter, word * 2));
+	note(I::with_rep_movsb(size as u32));
+	note(I::with_reg_i32(add(size), table, word * 3));
+	note(I::with_reg(dec(size), count));
+	note(I::with_branch(relative!(mode, Jmp), copy));
+
+	let mut label = I::with_reg_i64(code_rm!(size, Mov_, _im), table, clear_table as i64);
+	label.set_ip(clear);
+	note(label);
+	note(I::with_reg_i64(code_rm!(size, Mov_, _im), count, clears as i64));
+	note(I::with_reg_reg(code_rm!(size, Xor_, _r), value, value));
+	let mut label = I::with_reg_reg(test(size), count, count);
+	label.set_ip(zero);
+	note(label);
+	note(I::with_branch(relative!(mode, Je), exit));
+	note(load(mode.destination(), 0));
+	note(load(counter, word));
+	note(I::with_rep_stosb(size as u32));
+	note(I::with_reg_i32(add(size), table, word * 2));
+	note(I::with_reg(dec(size), count));
+	note(I::with_branch(relative!(mode, Jmp), zero));
+
+	let mut label = I::with_reg_i64(code_rm!(size, Mov_, _im), value, entry as i64);
+	label.set_ip(exit);
+	note(label);
+	note(I::with_reg(jump(size), value));
+
+	let block = InstructionBlock::new(&instructions, address as u64);
+	let options = BlockEncoderOptions::NONE;
+	BlockEncoder::encode(mode as u32, block, options).unwrap_or_else(|error|
+		panic!("encoding failure in startup routine: {}", error)).code_buffer
+}
+
+fn test(size: Size) -> Code {
+	match size {
+		Size::Byte => Code::Test_rm8_r8,
+		Size::Word => Code::Test_rm16_r16,
+		Size::Double => Code::Test_rm32_r32,
+		Size::Quad => Code::Test_rm64_r64,
+	}
+}
+
+fn add(size: Size) -> Code {
+	match size {
+		Size::Byte => Code::Add_rm8_imm8,
+		Size::Word => Code::Add_rm16_imm16,
+		Size::Double => Code::Add_rm32_imm32,
+		Size::Quad => Code::Add_rm64_imm32,
+	}
+}
+
+fn add_memory(size: Size) -> Code {
+	match size {
+		Size::Byte => Code::Add_rm8_r8,
+		Size::Word => Code::Add_rm16_r16,
+		Size::Double => Code::Add_rm32_r32,
+		Size::Quad => Code::Add_rm64_r64,
+	}
+}
+
+fn dec(size: Size) -> Code {
+	match size {
+		Size::Byte => Code::Dec_rm8,
+		Size::Word => Code::Dec_rm16,
+		Size::Double => Code::Dec_rm32,
+		Size::Quad => Code::Dec_rm64,
+	}
+}
+
+fn jump(size: Size) -> Code {
+	match size {
+		Size::Byte | Size::Word => Code::Jmp_rm16,
+		Size::Double => Code::Jmp_rm32,
+		Size::Quad => Code::Jmp_rm64,
+	}
+}
@@ -5,7 +5,8 @@ use iced_x86::MemoryOperand as M;
 use crate::context::Context;
 use crate::error::Diagnostic;
 use crate::inference::Types;
-use crate::node::{Dual, Size, Type, Unary, Value, ValueIndex, ValueNode};
+use crate::generate::Form;
+use crate::node::{Bits, Dual, Size, Symbol, Type, Unary, Value, ValueIndex, ValueNode};
 use crate::span::Span;
 
 use super::{Mode, Scene, Translation};
@@ -14,8 +15,10 @@ pub fn target(context: &Context, scene: &mut Scene, prime: &mut Translation,
 			  types: &Types, value: &Value, index: &ValueIndex) -> crate::Result<M> {
 	let span = &value[*index].span;
 	match &value[*index].node {
-		// TODO: path as start address intrinsic
-		ValueNode::Path(_) => unimplemented!(),
+		ValueNode::Path(path) => {
+			address(scene, prime, Symbol::Variable(path.clone()), 0, span);
+			Ok(M::with_base(scene.mode_primary()))
+		}
 		ValueNode::Variable(variable) => {
 			let offset = scene.variables[variable] as i32;
 			Ok(M::with_base_displ(scene.mode.base(), offset))
@@ -29,27 +32,57 @@ pub fn target(context: &Context, scene: &mut Scene, prime: &mut Translation,
 			Ok(target)
 		}
 		ValueNode::Index(target, index) => match &types[target] {
-			Type::Array(path, _) => {
+			Type::Array(path, length) => {
 				let scale = crate::node::size(context, scene
 					.parent.clone(), &path.node, Some(span.clone()))?;
+				let length = crate::node::length(context, scene.parent.clone(), length)?;
 				let target = self::target(context, scene, prime, types, value, target)?;
 
 				define_note!(note, prime, span);
 				note(I::with_reg_mem(load(scene.mode), scene.mode_primary(), target));
 				note(I::with_reg(super::code_push(scene.mode.size()), scene.mode_primary()));
 				swap_restore(context, scene, prime, types, value, index, span)?;
-				scale_index(scene, prime, &types[index], scale, Dual::Add, span)?;
+				extend_alternate(scene, prime, &types[index], span);
+				if let Some(handler) = crate::node::panic(context) {
+					let compare = I::with_reg_i32(match scene.mode {
+						Mode::Protected => Code::Cmp_rm32_imm32,
+						Mode::Long => Code::Cmp_rm64_imm32,
+						Mode::Real => Code::Cmp_rm16_imm16,
+					}, scene.alternate[scene.mode.size()], length as i32);
+					let branch = relative!(scene.mode, Jb);
+					super::guard(context, scene, prime, &handler, compare, branch, span);
+				}
+
+				self::scale(scene, prime, scale, Dual::Add, span);
 				Ok(M::with_base(scene.mode_primary()))
 			}
 			Type::Slice(path) => {
 				let scale = crate::node::size(context, scene
 					.parent.clone(), &path.node, Some(span.clone()))?;
 				super::value(context, scene, prime, types, value, target)?;
-				prime.push(I::with_mem(super::code_push(scene.mode.size()),
-					M::with_base(scene.mode_primary())), span);
+				let handler = crate::node::panic(context);
+				let size = scene.mode.size();
+				if handler.is_some() {
+					let length = crate::node::slice_length(scene.mode) as i32;
+					let length = M::with_base_displ(scene.mode_primary(), length);
+					prime.push(I::with_mem(code_push_memory(scene.mode), length), span);
+				}
 
+				prime.push(I::with_mem(code_push_memory(scene.mode),
+					M::with_base(scene.mode_primary())), span);
 				swap_restore(context, scene, prime, types, value, index, span)?;
-				scale_index(scene, prime, &types[index], scale, Dual::Add, span)?;
+				extend_alternate(scene, prime, &types[index], span);
+				if let Some(handler) = handler {
+					let length = M::with_base(scene.mode.stack());
+					let compare = I::with_reg_mem(code_rm!(size, Cmp_, _r),
+						scene.alternate[size], length);
+					let branch = relative!(scene.mode, Jb);
+					super::guard(context, scene, prime, &handler, compare, branch, span);
+					prime.push(I::with_reg_mem(load(scene.mode), scene.mode.stack(),
+						M::with_base_displ(scene.mode.stack(), size.bytes() as i32)), span);
+				}
+
+				self::scale(scene, prime, scale, Dual::Add, span);
 				Ok(M::with_base(scene.mode_primary()))
 			}
 			other => panic!("cannot index into type: {}", other)
@@ -64,8 +97,26 @@ pub fn target(context: &Context, scene: &mut Scene, prime: &mut Translation,
 	}
 }
 
+/// Returns the bit field of a field value if it exists. The
+/// target of a bit field is the address of its storage unit.
+pub fn bits(context: &Context, scene: &Scene, types: &Types, value: &Value,
+			index: &ValueIndex) -> crate::Result<Option<Bits>> {
+	match &value[*index].node {
+		ValueNode::Field(node, field) => crate::node::bits(context, scene.parent.clone(),
+			&types[node], &field.node, Some(value[*index].span.clone())),
+		_ => Ok(None),
+	}
+}
+
 pub fn scale_index(scene: &mut Scene, prime: &mut Translation, index: &Type,
 				   scale: usize, dual: Dual, span: &Span) -> crate::Result<()> {
+	extend_alternate(scene, prime, index, span);
+	Ok(self::scale(scene, prime, scale, dual, span))
+}
+
+/// Sign or zero extends the primary register from
+/// the size of an integral type to the mode size.
+pub fn extend(scene: &Scene, prime: &mut Translation, index: &Type, span: &Span) {
 	define_note!(note, prime, span);
 	let target = scene.mode.size();
 	match index {
@@ -75,18 +126,90 @@ pub fn scale_index(scene: &mut Scene, prime: &mut Translation, index: &Type,
 			.into_iter().for_each(|instruction| note(instruction)),
 		other => panic!("invalid arithmetic type: {}", other),
 	}
+}
+
+fn extend_alternate(scene: &mut Scene, prime: &mut Translation, index: &Type, span: &Span) {
+	std::mem::swap(&mut scene.primary, &mut scene.alternate);
+	extend(scene, prime, index, span);
+	std::mem::swap(&mut scene.primary, &mut scene.alternate);
+}
 
+/// Multiplies the alternate register by the scale and
+/// combines the result with the primary register.
+fn scale(scene: &Scene, prime: &mut Translation, scale: usize, dual: Dual, span: &Span) {
+	define_note!(note, prime, span);
+	let target = scene.mode.size();
 	let alternate = scene.alternate[target];
 	note(I::with_reg_reg_i32(match scene.mode {
 		Mode::Protected => Code::Imul_r32_rm32_imm32,
 		Mode::Long => Code::Imul_r64_rm64_imm32,
 		Mode::Real => Code::Imul_r16_rm16_imm16,
 	}, alternate, alternate, scale as i32));
-	Ok(note(I::with_reg_reg(match dual {
+	note(I::with_reg_reg(match dual {
 		Dual::Add => code_rm!(target, Add_, _r),
 		Dual::Minus => code_rm!(target, Sub_, _r),
 		other => panic!("invalid scale dual: {:?}", other),
-	}, scene.primary[target], alternate)))
+	}, scene.primary[target], alternate));
+}
+
+fn code_push_memory(mode: Mode) -> Code {
+	match mode {
+		Mode::Protected => Code::Push_rm32,
+		Mode::Long => Code::Push_rm64,
+		Mode::Real => Code::Push_rm16,
+	}
+}
+
+/// Loads the address of a symbol offset by the addend into the
+/// primary register. Position independent code in long mode
+/// addresses relative to the instruction pointer and otherwise
+/// relative to the instruction pointer stored by `anchor`.
+pub fn address(scene: &mut Scene, prime: &mut Translation,
+			   symbol: Symbol, addend: i128, span: &Span) {
+	let primary = scene.mode_primary();
+	let index = prime.instructions.len();
+	match (scene.independent, scene.mode) {
+		(false, _) => absolute(scene, prime, symbol, addend, span),
+		(true, Mode::Long) => {
+			prime.relatives.push((index, index + 1, symbol, addend));
+			let memory = M::with_base_displ(iced_x86::Register::RIP, 0);
+			prime.push(I::with_reg_mem(Code::Lea_r64_m, primary, memory), span);
+		}
+		(true, _) => {
+			let (offset, anchor) = scene.anchor.expect("anchor is absent");
+			let memory = M::with_base_displ(scene.mode.base(), offset as i32);
+			prime.push(I::with_reg_mem(Code::Mov_r32_rm32, primary, memory), span);
+			prime.relatives.push((index + 1, anchor, symbol, addend));
+			prime.push(I::with_reg_i32(Code::Add_rm32_imm32, primary, 0), span);
+		}
+	}
+}
+
+/// Stores the instruction pointer in the frame once for each function
+/// with a call to the next instruction. Position independent code
+/// in protected mode addresses symbols relative to it.
+pub fn anchor(scene: &mut Scene, prime: &mut Translation, span: &Span) {
+	let (size, primary) = (scene.mode.size(), scene.mode_primary());
+	let label = scene.label();
+	prime.push(I::with_branch(relative!(scene.mode, Call), label), span);
+	prime.set_pending_label(label, span);
+
+	let index = prime.instructions.len();
+	prime.push(I::with_reg(super::code_pop(size), primary), span);
+	let offset = scene.reserve(size.bytes());
+	let memory = M::with_base_displ(scene.mode.base(), offset as i32);
+	prime.push(I::with_mem_reg(Code::Mov_rm32_r32, memory, primary), span);
+	scene.anchor = Some((offset, index));
+}
+
+/// Loads the absolute address of a symbol offset
+/// by the addend into the primary register.
+pub fn absolute(scene: &Scene, prime: &mut Translation,
+				symbol: Symbol, addend: i128, span: &Span) {
+	let size = scene.mode.size();
+	let index = prime.instructions.len();
+	prime.absolutes.push((index, Form::Direct(size), symbol, addend));
+	prime.push(I::with_reg_i64(code_rm!(size, Mov_, _im), scene.primary[size], 0), span);
 }
 
 pub fn load(mode: Mode) -> Code {
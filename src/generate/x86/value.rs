@@ -39,14 +39,17 @@ pub fn value(context: &Context, scene: &mut Scene, prime: &mut Translation,
 
 			define_note!(note, prime, span);
 			note(I::with_reg(super::code_push(stack), scene.primary[stack]));
-			let target = super::target(context, scene, prime, types, value, target)?;
+			let memory = super::target(context, scene, prime, types, value, target)?;
 
 			define_note!(note, prime, span);
 			let node_size = crate::node::size(context, scene
 				.parent.clone(), &types[index], Some(span.clone()))?;
 			note(I::with_reg(super::code_pop(stack), scene.alternate[stack]));
-			super::set(scene, prime, &types[index], node_size,
-				target, scene.alternate[size], span);
+			match super::bits(context, scene, types, value, target)? {
+				Some(bits) => super::insert(prime, &scene.alternate, bits, memory, span),
+				None => super::set(scene, prime, &types[index], node_size,
+					memory, scene.alternate[size], span),
+			}
 		}
 		ValueNode::While(condition, index) => {
 			let entry = *prime.pending_label.get_or_insert_with(|| scene.label());
@@ -100,16 +103,34 @@ pub fn value(context: &Context, scene: &mut Scene, prime: &mut Translation,
 		ValueNode::Return(index) => super::render(context,
 			scene, prime, types, value, *index, span)?,
 		ValueNode::Compile(_) => unimplemented!(),
-		ValueNode::Inline(_) => unimplemented!(),
+		ValueNode::Inline(node) => {
+			let section = crate::evaluate::bytes(context,
+				scene.parent.clone(), value, node, &types[node])?;
+			if let Some(absolute) = section.absolute.first() {
+				return context.pass(Diagnostic::error()
+					.message("inline value cannot contain addresses")
+					.label(absolute.span.label()));
+			}
+
+			section.bytes.chunks(16).for_each(|chunk|
+				note(I::with_declare_byte(chunk)));
+		}
 		ValueNode::Call(path, arguments) => super::call(context, scene,
 			prime, types, value, index, path, &arguments, span)?,
 		ValueNode::Field(node, field) => {
 			self::value(context, scene, prime, types, value, node)?;
 			let offset = crate::node::offset(context, scene.parent.clone(),
 				&types[node], &field.node, Some(span.clone()))? as i32;
+			let bits = crate::node::bits(context, scene.parent.clone(),
+				&types[node], &field.node, Some(span.clone()))?;
 
 			define_note!(note, prime, span);
-			if types[index].composite() {
+			if let Some(bits) = bits {
+				let memory = M::with_base_displ(scene.mode_primary(), offset);
+				note(I::with_reg_mem(code_rm!(bits.unit, Mov_, _r),
+					scene.primary[bits.unit], memory));
+				super::extract(prime, &scene.primary, bits, span);
+			} else if types[index].composite() {
 				note(I::with_reg_i32(match scene.mode {
 					Mode::Protected => Code::Add_rm32_imm32,
 					Mode::Long => Code::Add_rm64_imm32,
@@ -129,13 +150,17 @@ pub fn value(context: &Context, scene: &mut Scene, prime: &mut Translation,
 				.ok_or(QueryError::Failure)?;
 			let base = scene.reserve(offsets.size);
 
-			for (identifier, offset) in &offsets.fields {
-				let path = &structure.fields[identifier].node;
+			for (identifier, path) in &structure.fields {
+				let (path, offset) = (&path.node, &offsets.fields[identifier]);
 				let node_size = crate::node::size(context,
 					scene.parent.clone(), &path, Some(span.clone()))?;
 				let offset = base + *offset as isize;
 
-				if let Some((index, _)) = fields.get(identifier) {
+				if let Some(bits) = offsets.bits.get(identifier) {
+					if bits.shift == 0 {
+						super::zero(scene, prime, offset, bits.unit.bytes(), span);
+					}
+				} else if let Some((index, _)) = fields.get(identifier) {
 					let size = super::size(context, scene, path, span)?;
 					self::value(context, scene, prime, types, value, index)?;
 					let memory = M::with_base_displ(scene.mode.base(), offset as i32);
@@ -146,12 +171,30 @@ pub fn value(context: &Context, scene: &mut Scene, prime: &mut Translation,
 				}
 			}
 
+			for identifier in structure.fields.keys() {
+				let bits = offsets.bits.get(identifier);
+				if let (Some(bits), Some((index, _))) = (bits, fields.get(identifier)) {
+					let offset = base + offsets.fields[identifier] as isize;
+					self::value(context, scene, prime, types, value, index)?;
+					let memory = M::with_base_displ(scene.mode.base(), offset as i32);
+					super::insert(prime, &scene.primary, *bits, memory, span);
+				}
+			}
+
 			let memory = M::with_base_displ(scene.mode.base(), base as i32);
 			prime.push(I::with_reg_mem(super::load(scene.mode),
 				scene.mode_primary(), memory), span);
 		}
-		ValueNode::Slice(_, _, _) => unimplemented!(),
-		ValueNode::Index(_, _) => unimplemented!(),
+		ValueNode::Slice(node, start, end) => super::slice(context,
+			scene, prime, types, value, node, start, end, span)?,
+		ValueNode::Index(_, _) => {
+			let memory = super::target(context, scene, prime, types, value, index)?;
+			let size = super::size(context, scene, &types[index], span)?;
+			prime.push(I::with_reg_mem(match types[index].composite() {
+				false => code_rm!(size, Mov_, _r),
+				true => super::load(scene.mode),
+			}, scene.primary[size], memory), span);
+		}
 		ValueNode::Compound(dual, target, index) => {
 			super::binary(context, scene, prime, types,
 				value, &Binary::Dual(*dual), target, index, span)?;
@@ -160,19 +203,28 @@ pub fn value(context: &Context, scene: &mut Scene, prime: &mut Translation,
 
 			define_note!(note, prime, span);
 			note(I::with_reg(super::code_push(stack), scene.primary[stack]));
-			let target = super::target(context, scene, prime, types, value, target)?;
+			let memory = super::target(context, scene, prime, types, value, target)?;
 
 			define_note!(note, prime, span);
 			let node_size = crate::node::size(context, scene
 				.parent.clone(), &types[index], Some(span.clone()))?;
 			note(I::with_reg(super::code_pop(stack), scene.alternate[stack]));
-			super::set(scene, prime, &types[index], node_size,
-				target, scene.alternate[size], span);
+			match super::bits(context, scene, types, value, target)? {
+				Some(bits) => super::insert(prime, &scene.alternate, bits, memory, span),
+				None => super::set(scene, prime, &types[index], node_size,
+					memory, scene.alternate[size], span),
+			}
 		}
 		ValueNode::Binary(binary, left, right) => super::binary(context,
 			scene, prime, types, value, binary, left, right, span)?,
 		ValueNode::Unary(unary, index) => {
 			if let Unary::Reference = unary {
+				if super::bits(context, scene, types, value, index)?.is_some() {
+					return context.pass(Diagnostic::error()
+						.message("bit field is not addressable")
+						.label(span.label()));
+				}
+
 				let instruction = I::with_reg_mem(super::load(scene.mode),
 					scene.mode_primary(), super::target(context,
 						scene, prime, types, value, index)?);
@@ -214,10 +266,48 @@ pub fn value(context: &Context, scene: &mut Scene, prime: &mut Translation,
 				true => super::load(scene.mode),
 			}, scene.primary[size], memory));
 		}
-		ValueNode::Path(_) => unimplemented!(),
-		ValueNode::String(_) => unimplemented!(),
+		ValueNode::Path(path) if immediate(context, path)? => {
+			let size = super::size(context, scene, &types[index], span)?;
+			let integral = crate::evaluate::integral(context,
+				scene.parent.clone(), value, index)? as i64;
+			note(I::with_reg_i64(code_rm!(size, Mov_, _im), scene.primary[size], integral));
+		}
+		ValueNode::Path(path) => {
+			let symbol = Symbol::Variable(path.clone());
+			super::address(scene, prime, symbol, 0, span);
+			if !types[index].composite() {
+				let size = super::size(context, scene, &types[index], span)?;
+				let memory = M::with_base(scene.mode_primary());
+				prime.push(I::with_reg_mem(code_rm!(size, Mov_, _r),
+					scene.primary[size], memory), span);
+			}
+		}
+		ValueNode::String(string) => super::string(scene, prime, string, span),
 		ValueNode::Register(_) => unimplemented!(),
-		ValueNode::Array(_) => unimplemented!(),
+		ValueNode::Type(_) => return context.pass(Diagnostic::error()
+			.message("type is not a value").label(span.label())),
+		ValueNode::Array(elements) => {
+			let element = match &types[index] {
+				Type::Array(element, _) => &element.node,
+				other => panic!("type: {}, is not an array", other),
+			};
+
+			let node_size = crate::node::size(context,
+				scene.parent.clone(), element, Some(span.clone()))?;
+			let base = scene.reserve(node_size * elements.len());
+			for (position, index) in elements.iter().enumerate() {
+				let size = super::size(context, scene, element, span)?;
+				self::value(context, scene, prime, types, value, index)?;
+				let offset = base + (position * node_size) as isize;
+				let memory = M::with_base_displ(scene.mode.base(), offset as i32);
+				super::set(scene, prime, element, node_size,
+					memory, scene.primary[size], span);
+			}
+
+			let memory = M::with_base_displ(scene.mode.base(), base as i32);
+			prime.push(I::with_reg_mem(super::load(scene.mode),
+				scene.mode_primary(), memory), span);
+		}
 		ValueNode::Integral(integral) => match types[index] {
 			Type::Signed(size) | Type::Unsigned(size) => {
 				let integral = *integral as i64;
@@ -243,3 +333,15 @@ pub fn value(context: &Context, scene: &mut Scene, prime: &mut Translation,
 			scene.loops.last().map(|(label, _)| *label).unwrap())),
 	})
 }
+
+/// Returns whether a static is a constant that is not placed
+/// in memory such as those imported from C headers.
+fn immediate(context: &Context, path: &Path) -> crate::Result<bool> {
+	let symbol = Symbol::Variable(path.clone());
+	if context.positions.read().contains_key(&symbol) { return Ok(false); }
+	if context.imports.contains_key(&symbol) { return Ok(false); }
+	Ok(match context.statics.get(path) {
+		Some(variable) => variable.value.is_some() && variable.constant(context)?,
+		None => false,
+	})
+}
@@ -2,6 +2,8 @@ use std::collections::HashMap;
 use std::ops::Index;
 use std::sync::Arc;
 
+use serde::{Deserialize, Serialize};
+
 use crate::context::Context;
 use crate::error::Diagnostic;
 use crate::node::*;
@@ -10,9 +12,10 @@ use crate::span::{S, Span};
 
 use super::{Scene, Terminal};
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Types {
 	pub types: HashMap<ValueIndex, Type>,
+	#[serde(with = "crate::cache::entries")]
 	pub variables: HashMap<Variable, Type>,
 	pub functions: HashMap<ValueIndex, FunctionKind>,
 }
@@ -23,7 +23,7 @@ pub fn function_call(context: &Context, scene: &mut Scene, place: Option<&S<Type
 					 value: &Value, index: &ValueIndex, path: &S<Path>, arguments: &[ValueIndex])
 					 -> crate::Result<TypeVariable> {
 	let span = value[*index].span.clone();
-	if let Some(intrinsic) = intrinsic(context, value, index, path, arguments)? {
+	if let Some(intrinsic) = intrinsic(context, scene, value, index, path, arguments)? {
 		return Ok(scene.ascribe(index, S::new(intrinsic, span)));
 	}
 
@@ -73,28 +73,57 @@ pub fn function_call(context: &Context, scene: &mut Scene, place: Option<&S<Type
 	}
 }
 
-fn intrinsic(context: &Context, value: &Value, index: &ValueIndex, path: &S<Path>,
-			 arguments: &[ValueIndex]) -> crate::Result<Option<Type>> {
-	match &path.node {
-		path if path == &["Intrinsic", "size"][..] => (),
-		path if path == &["Intrinsic", "start"][..] => (),
-		path if path == &["Intrinsic", "end"][..] => (),
-		_ => return Ok(None),
+fn intrinsic(context: &Context, scene: &Scene, value: &Value, index: &ValueIndex,
+			 path: &S<Path>, arguments: &[ValueIndex]) -> crate::Result<Option<Type>> {
+	let span = &value[*index].span;
+	if path.node == ["Intrinsic", "include"][..] {
+		let parent = scene.parent.clone();
+		let size = crate::evaluate::include(context, parent, value, arguments, span)?.len();
+		let element = S::new(Type::Unsigned(Size::Byte), span.clone());
+		let mut length = Value::default();
+		length.root = length.insert(S::new(ValueNode::Integral(size as i128), span.clone()));
+		return Ok(Some(Type::Array(Box::new(element), length)));
 	}
 
-	let span = &value[*index].span;
-	if arguments.len() != 1 {
+	let Path(elements) = &path.node;
+	let intrinsic = match elements.as_slice() {
+		[Identifier(module), Identifier(intrinsic)] if module == "Intrinsic" => intrinsic,
+		_ => return Ok(None),
+	};
+
+	let expected = match intrinsic.as_str() {
+		"offset" | "assert" => 2,
+		_ => 1,
+	};
+
+	if arguments.len() != expected {
 		return context.pass(Diagnostic::error()
-			.message("expected one argument")
+			.message(format!("expected: {}, arguments", expected))
 			.label(span.label()));
 	}
 
-	let path = &value[arguments[0]];
-	match path.node {
+	let argument = &value[arguments[0]];
+	let other = arguments.get(1).map(|index| &value[*index]);
+	match (intrinsic.as_str(), &argument.node, other.map(|other| &other.node)) {
+		("terminated", ValueNode::String(_), _) => {
+			let element = S::new(Type::Unsigned(Size::Byte), span.clone());
+			Ok(Some(Type::Pointer(Box::new(element))))
+		}
+		("terminated", _, _) => context.pass(Diagnostic::error()
+			.message("expected string literal").label(argument.span.label())),
+		("assert", _, Some(ValueNode::String(_))) => Ok(Some(Type::Truth)),
+		("assert", _, _) => context.pass(Diagnostic::error()
+			.message("expected string literal").label(other.unwrap().span.label())),
 		// TODO: use architecture pointer type
-		ValueNode::Path(_) => Ok(Some(Type::Unsigned(Size::Quad))),
+		("offset", ValueNode::Type(Type::Structure(_)), Some(ValueNode::Field(_, _))) |
+		("align", ValueNode::Type(_), _) => Ok(Some(Type::Unsigned(Size::Quad))),
+		("offset", _, _) => context.pass(Diagnostic::error()
+			.message("expected data structure and field").label(span.label())),
+		("align", _, _) => context.pass(Diagnostic::error()
+			.message("expected type").label(argument.span.label())),
+		(_, ValueNode::Path(_), _) => Ok(Some(Type::Unsigned(Size::Quad))),
 		_ => context.pass(Diagnostic::error().message("expected path")
-			.label(path.span.label())),
+			.label(argument.span.label())),
 	}
 }
 
@@ -99,6 +99,8 @@ impl Scene {
 				self.unify(context, left_node, right, &left_span, right_span);
 			}
 			(Some(Terminal::Type(S { node: Type::Slice(left), .. })), Some(Slice(right))) |
+			(Some(Terminal::Type(S { node: Type::Slice(left), .. })), Some(Sequence(right))) |
+			(Some(Terminal::Type(S { node: Type::Array(left, _), .. })), Some(Sequence(right))) |
 			(Some(Terminal::Type(S { node: Type::Pointer(left), .. })), Some(Pointer(right))) => {
 				let left_span = left.span.clone();
 				let (left_node, right) = (left.clone(), right.clone());
@@ -124,6 +126,7 @@ impl Scene {
 				self.unify(context, left, right, left_span, right_span)
 			}
 			(Some(Pointer(_)), Some(Terminal::Type(_))) |
+			(Some(Sequence(_)), Some(Terminal::Type(_))) |
 			(Some(Integral(_)), Some(Terminal::Type(_))) |
 			(Some(Array(_, _)), _) | (Some(Slice(_)), _) =>
 				self.unify(context, right, left, right_span, left_span),
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use crate::context::Context;
 use crate::error::Diagnostic;
 use crate::inference::Terminal;
-use crate::node::{Identifier, Path, Type, Value, ValueIndex};
+use crate::node::{Identifier, Path, Size, Type, Value, ValueIndex};
 use crate::query::QueryError;
 use crate::span::{S, Span};
 
@@ -29,6 +29,16 @@ pub fn field(context: &Context, scene: &mut Scene, place: Option<&S<TypeVariable
 				Some(field) => Ok(scene.ascribe(index, field.clone())),
 			}
 		}
+		Type::Slice(element) => match &field.node {
+			// TODO: use architecture pointer type
+			Identifier(name) if name == "length" => Ok(scene.ascribe(index,
+				S::new(Type::Unsigned(Size::Quad), field.span.clone()))),
+			Identifier(name) if name == "address" => Ok(scene.ascribe(index,
+				S::new(Type::Pointer(element.clone()), field.span.clone()))),
+			_ => context.pass(Diagnostic::error()
+				.message(format!("slice has no field: {}", field))
+				.label(field.span.label())),
+		},
 		other => context.pass(Diagnostic::error()
 			.label(span.label().with_message(other.to_string()))
 			.message("type is not a structure")),
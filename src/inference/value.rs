@@ -176,13 +176,11 @@ pub fn value(context: &Context, scene: &mut Scene, place: Option<&S<TypeVariable
 			.map(|(variable, _)| variable).unwrap()).get(),
 		ValueNode::Path(path) => super::path(context,
 			scene, value, index, path)?,
-		ValueNode::String(string) => {
-			let mut value = Value::default();
-			let size = ValueNode::Integral(string.len() as i128);
-			value.root = value.insert(S::new(size, span.clone()));
+		ValueNode::Type(_) => return context.pass(Diagnostic::error()
+			.message("type is not a value").label(span.label())),
+		ValueNode::String(_) => {
 			let element = S::new(Type::Unsigned(Size::Byte), span.clone());
-			let node = Type::Array(Box::new(element), value);
-			scene.ascribe(index, S::new(node, span.clone()))
+			scene.ascribe(index, S::new(Type::Slice(Box::new(element)), span.clone()))
 		}
 		ValueNode::Register(_) => {
 			let variable = scene.next();
@@ -192,11 +190,10 @@ pub fn value(context: &Context, scene: &mut Scene, place: Option<&S<TypeVariable
 			let variable = scene.next();
 			for element in elements {
 				let other = self::value(context, scene, place, value, element)?;
-				scene.unify(context, variable, other, span, &value[other].span);
+				scene.unify(context, variable, other, span, &value[*element].span);
 			}
 
-			// TODO: perform evaluation of array size
-			unimplemented!()
+			scene.terminal(index, Terminal::Array(variable, elements.len()))
 		}
 		ValueNode::Integral(node) => integral(context, scene, index, node, span.clone())?,
 		ValueNode::Truth(_) => scene.ascribe(index, S::new(Type::Truth, span.clone())),
@@ -9,28 +9,73 @@
 mod error;
 mod other;
 mod context;
+mod evaluate;
+mod format;
 mod inference;
 mod generate;
 mod analysis;
 mod binary;
+mod cache;
 mod arena;
 mod query;
 mod parse;
+mod server;
 mod node;
+mod options;
 mod span;
+mod watch;
 
 type Result<T> = std::result::Result<T, query::QueryError>;
 
 fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
-	let context = &context::Context::default();
-	let path = std::path::Path::new("examples/structures.lc");
-	query::emit(context, execute(context, path));
-	Ok(context::display(context)?)
+	let options = options::options()?;
+	if options.server { return Ok(server::serve(&options)?); }
+	if let Some(format) = &options.format {
+		let context = context::Context::default();
+		let formatted = format::format(&context, format)?;
+		context::display(&context, &options.diagnostics)?;
+		if !formatted { std::process::exit(1); }
+		return Ok(());
+	}
+
+	let mut context = context::Context::default();
+	context.threads = options.threads;
+	let result = execute(&context, &options);
+	query::emit(&context, graph(&context, &options, result));
+	context::display(&context, &options.diagnostics)?;
+	if options.statistics { cache::report(&context); }
+	if options.timings { query::timings(&context); }
+	match options.watch {
+		true => Ok(watch::watch(context, &options)?),
+		false => Ok(()),
+	}
 }
 
-fn execute(context: &context::Context, path: &std::path::Path) -> Result<()> {
-	parse::parse(context, path)?;
+fn execute(context: &context::Context, options: &options::Options) -> Result<()> {
+	if options.clean { cache::clean(context, &options.path)?; }
+	parse::parse(context, &options.path)?;
 	node::positions(context);
+	let cache = cache::load(context, &options.path);
 	node::present_all(context)?;
-	binary::compile(context)
+	cache.iter().for_each(|present| cache::verify(context, present));
+	compile(context, options)
+}
+
+fn compile(context: &context::Context, options: &options::Options) -> Result<()> {
+	match &options.emit {
+		options::Emit::Binary => binary::compile(context),
+		options::Emit::Header(path) => binary::header(context, path),
+		options::Emit::Graph(_) => binary::compile(context),
+	}?;
+
+	Ok(cache::save(context, &options.path))
+}
+
+/// Writes the query graph if it is emitted even if compilation fails.
+fn graph(context: &context::Context, options: &options::Options,
+		 result: Result<()>) -> Result<()> {
+	match &options.emit {
+		options::Emit::Graph(path) => query::graph(context, path).and(result),
+		_ => result,
+	}
 }
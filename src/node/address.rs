@@ -1,9 +1,11 @@
+use std::convert::TryFrom;
+
 use crate::context::Context;
 use crate::error::Diagnostic;
 use crate::query::Key;
 use crate::span::{S, Span};
 
-use super::{FunctionPath, Identifier, Symbol};
+use super::{FunctionPath, Identifier, Symbol, ValueNode};
 
 pub type Address = usize;
 pub type SymbolSize = usize;
@@ -12,11 +14,15 @@ pub fn load(context: &Context, parent: Option<Key>, symbol: &Symbol,
 			span: Option<Span>) -> crate::Result<Address> {
 	let key = Key::LoadAddress(symbol.clone());
 	context.address.scope(parent, key.clone(), span.clone(), || {
-		let targets = &[Identifier("load".to_string())];
-		let annotation = annotation(context, symbol, targets);
-		if let Some(_annotation) = annotation {
-			// TODO: load annotation address
-			return Ok(1024 * 1024);
+		if let Some(address) = super::imported(context, Some(key.clone()), symbol)? {
+			return Ok(address);
+		}
+
+		let targets = &[Identifier("load".to_string()), Identifier("region".to_string())];
+		if let Some(annotation) = annotation(context, symbol, &targets[..1]) {
+			return evaluate(context, Some(key), &annotation);
+		} else if let Some(annotation) = annotation(context, symbol, &targets[1..]) {
+			return super::place(context, Some(key), symbol, &annotation);
 		}
 
 		let span = self::span(context, symbol);
@@ -29,8 +35,8 @@ pub fn load(context: &Context, parent: Option<Key>, symbol: &Symbol,
 
 		let base = load(context, Some(key.clone()), other, Some(span.clone()))?;
 		if let Symbol::Module(_) = other { return Ok(base); }
-		let size = size(context, Some(key), other, Some(span))?;
-		Ok(align(other, symbol, base + size))
+		let size = size(context, Some(key.clone()), other, Some(span.clone()))?;
+		align(context, Some(key), other, symbol, base + size, Some(span))
 	}).map(|address| *address)
 }
 
@@ -38,12 +44,16 @@ pub fn start(context: &Context, parent: Option<Key>, symbol: &Symbol,
 			 span: Option<Span>) -> crate::Result<Address> {
 	let key = Key::VirtualAddress(symbol.clone());
 	context.address.scope(parent, key.clone(), span.clone(), || {
-		let load = Identifier("load".to_string());
-		let targets = &[Identifier("virtual".to_string()), load];
-		let annotation = annotation(context, symbol, targets);
-		if let Some(_annotation) = annotation {
-			// TODO: load annotation address
-			return Ok(1024 * 1024);
+		if let Some(address) = super::imported(context, Some(key.clone()), symbol)? {
+			return Ok(address);
+		}
+
+		let targets = &[Identifier("virtual".to_string()),
+			Identifier("load".to_string()), Identifier("region".to_string())];
+		if let Some(annotation) = annotation(context, symbol, &targets[..1]) {
+			return evaluate(context, Some(key), &annotation);
+		} else if annotation(context, symbol, &targets[1..]).is_some() {
+			return self::load(context, Some(key), symbol, span);
 		}
 
 		let span = Some(self::span(context, symbol));
@@ -57,8 +67,8 @@ pub fn start(context: &Context, parent: Option<Key>, symbol: &Symbol,
 
 		let base = start(context, Some(key.clone()), other, span.clone())?;
 		if let Symbol::Module(_) = other { return Ok(base); }
-		let size = size(context, Some(key), other, span)?;
-		Ok(align(other, symbol, base + size))
+		let size = size(context, Some(key.clone()), other, span.clone())?;
+		align(context, Some(key), other, symbol, base + size, span)
 	}).map(|address| *address)
 }
 
@@ -78,6 +88,8 @@ pub fn size(context: &Context, parent: Option<Key>, symbol: &Symbol,
 					Some(key.clone()), path.clone(), span.clone())?;
 				super::size(context, Some(key), &path.node, span)
 			}
+			Symbol::Function(_) if context.imports.contains_key(symbol) =>
+				Ok(super::import(context, Some(key.clone()), symbol)?.unwrap().2),
 			// TODO: use architecture generation
 			Symbol::Function(path) => Ok(crate::generate::x86::lower(context,
 				Some(key.clone()), path, span)?.bytes.len()),
@@ -91,19 +103,54 @@ pub fn size(context: &Context, parent: Option<Key>, symbol: &Symbol,
 		}).map(|size| *size)
 }
 
-fn align(other: &Symbol, symbol: &Symbol, address: Address) -> Address {
-	match (other, symbol) {
-		(Symbol::Function(_), Symbol::Function(_)) => address,
-		(Symbol::Variable(_), Symbol::Variable(_)) => address,
-		_ => {
-			// TODO: derive alignment from annotation
-			let alignment = 4 * 1024;
-			crate::other::ceiling(address, alignment)
+/// Returns the name of the output section for a symbol. Symbols
+/// without a `@section` annotation inherit from their module.
+pub fn section(context: &Context, parent: Option<Key>, symbol: &Symbol,
+			   span: Option<Span>) -> crate::Result<Option<String>> {
+	let key = Key::Section(symbol.clone());
+	context.names.scope(parent, key.clone(), span, || {
+		let targets = &[Identifier("section".to_string())];
+		if let Some(annotation) = annotation(context, symbol, targets) {
+			let S { node: value, span } = &annotation;
+			return match &value[value.root].node {
+				ValueNode::String(name) => Ok(Some(name.clone())),
+				_ => context.pass(Diagnostic::error()
+					.message("section name must be a string").label(span.label())),
+			};
 		}
+
+		let module = context.positions.read()[symbol].parent.clone();
+		module.map(|path| section(context, Some(key.clone()), &Symbol::Module(path), None))
+			.transpose().map(Option::flatten)
+	}).map(|name| name.as_ref().clone())
+}
+
+/// Evaluates an address annotation at compilation time.
+pub(super) fn evaluate(context: &Context, parent: Option<Key>,
+					   annotation: &S<super::Value>) -> crate::Result<Address> {
+	let S { node: value, span } = annotation;
+	let address = crate::evaluate::integral(context, parent, value, &value.root)?;
+	Address::try_from(address).map_err(|_| context.error(Diagnostic::error()
+		.message(format!("invalid address: {}", address)).label(span.label())))
+}
+
+pub(super) fn align(context: &Context, parent: Option<Key>, other: &Symbol, symbol: &Symbol,
+		 address: Address, span: Option<Span>) -> crate::Result<Address> {
+	// TODO: derive alignment from annotation
+	let alignment = 4 * 1024;
+	let section = |symbol| section(context, parent.clone(), symbol, span.clone());
+	if section(other)? != section(symbol)? {
+		return Ok(crate::other::ceiling(address, alignment));
 	}
+
+	Ok(match (other, symbol) {
+		(Symbol::Function(_), Symbol::Function(_)) => address,
+		(Symbol::Variable(_), Symbol::Variable(_)) => address,
+		_ => crate::other::ceiling(address, alignment),
+	})
 }
 
-fn span(context: &Context, symbol: &Symbol) -> Span {
+pub(super) fn span(context: &Context, symbol: &Symbol) -> Span {
 	match symbol {
 		Symbol::Function(FunctionPath(path, kind)) =>
 			context.functions.get(path).unwrap()[*kind]
@@ -115,7 +162,7 @@ fn span(context: &Context, symbol: &Symbol) -> Span {
 	}
 }
 
-fn annotation(context: &Context, symbol: &Symbol,
+pub(super) fn annotation(context: &Context, symbol: &Symbol,
 			  targets: &[Identifier]) -> Option<S<super::Value>> {
 	targets.iter().find_map(|target| match symbol {
 		Symbol::Function(FunctionPath(path, kind)) =>
@@ -144,8 +191,16 @@ fn previous(context: &Context, parent: Option<Key>, symbol: &Symbol,
 	}
 }
 
+/// Finds the symbol that another symbol is placed after. Symbols
+/// placed into a region are skipped such that symbols that follow
+/// them are not placed within the region.
 fn find(context: &Context, parent: Option<Key>, symbol: &Symbol,
 		targets: &[Identifier], span: Option<Span>) -> crate::Result<Option<Symbol>> {
+	let region = &[Identifier("region".to_string())];
+	if annotation(context, symbol, region).is_some() {
+		return previous(context, parent, symbol, targets, span);
+	}
+
 	if let Symbol::Module(path) = symbol {
 		let module = context.modules.get(path).unwrap();
 		let contains = |target| !module.annotations.contains_key(target);
@@ -161,7 +216,7 @@ fn find(context: &Context, parent: Option<Key>, symbol: &Symbol,
 		super::present(context, parent.clone(), function, span.clone())?
 			&& targets.iter().any(|target| !context.functions.get(path).unwrap()[*kind]
 			.annotations.contains_key(target)) => return Ok(Some(symbol.clone())),
-		Symbol::Variable(path) if targets.iter().any(|target| context.statics.get(path)
+		Symbol::Variable(path) if targets.iter().any(|target| !context.statics.get(path)
 			.unwrap().annotations.contains_key(target)) => return Ok(Some(symbol.clone())),
 		_ => previous(context, parent, symbol, targets, span),
 	}
@@ -0,0 +1,16 @@
+use crate::context::Context;
+use crate::error::Diagnostic;
+
+/// Evaluates the condition of each item level static assertion.
+pub fn assertions(context: &Context) -> crate::Result<()> {
+	let assertions = context.assertions.read();
+	assertions.iter().map(|assertion| {
+		let value = &assertion.node;
+		match crate::evaluate::integral(context, None, value, &value.root)? {
+			0 => context.pass(Diagnostic::error()
+				.message("static assertion failed")
+				.label(value[value.root].span.label())),
+			_ => Ok(()),
+		}
+	}).filter(Result::is_err).last().unwrap_or(Ok(()))
+}
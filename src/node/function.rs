@@ -2,6 +2,8 @@ use std::collections::HashMap;
 use std::fmt;
 use std::ops::Index;
 
+use serde::{Deserialize, Serialize};
+
 use crate::context::Context;
 use crate::span::{S, Span};
 
@@ -9,7 +11,7 @@ use super::{Identifier, Path};
 
 pub type ValueIndex = usize;
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Value {
 	pub root: ValueIndex,
 	pub values: Vec<S<ValueNode>>,
@@ -32,10 +34,10 @@ impl Index<ValueIndex> for Value {
 	}
 }
 
-#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Variable(pub Identifier, pub u16);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ValueNode {
 	Block(Vec<ValueIndex>),
 	Let(S<Variable>, Option<S<Type>>, Option<ValueIndex>),
@@ -57,6 +59,8 @@ pub enum ValueNode {
 	Unary(Unary, ValueIndex),
 	Variable(Variable),
 	Path(Path),
+	/// A type passed to an intrinsic.
+	Type(Type),
 	String(String),
 	Register(Identifier),
 	Array(Vec<ValueIndex>),
@@ -67,7 +71,7 @@ pub enum ValueNode {
 	Break,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Type {
 	Void,
 	Rune,
@@ -129,7 +133,7 @@ impl fmt::Display for Type {
 	}
 }
 
-#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
 pub enum Size {
 	Byte = 8,
 	Word = 16,
@@ -149,7 +153,7 @@ impl fmt::Display for Size {
 	}
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Binary {
 	Dual(Dual),
 	Compare(Compare),
@@ -173,7 +177,7 @@ impl Binary {
 	}
 }
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Dual {
 	Add,
 	Minus,
@@ -205,7 +209,7 @@ impl Dual {
 	}
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Compare {
 	Less,
 	Greater,
@@ -215,7 +219,7 @@ pub enum Compare {
 	Equal,
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Unary {
 	Not,
 	Negate,
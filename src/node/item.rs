@@ -1,25 +1,42 @@
 use std::collections::HashMap;
 use std::fmt;
+use std::path::PathBuf;
 
 use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
 
 use crate::node::Variable;
-use crate::span::S;
+use crate::span::{S, Span};
+
+use super::address::Address;
 
 #[derive(Debug)]
 pub enum Item {
 	Symbol(Symbol),
+	/// A symbol imported from a library that
+	/// is not placed in the binary.
+	Import(Symbol),
 	ModuleEnd,
 }
 
-#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub enum Symbol {
 	Module(Path),
 	Variable(Path),
 	Function(FunctionPath),
 }
 
-#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+impl fmt::Display for Symbol {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Symbol::Module(path) => write!(f, "{}", path),
+			Symbol::Variable(path) => write!(f, "{}", path),
+			Symbol::Function(FunctionPath(path, _)) => write!(f, "{}", path),
+		}
+	}
+}
+
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Identifier(pub String);
 
 impl fmt::Display for Identifier {
@@ -29,7 +46,7 @@ impl fmt::Display for Identifier {
 	}
 }
 
-#[derive(Default, Clone, Hash, Eq, PartialEq)]
+#[derive(Default, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Path(pub Vec<Identifier>);
 
 impl Path {
@@ -67,10 +84,24 @@ impl fmt::Debug for Path {
 
 pub type Annotations = HashMap<Identifier, S<super::Value>>;
 
+/// Returns whether an admission is suppressed
+/// with the annotation `@admit identifier`.
+pub fn admitted(annotations: &Annotations, admission: &str) -> bool {
+	annotations.get(&Identifier("admit".to_string())).map(|value| {
+		let value = &value.node;
+		matches!(&value[value.root].node, super::ValueNode::String(string)
+			if string == admission)
+	}).unwrap_or(false)
+}
+
 #[derive(Debug)]
 pub struct Structure {
 	pub annotations: Annotations,
 	pub fields: IndexMap<Identifier, S<super::Type>>,
+	/// The widths of fields declared with an explicit number of bits.
+	pub bits: HashMap<Identifier, u8>,
+	/// The spans of the identifiers of fields.
+	pub identifiers: HashMap<Identifier, Span>,
 }
 
 #[derive(Debug)]
@@ -81,9 +112,56 @@ pub struct Static {
 	pub value: Option<super::Value>,
 }
 
+impl Static {
+	/// Returns whether the variable is annotated with a true `@constant`
+	/// and is placed in read only memory.
+	pub fn constant(&self, context: &crate::context::Context) -> crate::Result<bool> {
+		Ok(match self.annotations.get(&Identifier("constant".to_string())) {
+			None => false,
+			Some(annotation) => {
+				let value = &annotation.node;
+				crate::evaluate::integral(context, None, value, &value.root)? != 0
+			}
+		})
+	}
+}
+
+/// A window of memory declared with `@@region`.
+#[derive(Debug)]
+pub struct Region {
+	pub identifier: S<Identifier>,
+	pub start: super::Value,
+	pub size: super::Value,
+	pub attributes: S<String>,
+}
+
+/// A binary declared with `use "path" as identifier`
+/// from which symbols may be imported. The binary is
+/// read when a symbol is first imported from it.
+#[derive(Debug)]
+pub struct Library {
+	pub annotations: Annotations,
+	pub identifier: S<Identifier>,
+	pub path: S<PathBuf>,
+}
+
+/// A function or static variable imported from a library.
+#[derive(Debug)]
+pub struct Import {
+	pub library: Path,
+	pub symbol: ImportSymbol,
+	pub span: Span,
+}
+
+#[derive(Debug)]
+pub enum ImportSymbol {
+	Address(Address),
+	Name(String),
+}
+
 pub type FunctionKind = usize;
 
-#[derive(Debug, Default, Clone, Hash, Eq, PartialEq)]
+#[derive(Debug, Default, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub struct FunctionPath(pub Path, pub FunctionKind);
 
 #[derive(Debug)]
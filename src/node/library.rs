@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use crate::context::Context;
+use crate::error::Diagnostic;
+use crate::parse::Binary;
+use crate::query::Key;
+use crate::span::Span;
+
+use super::{Identifier, ImportSymbol, Path, Symbol};
+use super::address::{Address, SymbolSize};
+
+/// Reads the symbol table of a library.
+pub fn library(context: &Context, parent: Option<Key>, path: &Path,
+			   span: Option<Span>) -> crate::Result<Arc<Binary>> {
+	let key = Key::Library(path.clone());
+	context.binaries.scope(parent, key, span.clone(), || {
+		let library = context.libraries.get(path).unwrap();
+		let binary = crate::parse::binary(context, &library.path)?;
+		let targets = ["load", "virtual"].iter().map(|target| Identifier(target.to_string()));
+		let relocated = targets.clone().any(|target| library.annotations.contains_key(&target));
+		if relocated && !binary.2 && !super::admitted(&library.annotations, "relocation") {
+			context.emit(Diagnostic::warning().label(library.identifier.span.label())
+				.message("library does not contain relocation information")
+				.note("only the addresses of imported symbols are relocated")
+				.note("suppress with the annotation: @admit relocation"));
+		}
+		Ok(binary)
+	})
+}
+
+/// Returns the address and size of an imported symbol
+/// within its library. Returns nothing if the symbol
+/// is not imported.
+pub fn import(context: &Context, parent: Option<Key>,
+			  symbol: &Symbol) -> crate::Result<Option<(Arc<Binary>, Address, SymbolSize)>> {
+	let import = match context.imports.get(symbol) {
+		Some(import) => import,
+		None => return Ok(None),
+	};
+
+	if !context.libraries.contains_key(&import.library) {
+		return context.pass(Diagnostic::error().label(import.span.label())
+			.message(format!("undefined library: {}", import.library)));
+	}
+
+	let span = Some(import.span.clone());
+	let binary = library(context, parent, &import.library, span)?;
+	let (address, size) = match &import.symbol {
+		ImportSymbol::Address(address) => (*address, 0),
+		ImportSymbol::Name(name) => *binary.0.get(name).ok_or_else(||
+			context.error(Diagnostic::error().label(import.span.label())
+				.message(format!("undefined symbol: {}, in library", name))))?,
+	};
+	Ok(Some((binary, address, size)))
+}
+
+/// Returns the address of an imported symbol relocated
+/// by the address annotations on its library. Returns
+/// nothing if the symbol is not imported.
+pub fn imported(context: &Context, parent: Option<Key>,
+				symbol: &Symbol) -> crate::Result<Option<Address>> {
+	let (binary, address, _) = match import(context, parent.clone(), symbol)? {
+		Some(import) => import,
+		None => return Ok(None),
+	};
+
+	let import = context.imports.get(symbol).unwrap();
+	let library = context.libraries.get(&import.library).unwrap();
+	let (_, base, _) = binary.as_ref();
+	let targets = [Identifier("virtual".to_string()), Identifier("load".to_string())];
+	let relocated = match targets.iter().find_map(|target| library.annotations.get(target)) {
+		Some(annotation) => super::address::evaluate(context, parent, annotation)?,
+		None => *base,
+	};
+
+	(relocated + address).checked_sub(*base).map(Some).ok_or_else(||
+		context.error(Diagnostic::error().label(import.span.label())
+			.message(format!("address: {:#x}, is before the library base: {:#x}",
+				address, base))))
+}
@@ -1,15 +1,23 @@
+pub use assertion::*;
 pub use context::*;
 pub use function::*;
 pub use item::*;
+pub use library::*;
 pub use offsets::*;
 pub use position::*;
 pub use present::*;
+pub use reference::*;
+pub use region::*;
 
 pub mod address;
 
+mod assertion;
 mod offsets;
 mod function;
 mod position;
 mod present;
+mod reference;
+mod region;
 mod context;
 mod item;
+mod library;
@@ -1,17 +1,53 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use serde::{Deserialize, Serialize};
+
 use crate::context::Context;
-use crate::node::{Identifier, Path, Size, Type};
+use crate::error::Diagnostic;
+use crate::generate::x86::Mode;
+use crate::node::{Identifier, Path, Size, Structure, Type, Value, ValueNode};
 use crate::query::{Key, QueryError};
 use crate::span::Span;
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Offsets {
 	pub fields: HashMap<Identifier, usize>,
+	pub bits: HashMap<Identifier, Bits>,
+	pub alignment: usize,
 	pub size: usize,
 }
 
+/// The position of a bit field within the storage unit
+/// at the offset of the field. Consecutive bit fields share
+/// a storage unit of at most sixty four bits.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Bits {
+	pub unit: Size,
+	pub shift: u8,
+	pub width: u8,
+}
+
+/// The mode that types are laid out for and functions are generated in.
+// TODO: dependent on architecture
+pub const MODE: Mode = Mode::Long;
+
+/// Returns the size of a pointer in bytes.
+pub fn pointer(mode: Mode) -> usize {
+	mode.size().bytes()
+}
+
+/// Returns the size of a slice in bytes. A slice is the
+/// address of its first element followed by its length.
+pub fn slice(mode: Mode) -> usize {
+	slice_length(mode) + pointer(mode)
+}
+
+/// Returns the offset of the length of a slice.
+pub fn slice_length(mode: Mode) -> usize {
+	pointer(mode)
+}
+
 pub fn size(context: &Context, parent: Option<Key>, path: &Type,
 			span: Option<Span>) -> crate::Result<usize> {
 	Ok(match path {
@@ -20,29 +56,61 @@ pub fn size(context: &Context, parent: Option<Key>, path: &Type,
 		Type::Rune => Size::Double.bytes(),
 		Type::Structure(path) => offsets(context, parent, path, span)?.size,
 		Type::Signed(size) | Type::Unsigned(size) => size.bytes(),
-		// TODO: dependent on architecture
-		Type::Pointer(_) => unimplemented!(),
-		// TODO: dependent on compile time evaluation
-		Type::Array(_, _) => unimplemented!(),
-		// TODO: dependent on pointer size
-		Type::Slice(_) => unimplemented!(),
+		Type::Pointer(_) => pointer(MODE),
+		Type::Array(node, length) => {
+			let length = self::length(context, parent.clone(), length)?;
+			size(context, parent, &node.node, span)? * length
+		}
+		Type::Slice(_) => slice(MODE),
 	})
 }
 
+pub fn length(context: &Context, parent: Option<Key>,
+			  length: &Value) -> crate::Result<usize> {
+	let span = &length[length.root].span;
+	match crate::evaluate::integral(context, parent, length, &length.root)? {
+		length if length >= 0 => Ok(length as usize),
+		_ => context.pass(Diagnostic::error()
+			.message("array length is negative").label(span.label())),
+	}
+}
+
 pub fn offset(context: &Context, parent: Option<Key>, path: &Type,
 			  field: &Identifier, span: Option<Span>) -> crate::Result<usize> {
 	Ok(match path {
 		Type::Structure(path) => offsets(context,
 			parent, path, span)?.fields[field],
-		Type::Slice(_) => {
-			// TODO: dependent on pointer size
-			unimplemented!()
-		}
+		Type::Slice(_) => match field {
+			Identifier(field) if field == "length" => slice_length(MODE),
+			_ => 0,
+		},
 		other => panic!("offset on type: {}", other),
 	})
 }
 
-// TODO: consider C and packed representation
+/// Returns the alignment of a type in bytes. Structures are packed
+/// unless annotated with `@representation "C"` in which case they
+/// are aligned to the largest alignment of their fields or bit field units.
+pub fn alignment(context: &Context, parent: Option<Key>, path: &Type,
+				 span: Option<Span>) -> crate::Result<usize> {
+	Ok(match path {
+		Type::Void | Type::Never => 1,
+		Type::Truth => Size::Byte.bytes(),
+		Type::Rune => Size::Double.bytes(),
+		Type::Signed(size) | Type::Unsigned(size) => size.bytes(),
+		Type::Pointer(_) | Type::Slice(_) => pointer(MODE),
+		Type::Array(node, _) => alignment(context, parent, &node.node, span)?,
+		Type::Structure(path) => offsets(context, parent, path, span)?.alignment,
+	})
+}
+
+/// Returns whether a structure is annotated with `@representation "C"`.
+pub fn representation(structure: &Structure) -> bool {
+	structure.annotations.get(&Identifier("representation".to_string()))
+		.map(|value| matches!(&value.node[value.node.root].node,
+			ValueNode::String(string) if string == "C")).unwrap_or(false)
+}
+
 pub fn offsets(context: &Context, parent: Option<Key>, path: &Path,
 			   span: Option<Span>) -> crate::Result<Arc<Offsets>> {
 	let key = Key::Offsets(path.clone());
@@ -51,13 +119,82 @@ pub fn offsets(context: &Context, parent: Option<Key>, path: &Path,
 		let structure = context.structures.get(&path)
 			.ok_or(QueryError::Failure)?;
 
-		for (field, path) in &structure.fields {
-			offsets.fields.insert(field.clone(), offsets.size);
-			offsets.size += size(context, Some(key.clone()),
-				&path.node, Some(path.span.clone()))?;
+		offsets.alignment = 1;
+		let aligned = representation(&structure);
+		let fields: Vec<_> = structure.fields.iter().collect();
+		let mut index = 0;
+		while let Some((field, path)) = fields.get(index) {
+			if let Some(width) = structure.bits.get(field) {
+				let mut run = vec![(*field, *width)];
+				let mut total = *width as usize;
+				index += 1;
+
+				while let Some((field, _)) = fields.get(index) {
+					match structure.bits.get(field) {
+						Some(width) if total + *width as usize <= 64 => {
+							run.push((*field, *width));
+							total += *width as usize;
+							index += 1;
+						}
+						_ => break,
+					}
+				}
+
+				let unit = unit(total);
+				if aligned {
+					offsets.alignment = offsets.alignment.max(unit.bytes());
+					offsets.size = round(offsets.size, unit.bytes());
+				}
+
+				let mut shift = 0;
+				for (field, width) in run {
+					offsets.fields.insert(field.clone(), offsets.size);
+					offsets.bits.insert(field.clone(), Bits { unit, shift, width });
+					shift += width;
+				}
+
+				offsets.size += unit.bytes();
+				continue;
+			}
+
+			let span = Some(path.span.clone());
+			if aligned {
+				let alignment = alignment(context, Some(key.clone()), &path.node, span.clone())?;
+				offsets.alignment = offsets.alignment.max(alignment);
+				offsets.size = round(offsets.size, alignment);
+			}
+
+			offsets.fields.insert((*field).clone(), offsets.size);
+			offsets.size += size(context, Some(key.clone()), &path.node, span)?;
+			index += 1;
 		}
 
+		offsets.size = round(offsets.size, offsets.alignment);
 		Ok(offsets)
 	})
 }
 
+/// Returns the bit field at a field of a type if it exists.
+pub fn bits(context: &Context, parent: Option<Key>, path: &Type,
+			field: &Identifier, span: Option<Span>) -> crate::Result<Option<Bits>> {
+	Ok(match path {
+		Type::Structure(path) => offsets(context,
+			parent, path, span)?.bits.get(field).copied(),
+		_ => None,
+	})
+}
+
+/// Returns the smallest integral size containing the number of bits.
+fn unit(bits: usize) -> Size {
+	match (bits + 7) / 8 {
+		1 => Size::Byte,
+		2 => Size::Word,
+		3 | 4 => Size::Double,
+		_ => Size::Quad,
+	}
+}
+
+fn round(size: usize, alignment: usize) -> usize {
+	(size + alignment - 1) / alignment * alignment
+}
+
@@ -1,5 +1,5 @@
 use crate::context::Context;
-use crate::node::{Item, Path};
+use crate::node::{Identifier, Item, Path, ValueNode};
 
 use super::Symbol;
 
@@ -9,35 +9,85 @@ pub struct Position {
 	pub previous: Option<Symbol>,
 }
 
+/// An item and the items within it if it is a module.
+#[derive(Debug)]
+enum Node {
+	Symbol(Symbol),
+	Import(Symbol),
+	Module(Path, Vec<Node>),
+}
+
 pub fn positions(context: &Context) {
-	let mut stack = Vec::new();
-	stack.push(Position::default());
+	let mut stack = vec![(None, Vec::new())];
 	let items = context.items.read();
 	items.iter().for_each(|item| match item {
-		Item::Symbol(symbol) => {
-			if let Some(module) = &stack.last().unwrap().parent {
-				let module = &mut context.modules.get_mut(module).unwrap();
-				if module.first.is_none() { module.first = Some(symbol.clone()); }
-				module.last = Some(symbol.clone());
-			}
+		Item::Symbol(Symbol::Module(path)) =>
+			stack.push((Some(path.clone()), Vec::new())),
+		Item::Symbol(symbol) => stack.last_mut().unwrap()
+			.1.push(Node::Symbol(symbol.clone())),
+		Item::Import(symbol) => stack.last_mut().unwrap()
+			.1.push(Node::Import(symbol.clone())),
+		Item::ModuleEnd => {
+			let (path, nodes) = stack.pop().unwrap();
+			let node = Node::Module(path.unwrap(), nodes);
+			stack.last_mut().unwrap().1.push(node);
+		}
+	});
+
+	let (_, nodes) = stack.pop().unwrap();
+	place(context, None, None, nodes, &mut None);
+}
+
+/// Positions the items of a module such that items in the same
+/// section are consecutive. Sections are ordered by their first item.
+fn place(context: &Context, parent: Option<&Path>, inherited: Option<String>,
+		 nodes: Vec<Node>, previous: &mut Option<Symbol>) {
+	let mut sections = Vec::new();
+	let mut nodes: Vec<_> = nodes.into_iter().map(|node| {
+		let section = match &node {
+			Node::Symbol(symbol) => section(context, symbol),
+			Node::Module(path, _) => section(context, &Symbol::Module(path.clone())),
+			Node::Import(_) => None,
+		}.or_else(|| inherited.clone());
 
-			match symbol {
-				Symbol::Function(_) | Symbol::Variable(_) => {
-					let position = stack.last().unwrap().clone();
-					context.positions.write().insert(symbol.clone(), position);
-					stack.last_mut().unwrap().previous = Some(symbol.clone());
-				}
-				Symbol::Module(path) => {
-					let parent = Some(path.clone());
-					stack.push(Position { parent, previous: None });
-				}
+		let index = sections.iter().position(|other| other == &section)
+			.unwrap_or_else(|| { sections.push(section.clone()); sections.len() - 1 });
+		(index, section, node)
+	}).collect();
+
+	nodes.sort_by_key(|(index, _, _)| *index);
+	for (_, section, node) in nodes {
+		let position = Position { parent: parent.cloned(), previous: previous.clone() };
+		let symbol = match node {
+			Node::Import(symbol) => {
+				context.positions.write().insert(symbol, position);
+				continue;
 			}
+			Node::Symbol(symbol) => symbol,
+			Node::Module(path, nodes) => {
+				let symbol = Symbol::Module(path.clone());
+				place(context, Some(&path), section, nodes, &mut None);
+				symbol
+			}
+		};
+
+		if let Some(module) = parent {
+			let module = &mut context.modules.get_mut(module).unwrap();
+			if module.first.is_none() { module.first = Some(symbol.clone()); }
+			module.last = Some(symbol.clone());
 		}
-		Item::ModuleEnd => {
-			let path = Symbol::Module(stack.pop().unwrap().parent.unwrap());
-			context.positions.write().insert(path.clone(), stack.last().unwrap().clone());
-			stack.last_mut().unwrap().previous = Some(path);
-		}
-	})
+
+		context.positions.write().insert(symbol.clone(), position);
+		*previous = Some(symbol);
+	}
 }
 
+/// Returns the name of the section annotation on a symbol.
+fn section(context: &Context, symbol: &Symbol) -> Option<String> {
+	let targets = &[Identifier("section".to_string())];
+	let value = super::address::annotation(context, symbol, targets)?.node;
+	match &value[value.root].node {
+		ValueNode::String(name) => Some(name.clone()),
+		_ => None,
+	}
+}
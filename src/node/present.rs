@@ -1,20 +1,76 @@
 use std::collections::HashSet;
 
 use crate::context::Context;
+use crate::error::Diagnostic;
 use crate::query::Key;
 use crate::span::Span;
 
-use super::{FunctionPath, ValueNode};
+use super::{FunctionPath, Identifier, Symbol, ValueNode};
 
 pub fn present_all(context: &Context) -> crate::Result<()> {
 	let mut present = HashSet::new();
-	context.unit.ephemeral(None, Key::TraverseRoots, None, || context.functions.iter()
-		.try_for_each(|path| path.value().iter().filter(|function| function.is_root)
-			.enumerate().map(|(kind, _)| FunctionPath(path.key().clone(), kind))
-			.try_for_each(|path| Ok(if !present.contains(&path) {
-				present.insert(path.clone());
-				self::function(context, &mut present, &path)?
-			})))).map(|_| *context.present.write() = present)
+	let handlers = handlers(context);
+	if let [(_, span), other @ ..] = handlers.as_slice() {
+		if !other.is_empty() {
+			let diagnostic = Diagnostic::error()
+				.message("multiple panic handlers").label(span.label());
+			return context.pass(other.iter().fold(diagnostic,
+				|diagnostic, (_, span)| diagnostic.label(span.other())));
+		}
+	}
+
+	let identifier = &panic_identifier();
+	let export = &Identifier("export".to_string());
+	let roots: Vec<_> = context.functions.iter().flat_map(|path| path.value().iter()
+		.enumerate().filter(|(_, function)| function.is_root || function.annotations
+			.contains_key(identifier) || function.annotations.contains_key(export))
+		.map(|(kind, _)| FunctionPath(path.key().clone(), kind))
+		.collect::<Vec<_>>()).collect();
+
+	check(context, &roots)?;
+	context.unit.ephemeral(None, Key::TraverseRoots, None, || roots.iter()
+		.try_for_each(|path| Ok(if !present.contains(path) {
+			present.insert(path.clone());
+			self::function(context, &mut present, path)?
+		}))).map(|_| *context.present.write() = present)
+}
+
+/// Type checks the functions reachable from the roots in parallel
+/// such that traversing the roots only reads derived queries.
+fn check(context: &Context, roots: &[FunctionPath]) -> crate::Result<()> {
+	let mut visited: HashSet<_> = roots.iter().cloned().collect();
+	let mut frontier = roots.to_vec();
+	while !frontier.is_empty() {
+		let results = crate::context::parallel(context,
+			&frontier, |path| callees(context, path))?;
+		frontier = Vec::new();
+		for result in results {
+			if let Ok(callees) = &result {
+				frontier.extend(callees.iter().filter(|path|
+					visited.insert((*path).clone())).cloned());
+			}
+			crate::query::emit(context, result);
+		}
+	}
+	Ok(())
+}
+
+/// Returns the function annotated with `@panic`. Bounds
+/// checks are only generated if a panic handler exists.
+pub fn panic(context: &Context) -> Option<FunctionPath> {
+	handlers(context).into_iter().next().map(|(path, _)| path)
+}
+
+fn handlers(context: &Context) -> Vec<(FunctionPath, Span)> {
+	let identifier = &panic_identifier();
+	context.functions.iter().flat_map(|entry| entry.value().iter().enumerate()
+		.filter(|(_, function)| function.annotations.contains_key(identifier))
+		.map(|(kind, function)| (FunctionPath(entry.key().clone(), kind),
+			function.identifier.span.clone())).collect::<Vec<_>>()).collect()
+}
+
+fn panic_identifier() -> Identifier {
+	Identifier("panic".to_string())
 }
 
 pub fn present(context: &Context, parent: Option<Key>, path: &FunctionPath,
@@ -27,18 +83,28 @@ pub fn present(context: &Context, parent: Option<Key>, path: &FunctionPath,
 
 fn function(context: &Context, present: &mut HashSet<FunctionPath>,
 			path: &FunctionPath) -> crate::Result<()> {
+	callees(context, path)?.into_iter().try_for_each(|function|
+		Ok(if !present.contains(&function) {
+			present.insert(function.clone());
+			self::function(context, present, &function)?;
+		}))
+}
+
+/// Returns the functions called within a function.
+pub fn callees(context: &Context, path: &FunctionPath) -> crate::Result<Vec<FunctionPath>> {
+	let symbol = Symbol::Function(path.clone());
+	if context.imports.contains_key(&symbol) {
+		return Ok(Vec::new());
+	}
+
 	let function = crate::node::function(context, None, path, None)?;
 	let types = crate::inference::type_function(context, None, path, None)?;
-	types.functions.iter().try_for_each(|(index, kind)| {
+	Ok(types.functions.iter().map(|(index, kind)| {
 		let path = match &function.value[*index].node {
 			ValueNode::Call(path, _) => path.clone(),
 			_ => panic!("value: {}, is not a function call", index),
 		};
 
-		let function = FunctionPath(path.node, *kind);
-		Ok(if !present.contains(&function) {
-			present.insert(function.clone());
-			self::function(context, present, &function)?;
-		})
-	})
+		FunctionPath(path.node, *kind)
+	}).collect())
 }
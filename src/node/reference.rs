@@ -0,0 +1,303 @@
+use std::sync::Arc;
+
+use codespan::FileId;
+
+use crate::context::Context;
+use crate::error::Diagnostic;
+use crate::inference::Types;
+use crate::parse::{Include, Reference};
+use crate::span::Span;
+
+use super::{FunctionPath, Identifier, Parameter, Path, Symbol, Type, Value, ValueIndex, ValueNode};
+
+/// A symbol or a field of a data structure.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Target {
+	Symbol(Path),
+	Field(Path, Identifier),
+}
+
+/// Returns the target named by the identifier at an offset and the span
+/// of the identifier. Identifiers within a path name the module prefix.
+pub fn target(context: &Context, file: FileId, offset: usize) -> Option<(Target, Span)> {
+	let references = context.references.read().clone();
+	let reference = references.iter().flat_map(|reference| elements(context, reference))
+		.filter(|(_, span)| span.contains(file, offset))
+		.min_by_key(|(_, span)| span.range().len());
+	if let Some((path, span)) = reference {
+		return Some((Target::Symbol(path), span));
+	}
+
+	let definitions = context.definitions.read();
+	let definition = definitions.iter().find(|(_, span)| span.contains(file, offset));
+	if let Some((path, span)) = definition {
+		return Some((Target::Symbol(path.clone()), span.clone()));
+	}
+
+	for structure in context.structures.iter() {
+		for (field, span) in &structure.identifiers {
+			if span.contains(file, offset) {
+				let target = Target::Field(structure.key().clone(), field.clone());
+				return Some((target, span.clone()));
+			}
+		}
+	}
+
+	values(context).into_iter().filter(|(value, _)| value.values.get(value.root)
+		.filter(|node| node.span.contains(file, offset)).is_some())
+		.find_map(|(value, types)| fields(&value, types.as_deref())
+			.find(|(_, _, span, _)| span.contains(file, offset))
+			.map(|(structure, field, span, _)| (Target::Field(structure, field), span)))
+}
+
+/// Returns the spans of every identifier that names a target
+/// including its definition. Identifiers that name an alias
+/// of the target introduced with `use ... as` are excluded.
+pub fn references(context: &Context, target: &Target) -> Vec<Span> {
+	occurrences(context, target).into_iter().map(|(span, _)| span).collect()
+}
+
+/// Returns the functions that contain a call to a function.
+pub fn callers(context: &Context, path: &FunctionPath) -> Vec<FunctionPath> {
+	let mut callers: Vec<_> = functions(context).into_iter().filter(|caller|
+		super::callees(context, caller).map(|callees|
+			callees.contains(path)).unwrap_or(false)).collect();
+	callers.sort_by_key(|FunctionPath(path, kind)| (path.to_string(), *kind));
+	callers
+}
+
+/// Returns the replacements of every identifier that names a target.
+/// Shorthand fields in a structure creation are expanded such that the
+/// variable they name is unchanged. Renames that would change what an
+/// identifier resolves to in the scope of any occurrence are refused.
+pub fn rename(context: &Context, target: &Target,
+			  name: &str) -> Result<Vec<(Span, String)>, Diagnostic> {
+	let mut characters = name.chars();
+	let valid = characters.next().filter(|first| first.is_ascii_alphabetic() || *first == '_')
+		.is_some() && characters.all(|other| other.is_ascii_alphanumeric() || other == '_');
+	if !valid {
+		return Err(Diagnostic::error().message(format!("invalid identifier: {}", name)));
+	}
+
+	let identifier = Identifier(name.to_string());
+	let other = match target {
+		Target::Symbol(path) if !context.definitions.read().contains_key(path) =>
+			return Err(Diagnostic::error().message(format!("no definition for symbol: {}", path))),
+		Target::Symbol(path) => match shadowed(context, path, &identifier) {
+			Some(variable) => return Err(Diagnostic::error().label(variable.label())
+				.message(format!("variable: {}, shadows the renamed symbol", name))),
+			None => collision(context, path, &identifier),
+		},
+		Target::Field(path, _) => context.structures.get(path).and_then(|structure|
+			structure.identifiers.get(&identifier).cloned()),
+	};
+
+	if let Some(other) = other {
+		return Err(Diagnostic::error().label(other.label())
+			.message(format!("symbol: {}, already exists", name)));
+	}
+
+	let occurrences = occurrences(context, target);
+	let files = context.files.read();
+	let external = occurrences.iter().find(|(span, _)| files.path(span.file())
+		.and_then(|path| path.extension()).filter(|extension| *extension == "lc").is_none());
+	match (external, occurrences.is_empty()) {
+		(Some((span, _)), _) => Err(Diagnostic::error().label(span.label())
+			.message("symbol is not declared in a source file")),
+		(None, true) => Err(Diagnostic::error().message("no symbol to rename")),
+		(None, false) => Ok(occurrences.into_iter().map(|(span, shorthand)| {
+			let text = &files.source(span.file())[span.range()];
+			(span.clone(), match shorthand {
+				true => format!("{} = {}", name, text),
+				false => name.to_string(),
+			})
+		}).collect()),
+	}
+}
+
+/// Returns the span of a symbol named by an identifier in a scope
+/// that would include a symbol renamed to the identifier. Symbols
+/// are included by their module, by wildcard and by item imports.
+fn collision(context: &Context, path: &Path, identifier: &Identifier) -> Option<Span> {
+	let Path(mut elements) = path.clone();
+	elements.pop();
+	let parent = Path(elements);
+	let renamed = parent.push(identifier.clone());
+	let definitions = context.definitions.read();
+	if let Some(span) = definitions.get(&renamed) {
+		return Some(span.clone());
+	}
+
+	let inclusions = context.inclusions.read();
+	let scopes: Vec<_> = inclusions.iter().filter(|(_, include)| match &include.node {
+		Include::Wild(module) => module == &parent,
+		Include::Item(item) => item == path,
+		Include::As(_, _) => false,
+	}).map(|(scope, _)| scope.as_ref()).collect();
+	if scopes.is_empty() { return None; }
+
+	let root = Path(vec![identifier.clone()]);
+	if let Some(span) = definitions.get(&root) {
+		return Some(span.clone());
+	}
+
+	inclusions.iter().filter(|(other, _)| scopes.iter()
+		.any(|scope| overlap(*scope, other.as_ref())))
+		.find_map(|(_, include)| match &include.node {
+			Include::Wild(module) => Some(module.push(identifier.clone())),
+			Include::Item(item) if item.0.last() == Some(identifier) => Some(item.clone()),
+			Include::As(item, alias) if alias == identifier => Some(item.clone()),
+			_ => None,
+		}.filter(|other| other != &renamed && definitions.contains_key(other))
+			.map(|_| include.span.clone()))
+}
+
+/// Returns whether two scopes overlap. A scope without
+/// a span is outside of every module and includes them.
+fn overlap(scope: Option<&Span>, other: Option<&Span>) -> bool {
+	match (scope, other) {
+		(Some(scope), Some(other)) => {
+			let (left, right) = (scope.range(), other.range());
+			scope.file() == other.file() && left.start < right.end && right.start < left.end
+		}
+		_ => true,
+	}
+}
+
+/// Returns the span of a local variable named by an identifier that
+/// is declared before an unqualified reference to a symbol within
+/// the same function. The reference would name the variable if the
+/// symbol were renamed to the identifier.
+fn shadowed(context: &Context, path: &Path, identifier: &Identifier) -> Option<Span> {
+	let references = context.references.read().clone();
+	let unqualified: Vec<_> = references.iter().flat_map(|reference| {
+		let start = reference.span.range().start;
+		elements(context, reference).into_iter().filter(move |(other, span)|
+			other == path && span.range().start == start)
+	}).map(|(_, span)| span).collect();
+
+	unqualified.iter().find_map(|span| context.functions.iter().flat_map(|entry|
+		entry.value().clone()).find_map(|function| {
+		let root = &function.value[function.value.root].span;
+		let (range, body) = (span.range(), root.range());
+		if span.file() != root.file() || range.start < body.start
+			|| range.end > body.end { return None; }
+
+		let parameters = function.parameters.iter().filter_map(|parameter| match &parameter.node {
+			Parameter::Variable(variable, _) => Some(variable),
+			Parameter::Register(_) => None,
+		});
+
+		let variables = function.value.values.iter().filter_map(|node| match &node.node {
+			ValueNode::Let(variable, _, _) => Some(variable),
+			_ => None,
+		}).filter(|variable| variable.span.range().start < range.start);
+		parameters.chain(variables).find(|variable| &variable.node.0 == identifier)
+			.map(|variable| variable.span.clone())
+	}))
+}
+
+/// Returns the spans of every identifier that names a target
+/// and whether the identifier is a shorthand field.
+fn occurrences(context: &Context, target: &Target) -> Vec<(Span, bool)> {
+	let mut occurrences = Vec::new();
+	match target {
+		Target::Symbol(path) => {
+			let definition = context.definitions.read().get(path).cloned();
+			occurrences.extend(definition.map(|span| (span, false)));
+			let references = context.references.read().clone();
+			occurrences.extend(references.iter().flat_map(|reference|
+				elements(context, reference)).filter(|(other, _)| other == path)
+				.map(|(_, span)| (span, false)));
+		}
+		Target::Field(path, field) => {
+			let structure = context.structures.get(path);
+			occurrences.extend(structure.and_then(|structure| structure
+				.identifiers.get(field).cloned()).map(|span| (span, false)));
+			for (value, types) in values(context) {
+				occurrences.extend(fields(&value, types.as_deref())
+					.filter(|(structure, other, _, _)| structure == path && other == field)
+					.map(|(_, _, span, shorthand)| (span, shorthand)));
+			}
+		}
+	}
+
+	occurrences.sort_by_key(|(span, _)| (span.file(), span.range().start));
+	occurrences.dedup_by(|(span, _), (other, _)| span == other);
+	occurrences
+}
+
+/// Returns the identifiers of a path written in a source file paired
+/// with the symbol each identifier names. The last identifier names
+/// the symbol the path resolves to and each preceding identifier names
+/// the module that contains it. Identifiers from an alias are omitted.
+fn elements(context: &Context, reference: &Reference) -> Vec<(Path, Span)> {
+	let file = reference.span.file();
+	let text = context.files.read().source(file).clone();
+	let range = reference.span.range();
+
+	let mut start = range.start;
+	let mut written = Vec::new();
+	for element in text[range].split('.') {
+		let identifier = element.trim();
+		let offset = start + element.len() - element.trim_start().len();
+		if identifier != "*" {
+			let span = Span::new(offset..offset + identifier.len(), file);
+			written.push((identifier, span));
+		}
+		start += element.len() + 1;
+	}
+
+	let Path(path) = &reference.path;
+	written.into_iter().rev().zip((1..=path.len()).rev())
+		.take_while(|((identifier, _), end)| path[end - 1].0 == *identifier)
+		.map(|((_, span), end)| (Path(path[..end].to_vec()), span)).collect()
+}
+
+/// Returns the fields named in a value with the structure that contains
+/// them, the span of their identifier and whether the field is shorthand.
+fn fields<'a>(value: &'a Value, types: Option<&'a Types>)
+			  -> impl Iterator<Item=(Path, Identifier, Span, bool)> + 'a {
+	value.values.iter().flat_map(move |node| match &node.node {
+		ValueNode::Create(path, fields) => fields.iter().map(|(field, (other, span))|
+			(path.node.clone(), field.clone(), span.clone(), &value[*other].span == span))
+			.collect(),
+		ValueNode::Field(other, field) => structure(value, types, *other).map(|path|
+			(path.clone(), field.node.clone(), field.span.clone(), false)).into_iter().collect(),
+		_ => Vec::new(),
+	})
+}
+
+/// Returns the structure of a value or the structure passed to an intrinsic.
+fn structure<'a>(value: &'a Value, types: Option<&'a Types>,
+				 index: ValueIndex) -> Option<&'a Path> {
+	match &value[index].node {
+		ValueNode::Type(Type::Structure(path)) => Some(path),
+		_ => match types?.types.get(&index)? {
+			Type::Structure(path) => Some(path),
+			_ => None,
+		},
+	}
+}
+
+/// Returns the functions that are defined in source files.
+fn functions(context: &Context) -> Vec<FunctionPath> {
+	context.functions.iter().flat_map(|entry| (0..entry.value().len())
+		.map(|kind| FunctionPath(entry.key().clone(), kind)).collect::<Vec<_>>())
+		.filter(|path| !context.imports.contains_key(&Symbol::Function(path.clone())))
+		.collect()
+}
+
+/// Returns the values of every function and static variable
+/// with the inferred types of the values within functions.
+fn values(context: &Context) -> Vec<(Value, Option<Arc<Types>>)> {
+	let mut values: Vec<_> = functions(context).into_iter().filter_map(|path| {
+		let function = super::function(context, None, &path, None).ok()?;
+		let types = crate::inference::type_function(context, None, &path, None).ok();
+		Some((function.value.clone(), types))
+	}).collect();
+
+	values.extend(context.statics.iter().filter_map(|variable|
+		variable.value.clone().map(|value| (value, None))));
+	values
+}
@@ -0,0 +1,149 @@
+use crate::context::Context;
+use crate::error::Diagnostic;
+use crate::query::Key;
+use crate::span::S;
+
+use super::{Identifier, Item, Region, Symbol, Value, ValueNode};
+use super::address::{self, Address};
+
+/// Derives the load address of a symbol annotated with `@region`.
+/// Symbols are placed into a region in order of declaration.
+pub fn place(context: &Context, parent: Option<Key>, symbol: &Symbol,
+			 annotation: &S<Value>) -> crate::Result<Address> {
+	let identifier = name(context, annotation)?;
+	let region = context.regions.get(&identifier).ok_or_else(|| context.error(
+		Diagnostic::error().message(format!("undefined region: {}", identifier))
+			.label(annotation.span.label())))?;
+
+	let span = Some(annotation.span.clone());
+	let members = members(context, &identifier);
+	let index = members.iter().position(|other| other == symbol).unwrap();
+	for other in members[..index].iter().rev() {
+		if present(context, parent.clone(), other)? {
+			let base = address::load(context, parent.clone(), other, span.clone())?;
+			let size = address::size(context, parent.clone(), other, span.clone())?;
+			return address::align(context, parent, other, symbol, base + size, span);
+		}
+	}
+
+	evaluate(context, parent, &region.start)
+}
+
+/// Verifies the symbols placed into each region do
+/// not exceed its bounds or required attributes.
+pub fn regions(context: &Context) -> crate::Result<()> {
+	context.regions.iter().map(|region| self::region(context, region.value()))
+		.filter(Result::is_err).last().unwrap_or(Ok(()))
+}
+
+fn region(context: &Context, region: &Region) -> crate::Result<()> {
+	let start = evaluate(context, None, &region.start)?;
+	let size = evaluate(context, None, &region.size)?;
+	let mut usage = Vec::new();
+	for symbol in members(context, &region.identifier.node) {
+		if !present(context, None, &symbol)? { continue; }
+		let load = address::load(context, None, &symbol, None)?;
+		let length = address::size(context, None, &symbol, None)?;
+		usage.push((symbol, load, length));
+	}
+
+	let mut result = attributes(context, region);
+	let end = usage.iter().map(|(_, load, length)| load + length).max();
+	if let Some(end) = end.filter(|end| *end > start + size) {
+		let diagnostic = Diagnostic::error().label(region.identifier.span.label())
+			.message(format!("region: {}, overflows by {} bytes",
+				region.identifier.node, end - (start + size)))
+			.note(format!("region size: {} bytes", size));
+		result = context.pass(usage.into_iter().fold(diagnostic, |diagnostic, (symbol, load, length)|
+			diagnostic.note(format!("{}: {} bytes at {:#x}", symbol, length, load))));
+	}
+	result
+}
+
+/// Verifies every symbol within a region is permitted by its
+/// attributes. Functions must be executable, statics must be
+/// readable and statics that are not constant must be writable.
+fn attributes(context: &Context, region: &Region) -> crate::Result<()> {
+	let mut result = Ok(());
+	let attributes = &region.attributes.node;
+	for item in context.items.read().iter() {
+		let (symbol, required) = match item {
+			Item::Symbol(symbol @ Symbol::Function(path)) => {
+				if !super::present(context, None, path, None)? { continue; }
+				(symbol, "x")
+			}
+			Item::Symbol(symbol @ Symbol::Variable(path)) =>
+				match context.statics.get(path).unwrap().constant(context)? {
+					true => (symbol, "r"),
+					false => (symbol, "rw"),
+				},
+			_ => continue,
+		};
+
+		if enclosing(context, symbol).as_ref() != Some(&region.identifier.node) { continue; }
+		if let Some(attribute) = required.chars().find(|attribute| !attributes.contains(*attribute)) {
+			let kind = match symbol {
+				Symbol::Function(_) => "function",
+				_ => "static",
+			};
+
+			let attribute = match attribute {
+				'x' => "executable",
+				'r' => "readable",
+				_ => "writable",
+			};
+
+			let span = address::span(context, symbol);
+			result = context.pass(Diagnostic::error()
+				.message(format!("{} placed in non {} region", kind, attribute))
+				.label(span.label()).label(region.identifier.span.other()));
+		}
+	}
+	result
+}
+
+/// Returns the symbols annotated with a
+/// region in order of declaration.
+fn members(context: &Context, identifier: &Identifier) -> Vec<Symbol> {
+	let targets = &[Identifier("region".to_string())];
+	context.items.read().iter().filter_map(|item| match item {
+		Item::Symbol(symbol) => Some(symbol),
+		Item::Import(_) | Item::ModuleEnd => None,
+	}).filter(|symbol| address::annotation(context, symbol, targets)
+		.map(|annotation| name(context, &annotation).ok().as_ref() == Some(identifier))
+		.unwrap_or(false)).cloned().collect()
+}
+
+/// Returns the region a symbol is placed into
+/// including regions of enclosing modules.
+fn enclosing(context: &Context, symbol: &Symbol) -> Option<Identifier> {
+	let targets = &[Identifier("region".to_string())];
+	match address::annotation(context, symbol, targets) {
+		Some(annotation) => name(context, &annotation).ok(),
+		None => {
+			let parent = context.positions.read()[symbol].parent.clone();
+			parent.and_then(|path| enclosing(context, &Symbol::Module(path)))
+		}
+	}
+}
+
+fn name(context: &Context, annotation: &S<Value>) -> crate::Result<Identifier> {
+	let S { node: value, span } = annotation;
+	match &value[value.root].node {
+		ValueNode::String(name) => Ok(Identifier(name.clone())),
+		_ => context.pass(Diagnostic::error()
+			.message("region name must be a string").label(span.label())),
+	}
+}
+
+fn present(context: &Context, parent: Option<Key>, symbol: &Symbol) -> crate::Result<bool> {
+	match symbol {
+		Symbol::Function(path) => super::present(context, parent, path, None),
+		_ => Ok(true),
+	}
+}
+
+fn evaluate(context: &Context, parent: Option<Key>, value: &Value) -> crate::Result<Address> {
+	let span = value[value.root].span.clone();
+	address::evaluate(context, parent, &S::new(value.clone(), span))
+}
@@ -0,0 +1,124 @@
+use std::path::PathBuf;
+
+#[derive(Debug)]
+pub enum Emit {
+	Binary,
+	/// Writes the C header to the path.
+	Header(PathBuf),
+	/// Writes the query graph to the path.
+	Graph(PathBuf),
+}
+
+/// The format in which diagnostics are written.
+#[derive(Debug)]
+pub enum Diagnostics {
+	/// Coloured text with the source of each label.
+	Human,
+	/// A JSON object for each diagnostic on its own line.
+	Json,
+}
+
+/// Formats source files with `fmt` instead of compiling.
+#[derive(Debug)]
+pub struct Format {
+	pub paths: Vec<PathBuf>,
+	/// Reports files that are not formatted without modifying them.
+	pub check: bool,
+}
+
+#[derive(Debug)]
+pub struct Options {
+	pub path: PathBuf,
+	pub emit: Emit,
+	/// Removes the cache before compilation.
+	pub clean: bool,
+	/// Compiles again whenever a source file is modified.
+	pub watch: bool,
+	/// The number of threads used to type check and lower
+	/// functions. Zero uses a thread for each processor.
+	pub threads: usize,
+	/// Reports the time spent computing each query.
+	pub timings: bool,
+	/// Reports the number of queries loaded from the cache.
+	pub statistics: bool,
+	/// Serves the language server protocol instead of compiling.
+	pub server: bool,
+	pub format: Option<Format>,
+	pub diagnostics: Diagnostics,
+}
+
+/// Parses options from the command line arguments.
+pub fn options() -> Result<Options, String> {
+	let mut arguments = std::env::args().skip(1).peekable();
+	if arguments.peek().map(String::as_str) == Some("fmt") {
+		arguments.next();
+		return format(arguments);
+	}
+
+	let (mut path, mut emit) = (None, Emit::Binary);
+	let (mut clean, mut watch, mut threads) = (false, false, 0);
+	let (mut timings, mut statistics, mut server) = (false, false, false);
+	let mut diagnostics = Diagnostics::Human;
+	while let Some(argument) = arguments.next() {
+		match argument.as_str() {
+			"--diagnostics" => diagnostics = self::diagnostics(arguments.next())?,
+			"--emit" => emit = match arguments.next().as_deref() {
+				Some("binary") => Emit::Binary,
+				Some("c-header") => Emit::Header(PathBuf::from("binary.h")),
+				Some(other) if other.starts_with("c-header=") =>
+					Emit::Header(PathBuf::from(&other["c-header=".len()..])),
+				Some("query-graph") => Emit::Graph(PathBuf::from("queries.dot")),
+				Some(other) if other.starts_with("query-graph=") =>
+					Emit::Graph(PathBuf::from(&other["query-graph=".len()..])),
+				Some(other) => return Err(format!("invalid emit kind: {}", other)),
+				None => return Err("expected emit kind: binary, c-header, query-graph".to_string()),
+			},
+			"--clean" => clean = true,
+			"--watch" => watch = true,
+			"--timings" => timings = true,
+			"--cache-statistics" => statistics = true,
+			"--language-server" => server = true,
+			"--threads" => threads = match arguments.next().map(|count| count.parse()) {
+				Some(Ok(count)) => count,
+				Some(Err(_)) | None => return Err("expected thread count".to_string()),
+			},
+			other if other.starts_with("--") =>
+				return Err(format!("invalid option: {}", other)),
+			_ if path.is_some() => return Err("multiple source files".to_string()),
+			_ => path = Some(PathBuf::from(argument)),
+		}
+	}
+
+	let path = path.ok_or_else(|| "expected source file".to_string())?;
+	Ok(Options { path, emit, clean, watch, threads,
+		timings, statistics, server, format: None, diagnostics })
+}
+
+/// Parses the arguments of the `fmt` command.
+fn format(mut arguments: impl Iterator<Item=String>) -> Result<Options, String> {
+	let (mut paths, mut check) = (Vec::new(), false);
+	let mut diagnostics = Diagnostics::Human;
+	while let Some(argument) = arguments.next() {
+		match argument.as_str() {
+			"--check" => check = true,
+			"--diagnostics" => diagnostics = self::diagnostics(arguments.next())?,
+			other if other.starts_with("--") =>
+				return Err(format!("invalid option: {}", other)),
+			_ => paths.push(PathBuf::from(argument)),
+		}
+	}
+
+	let path = paths.first().cloned().ok_or_else(|| "expected source files".to_string())?;
+	let format = Some(Format { paths, check });
+	Ok(Options { path, emit: Emit::Binary, clean: false, watch: false,
+		threads: 0, timings: false, statistics: false, server: false, format, diagnostics })
+}
+
+fn diagnostics(argument: Option<String>) -> Result<Diagnostics, String> {
+	match argument.as_deref() {
+		Some("human") => Ok(Diagnostics::Human),
+		Some("json") => Ok(Diagnostics::Json),
+		Some(other) => Err(format!("invalid diagnostics format: {}", other)),
+		None => Err("expected diagnostics format: human, json".to_string()),
+	}
+}
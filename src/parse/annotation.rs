@@ -0,0 +1,70 @@
+use tree_sitter::Node;
+
+use crate::context::Context;
+use crate::error::Diagnostic;
+use crate::node::{Identifier, Path, Region};
+use crate::span::S;
+
+use super::{Source, Symbols};
+
+pub fn global_annotation(context: &Context, symbols: &Symbols,
+						 source: &Source, node: Node) -> crate::Result<()> {
+	let name = super::identifier(source, node.child_by_field_name("name").unwrap());
+	let value = node.child_by_field_name("value").unwrap();
+	if name.node == Identifier("region".to_string()) {
+		return region(context, symbols, source, value);
+	} else if name.node == Identifier("assert".to_string()) {
+		let value = super::value(context, symbols, source, value)?;
+		context.assertions.write().push(S::new(value, name.span));
+		return Ok(());
+	}
+
+	if let Some(other) = context.annotations.get(&name.node) {
+		return context.pass(Diagnostic::error().message("duplicate global annotation")
+			.label(name.span.label()).label(other.span.other()));
+	}
+
+	let value = super::value(context, symbols, source, value)?;
+	let value = S::new(value, name.span);
+	Ok(context.annotations.insert(name.node, value).unwrap_none())
+}
+
+/// Parses a region declaration of the form:
+/// `@@region name(start, size, "attributes")`.
+fn region(context: &Context, symbols: &Symbols,
+		  source: &Source, node: Node) -> crate::Result<()> {
+	let cursor = &mut node.walk();
+	let span = S::create((), node.byte_range(), source.file).span;
+	let arguments: Vec<_> = node.children_by_field_name("argument", cursor).collect();
+	let (path, start, size, attributes) = match (node.kind(), arguments.as_slice()) {
+		("call", [start, size, attributes]) if attributes.kind() == "string" =>
+			(super::path(source, node.child_by_field_name("function").unwrap()),
+				start, size, attributes),
+		_ => return context.pass(Diagnostic::error()
+			.message("invalid region declaration").label(span.label())
+			.note("expected: @@region name(start, size, \"attributes\")")),
+	};
+
+	let identifier = match path.node {
+		Path(mut elements) if elements.len() == 1 => S::new(elements.remove(0), path.span),
+		_ => return context.pass(Diagnostic::error()
+			.message("region name must be an identifier").label(path.span.label())),
+	};
+
+	let attributes = super::string(context, source, *attributes)?;
+	if let Some(other) = attributes.node.chars().find(|other| !"rwx".contains(*other)) {
+		return context.pass(Diagnostic::error().label(attributes.span.label())
+			.message(format!("invalid region attribute: {}", other))
+			.note("valid attributes are: r, w, x"));
+	}
+
+	let start = super::value(context, symbols, source, *start)?;
+	let size = super::value(context, symbols, source, *size)?;
+	if let Some(other) = context.regions.get(&identifier.node) {
+		return context.pass(Diagnostic::error().message("duplicate region")
+			.label(identifier.span.label()).label(other.identifier.span.other()));
+	}
+
+	let region = Region { identifier: identifier.clone(), start, size, attributes };
+	Ok(context.regions.insert(identifier.node, region).unwrap_none())
+}
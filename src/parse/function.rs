@@ -52,7 +52,7 @@ fn parameters(scene: &mut Scene, node: Node) -> crate::Result<Vec<S<Parameter>>>
 					}
 				}
 			}
-			other => panic!("invalid parameter type: {}", other),
+			_ => return super::unexpected(scene.context, scene.source, node, "parameter"),
 		}, node.byte_range(), scene.source.file))).collect()
 }
 
@@ -91,7 +91,7 @@ pub fn node_type(context: &Context, symbols: &Symbols,
 			Type::Slice(Box::new(element))
 		}
 		"path" => return path_type(context, source, symbols, node),
-		other => panic!("invalid type kind: {}", other),
+		_ => return super::unexpected(context, source, node, "type"),
 	}, node.byte_range(), source.file))
 }
 
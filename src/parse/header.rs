@@ -0,0 +1,693 @@
+//! Reads the subset of C declarations that can be imported:
+//! function prototypes, structures, enumerations, type
+//! definitions and integer constant definitions.
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use codespan::FileId;
+
+use crate::node::*;
+use crate::span::{S, Span};
+
+pub type Fields = Vec<(S<Identifier>, S<Type>)>;
+
+/// Declarations read from a C header.
+#[derive(Debug, Default)]
+pub struct Header {
+	pub structures: Vec<(S<Identifier>, Fields)>,
+	pub functions: Vec<(S<Identifier>, Vec<S<Type>>, S<Type>)>,
+	pub variables: Vec<(S<Identifier>, S<Type>)>,
+	pub constants: Vec<(S<Identifier>, Type, i128)>,
+	/// Declarations that cannot be imported.
+	pub unsupported: Vec<S<&'static str>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+	Identifier(String),
+	Integral(i128),
+	String,
+	Symbol(&'static str),
+}
+
+/// A structure defined within a type specifier.
+struct Definition {
+	tag: Option<String>,
+	fields: Fields,
+}
+
+struct Parser<'a> {
+	tokens: Vec<(Token, Range<usize>)>,
+	index: usize,
+	file: FileId,
+	namespace: &'a Path,
+	typedefs: HashMap<String, Type>,
+	structures: HashMap<String, Fields>,
+	constants: HashMap<String, i128>,
+	reason: Option<&'static str>,
+	header: Header,
+}
+
+const SYMBOLS: &[&str] = &["#", "...", "<<", ">>", "{", "}", "(", ")", "[", "]", ";", ",",
+	"*", "=", "<", ">", "|", "&", "^", "~", "!", "+", "-", "/", "%", "?", ":", "."];
+const QUALIFIERS: &[&str] = &["const", "extern", "volatile", "restrict", "static",
+	"inline", "register", "__extension__", "__restrict"];
+
+/// Parses the declarations in a header. Structures
+/// are named within the provided namespace.
+pub fn declarations(text: &str, file: FileId, namespace: &Path) -> Header {
+	let text = comments(text);
+	let mut code = text.clone().into_bytes();
+	let mut parser = Parser {
+		tokens: Vec::new(),
+		index: 0,
+		file,
+		namespace,
+		typedefs: HashMap::new(),
+		structures: HashMap::new(),
+		constants: HashMap::new(),
+		reason: None,
+		header: Header::default(),
+	};
+
+	let mut offset = 0;
+	let mut lines = text.split_inclusive('\n');
+	while let Some(line) = lines.next() {
+		let (start, mut end) = (offset, offset + line.len());
+		let mut directive = line.to_string();
+		if line.trim_start().starts_with('#') {
+			while directive.trim_end().ends_with('\\') {
+				match lines.next() {
+					Some(line) => {
+						directive.push_str(line);
+						end += line.len();
+					}
+					None => break,
+				}
+			}
+
+			parser.directive(&directive.replace("\\\n", "  "), start);
+			code[start..end].iter_mut().filter(|byte| **byte != b'\n')
+				.for_each(|byte| *byte = b' ');
+		}
+		offset = end;
+	}
+
+	let code = String::from_utf8(code).unwrap();
+	parser.tokens = tokens(&code, 0);
+	parser.declarations();
+	parser.header
+}
+
+/// Replaces comments with spaces so that
+/// byte offsets are preserved.
+fn comments(text: &str) -> String {
+	let mut bytes = text.as_bytes().to_vec();
+	let mut index = 0;
+	while index < bytes.len() {
+		match (bytes[index], bytes.get(index + 1)) {
+			(b'"', _) | (b'\'', _) => {
+				let quote = bytes[index];
+				index += 1;
+				while index < bytes.len() && bytes[index] != quote && bytes[index] != b'\n' {
+					index += if bytes[index] == b'\\' { 2 } else { 1 };
+				}
+			}
+			(b'/', Some(b'/')) => while index < bytes.len() && bytes[index] != b'\n' {
+				bytes[index] = b' ';
+				index += 1;
+			},
+			(b'/', Some(b'*')) => {
+				let end = text[index + 2..].find("*/")
+					.map(|end| index + end + 4).unwrap_or(bytes.len());
+				bytes[index..end].iter_mut().filter(|byte| **byte != b'\n')
+					.for_each(|byte| *byte = b' ');
+				index = end;
+				continue;
+			}
+			_ => (),
+		}
+		index += 1;
+	}
+	String::from_utf8(bytes).unwrap()
+}
+
+fn tokens(text: &str, base: usize) -> Vec<(Token, Range<usize>)> {
+	let bytes = text.as_bytes();
+	let (mut tokens, mut index) = (Vec::new(), 0);
+	while index < bytes.len() {
+		let start = index;
+		let byte = bytes[index];
+		let token = match byte {
+			_ if byte.is_ascii_whitespace() => {
+				index += 1;
+				continue;
+			}
+			b'a'..=b'z' | b'A'..=b'Z' | b'_' => {
+				while index < bytes.len() && (bytes[index].is_ascii_alphanumeric()
+					|| bytes[index] == b'_') { index += 1; }
+				Token::Identifier(text[start..index].to_string())
+			}
+			b'0'..=b'9' => {
+				while index < bytes.len() && (bytes[index].is_ascii_alphanumeric()
+					|| bytes[index] == b'.') { index += 1; }
+				integral(&text[start..index]).map(Token::Integral)
+					.unwrap_or(Token::Symbol("?"))
+			}
+			b'"' | b'\'' => {
+				index += 1;
+				while index < bytes.len() && bytes[index] != byte {
+					index += match bytes[index] {
+						b'\\' if index + 1 < bytes.len() => 2,
+						_ => 1,
+					};
+				}
+
+				// An unterminated literal ends with the text.
+				let end = index;
+				index = (index + 1).min(bytes.len());
+				let string = &text[start + 1..end];
+				match (byte, string.as_bytes()) {
+					(b'\'', [character]) => Token::Integral(*character as i128),
+					(b'\'', _) => Token::Symbol("?"),
+					_ => Token::String,
+				}
+			}
+			_ => match SYMBOLS.iter().find(|symbol| text[index..].starts_with(**symbol)) {
+				Some(symbol) => {
+					index += symbol.len();
+					Token::Symbol(symbol)
+				}
+				None => {
+					index += text[index..].chars().next().unwrap().len_utf8();
+					Token::Symbol("?")
+				}
+			}
+		};
+		tokens.push((token, base + start..base + index));
+	}
+	tokens
+}
+
+fn integral(string: &str) -> Option<i128> {
+	let string = string.trim_end_matches(|character| matches!(character, 'u' | 'U' | 'l' | 'L'));
+	match string.get(..2) {
+		Some("0x") | Some("0X") => i128::from_str_radix(&string[2..], 16).ok(),
+		Some("0b") | Some("0B") => i128::from_str_radix(&string[2..], 2).ok(),
+		_ if string.len() > 1 && string.starts_with('0') =>
+			i128::from_str_radix(&string[1..], 8).ok(),
+		_ => string.parse().ok(),
+	}
+}
+
+impl<'a> Parser<'a> {
+	fn directive(&mut self, directive: &str, offset: usize) {
+		let tokens = tokens(directive, offset);
+		let span = tokens.first().map(|(_, range)| range.start)
+			.zip(tokens.last().map(|(_, range)| range.end))
+			.map(|(start, end)| start..end).unwrap_or(offset..offset);
+		match tokens.as_slice() {
+			[(Token::Symbol("#"), _), ..] => (),
+			_ => return,
+		}
+
+		match tokens.get(1) {
+			Some((Token::Identifier(word), _)) if word == "define" => (),
+			_ => return,
+		}
+
+		let (name, range) = match tokens.get(2) {
+			Some((Token::Identifier(name), range)) => (name.clone(), range.clone()),
+			_ => return,
+		};
+
+		let function = matches!(tokens.get(3), Some((Token::Symbol("("), other))
+			if other.start == range.end);
+		if function {
+			return self.unsupported(span, "function like macros are unsupported");
+		} else if tokens.len() == 3 {
+			return;
+		}
+
+		let (tokens, index) = (std::mem::replace(&mut self.tokens, tokens), self.index);
+		self.index = 3;
+		let value = self.expression(0).filter(|_| self.done());
+		self.tokens = tokens;
+		self.index = index;
+
+		match value {
+			None => self.unsupported(span, "macro is not an integer constant"),
+			Some(value) => {
+				let identifier = S::create(Identifier(name.clone()), range, self.file);
+				let node_type = match value > i64::MAX as i128 {
+					true => Type::Unsigned(Size::Quad),
+					false => Type::Signed(Size::Quad),
+				};
+
+				self.constants.insert(name, value);
+				self.header.constants.push((identifier, node_type, value));
+			}
+		}
+	}
+
+	fn declarations(&mut self) {
+		while !self.done() {
+			let start = self.index;
+			if self.word("extern") && matches!(self.peek(), Some(Token::String)) {
+				self.index += 1;
+				self.symbol("{");
+				continue;
+			}
+
+			self.index = start;
+			if self.symbol("}") || self.symbol(";") {
+				continue;
+			}
+
+			self.reason = None;
+			if self.declaration().is_none() {
+				let reason = self.reason.take().unwrap_or("unsupported declaration");
+				self.index = start;
+				self.recover(reason);
+			}
+		}
+	}
+
+	fn declaration(&mut self) -> Option<()> {
+		let start = self.index;
+		let typedef = self.word("typedef");
+		self.qualifiers();
+		let (base, definition) = self.specifier()?;
+		if self.symbol(";") {
+			return match definition {
+				Some(Definition { tag: Some(tag), fields }) =>
+					Some(self.structure(tag, fields, start)),
+				Some(_) => self.fail("anonymous structures are unsupported"),
+				None => Some(()),
+			};
+		}
+
+		let mut definition = definition;
+		loop {
+			let (name, node_type, parameters) = self.declarator(base.clone())?;
+			if let Some(Definition { tag, fields }) = definition.take() {
+				let tag = match (tag, typedef) {
+					(Some(tag), _) => tag,
+					(None, true) => name.node.0.clone(),
+					(None, false) => return self.fail("anonymous structures are unsupported"),
+				};
+				self.structure(tag, fields, start);
+			}
+
+			let node_type = S::new(node_type, name.span.clone());
+			match (typedef, parameters) {
+				(true, Some(_)) => return self.fail("function type definitions are unsupported"),
+				(true, None) => {
+					let Identifier(name) = &name.node;
+					let target = match &node_type.node {
+						Type::Structure(Path(elements)) => elements.last()
+							.and_then(|Identifier(tag)| self.structures.get(tag).cloned()),
+						_ => None,
+					};
+
+					match target {
+						Some(fields) if !self.structures.contains_key(name) =>
+							self.structure(name.clone(), fields, start),
+						_ => std::mem::drop(self.typedefs.insert(name.clone(), node_type.node)),
+					}
+				}
+				(false, Some(parameters)) =>
+					self.header.functions.push((name, parameters, node_type)),
+				(false, None) => self.header.variables.push((name, node_type)),
+			}
+
+			if !self.symbol(",") {
+				break;
+			}
+		}
+
+		self.expect(";")
+	}
+
+	fn structure(&mut self, tag: String, fields: Fields, start: usize) {
+		let range = self.tokens[start].1.clone();
+		let identifier = S::create(Identifier(tag.clone()), range, self.file);
+		let node_type = Type::Structure(self.namespace.push(Identifier(tag.clone())));
+		self.typedefs.insert(tag.clone(), node_type);
+		self.structures.insert(tag, fields.clone());
+		self.header.structures.push((identifier, fields));
+	}
+
+	fn specifier(&mut self) -> Option<(Type, Option<Definition>)> {
+		let start = self.index;
+		if self.word("union") {
+			return self.fail("unions are unsupported");
+		} else if self.word("struct") {
+			let tag = self.identifier().map(|identifier| identifier.node.0);
+			let path = tag.as_ref().map(|tag| self.namespace.push(Identifier(tag.clone())));
+			self.qualifiers();
+			if !self.symbol("{") {
+				return Some((Type::Structure(path.or_else(|| {
+					self.index = start;
+					None
+				})?), None));
+			}
+
+			let fields = self.fields()?;
+			let path = path.unwrap_or_default();
+			return Some((Type::Structure(path), Some(Definition { tag, fields })));
+		} else if self.word("enum") {
+			self.identifier();
+			if self.symbol("{") {
+				self.enumerators()?;
+			}
+
+			self.qualifiers();
+			return Some((Type::Signed(Size::Double), None));
+		}
+
+		if let Some(Token::Identifier(name)) = self.peek().cloned() {
+			let node_type = self.typedefs.get(&name).cloned()
+				.or_else(|| standard(&name));
+			if let Some(node_type) = node_type {
+				self.index += 1;
+				self.qualifiers();
+				return Some((node_type, None));
+			}
+		}
+
+		let (mut longs, mut unsigned, mut base) = (0, false, None);
+		loop {
+			self.qualifiers();
+			match self.peek() {
+				Some(Token::Identifier(word)) => match word.as_str() {
+					"long" => longs += 1,
+					"unsigned" => unsigned = true,
+					"signed" => (),
+					"float" | "double" => return self.fail("floating point types are unsupported"),
+					"void" | "_Bool" | "bool" | "char" | "short" | "int" if base.is_none() =>
+						base = Some(word.clone()),
+					_ => break,
+				},
+				_ => break,
+			}
+			self.index += 1;
+		}
+
+		if self.index == start {
+			return self.fail("unknown type");
+		}
+
+		let size = match (base.as_deref(), longs) {
+			(Some("void"), _) => return Some((Type::Void, None)),
+			(Some("_Bool"), _) | (Some("bool"), _) => return Some((Type::Truth, None)),
+			(Some("char"), _) => Size::Byte,
+			(Some("short"), _) => Size::Word,
+			(_, 0) => Size::Double,
+			(_, _) => Size::Quad,
+		};
+
+		Some((match unsigned {
+			true => Type::Unsigned(size),
+			false => Type::Signed(size),
+		}, None))
+	}
+
+	fn fields(&mut self) -> Option<Fields> {
+		let mut fields = Vec::new();
+		while !self.symbol("}") {
+			let (base, definition) = self.specifier()?;
+			if definition.is_some() {
+				return self.fail("nested structure definitions are unsupported");
+			}
+
+			loop {
+				let (name, node_type, parameters) = self.declarator(base.clone())?;
+				if parameters.is_some() {
+					return self.fail("function fields are unsupported");
+				} else if self.symbol(":") {
+					return self.fail("bit fields are unsupported");
+				}
+
+				let node_type = S::new(node_type, name.span.clone());
+				fields.push((name, node_type));
+				if !self.symbol(",") {
+					break;
+				}
+			}
+			self.expect(";")?;
+		}
+		Some(fields)
+	}
+
+	fn enumerators(&mut self) -> Option<()> {
+		let mut value = 0;
+		while !self.symbol("}") {
+			let identifier = self.identifier()?;
+			if self.symbol("=") {
+				value = self.expression(0)?;
+			}
+
+			let Identifier(name) = &identifier.node;
+			self.constants.insert(name.clone(), value);
+			let node_type = Type::Signed(Size::Double);
+			self.header.constants.push((identifier, node_type, value));
+			value += 1;
+
+			if !self.symbol(",") {
+				return self.expect("}");
+			}
+		}
+		Some(())
+	}
+
+	/// Parses a declarator and returns its name, type
+	/// and parameters if the declarator is a function.
+	fn declarator(&mut self, mut node_type: Type)
+				  -> Option<(S<Identifier>, Type, Option<Vec<S<Type>>>)> {
+		while self.symbol("*") {
+			let span = self.span(self.index - 1);
+			node_type = Type::Pointer(Box::new(S::new(node_type, span)));
+			self.qualifiers();
+		}
+
+		if self.symbol("(") {
+			return self.fail("function pointers are unsupported");
+		}
+
+		let name = self.identifier()?;
+		if self.symbol("(") {
+			return Some((name, node_type, Some(self.parameters()?)));
+		}
+
+		let node_type = self.arrays(node_type)?;
+		Some((name, node_type, None))
+	}
+
+	fn parameters(&mut self) -> Option<Vec<S<Type>>> {
+		let mut parameters = Vec::new();
+		let start = self.index;
+		if self.word("void") && self.symbol(")") {
+			return Some(parameters);
+		}
+
+		self.index = start;
+		while !self.symbol(")") {
+			if self.symbol("...") {
+				return self.fail("variadic functions are unsupported");
+			}
+
+			let start = self.index;
+			self.qualifiers();
+			let (mut node_type, definition) = self.specifier()?;
+			if definition.is_some() {
+				return self.fail("structure definitions in parameters are unsupported");
+			}
+
+			while self.symbol("*") {
+				let span = self.span(self.index - 1);
+				node_type = Type::Pointer(Box::new(S::new(node_type, span)));
+				self.qualifiers();
+			}
+
+			if self.symbol("(") {
+				return self.fail("function pointers are unsupported");
+			}
+
+			self.identifier();
+			let span = self.span(start);
+			if self.peek() == Some(&Token::Symbol("[")) {
+				self.arrays(Type::Void)?;
+				node_type = Type::Pointer(Box::new(S::new(node_type, span.clone())));
+			}
+
+			parameters.push(S::new(node_type, span));
+			if !self.symbol(",") {
+				self.expect(")")?;
+				break;
+			}
+		}
+		Some(parameters)
+	}
+
+	fn arrays(&mut self, node_type: Type) -> Option<Type> {
+		let mut lengths = Vec::new();
+		while self.symbol("[") {
+			let span = self.span(self.index - 1);
+			if self.symbol("]") {
+				return self.fail("arrays without length are unsupported");
+			}
+
+			lengths.push((self.expression(0)?, span));
+			self.expect("]")?;
+		}
+
+		Some(lengths.into_iter().rev().fold(node_type, |node_type, (length, span)| {
+			let mut value = Value::default();
+			let length = S::new(ValueNode::Integral(length), span.clone());
+			value.root = value.insert(length);
+			Type::Array(Box::new(S::new(node_type, span)), value)
+		}))
+	}
+
+	fn expression(&mut self, precedence: u8) -> Option<i128> {
+		let mut left = match self.next()? {
+			Token::Integral(integral) => integral,
+			Token::Identifier(name) => *self.constants.get(&name)?,
+			Token::Symbol("-") => self.expression(11)?.wrapping_neg(),
+			Token::Symbol("+") => self.expression(11)?,
+			Token::Symbol("~") => !self.expression(11)?,
+			Token::Symbol("!") => (self.expression(11)? == 0) as i128,
+			Token::Symbol("(") => {
+				let value = self.expression(0)?;
+				self.expect(")")?;
+				value
+			}
+			_ => return None,
+		};
+
+		loop {
+			let (operator, other) = match self.peek() {
+				Some(Token::Symbol(operator)) => match *operator {
+					"*" | "/" | "%" => (*operator, 10),
+					"+" | "-" => (*operator, 9),
+					"<<" | ">>" => (*operator, 8),
+					"&" => (*operator, 7),
+					"^" => (*operator, 6),
+					"|" => (*operator, 5),
+					_ => return Some(left),
+				},
+				_ => return Some(left),
+			};
+
+			if other <= precedence {
+				return Some(left);
+			}
+
+			self.index += 1;
+			let right = self.expression(other)?;
+			left = match operator {
+				"*" => left.wrapping_mul(right),
+				"/" => left.checked_div(right)?,
+				"%" => left.checked_rem(right)?,
+				"+" => left.wrapping_add(right),
+				"-" => left.wrapping_sub(right),
+				"<<" => left.wrapping_shl(right as u32),
+				">>" => left.wrapping_shr(right as u32),
+				"&" => left & right,
+				"^" => left ^ right,
+				_ => left | right,
+			};
+		}
+	}
+
+	fn recover(&mut self, reason: &'static str) {
+		let start = self.index;
+		let mut depth = 0;
+		while let Some(token) = self.next() {
+			match token {
+				Token::Symbol("{") | Token::Symbol("(") => depth += 1,
+				Token::Symbol("}") | Token::Symbol(")") if depth > 0 => depth -= 1,
+				Token::Symbol(";") if depth == 0 => break,
+				_ => (),
+			}
+		}
+
+		let end = self.tokens[self.index - 1].1.end;
+		self.unsupported(self.tokens[start].1.start..end, reason);
+	}
+
+	fn unsupported(&mut self, range: Range<usize>, reason: &'static str) {
+		self.header.unsupported.push(S::create(reason, range, self.file));
+	}
+
+	fn fail<T>(&mut self, reason: &'static str) -> Option<T> {
+		self.reason.get_or_insert(reason);
+		None
+	}
+
+	fn qualifiers(&mut self) {
+		while QUALIFIERS.iter().any(|qualifier| self.word(qualifier)) {}
+	}
+
+	fn identifier(&mut self) -> Option<S<Identifier>> {
+		match self.peek() {
+			Some(Token::Identifier(name)) => {
+				let identifier = Identifier(name.clone());
+				self.index += 1;
+				Some(S::new(identifier, self.span(self.index - 1)))
+			}
+			_ => None,
+		}
+	}
+
+	fn word(&mut self, word: &str) -> bool {
+		let matches = matches!(self.peek(), Some(Token::Identifier(other)) if other == word);
+		(matches, self.index += matches as usize).0
+	}
+
+	fn symbol(&mut self, symbol: &str) -> bool {
+		let matches = matches!(self.peek(), Some(Token::Symbol(other)) if *other == symbol);
+		(matches, self.index += matches as usize).0
+	}
+
+	fn expect(&mut self, symbol: &str) -> Option<()> {
+		match self.symbol(symbol) {
+			false => self.fail("unexpected token"),
+			true => Some(()),
+		}
+	}
+
+	fn next(&mut self) -> Option<Token> {
+		let token = self.peek().cloned();
+		self.index += token.is_some() as usize;
+		token
+	}
+
+	fn peek(&self) -> Option<&Token> {
+		self.tokens.get(self.index).map(|(token, _)| token)
+	}
+
+	fn done(&self) -> bool {
+		self.index >= self.tokens.len()
+	}
+
+	fn span(&self, index: usize) -> Span {
+		Span::new(self.tokens[index].1.clone(), self.file)
+	}
+}
+
+fn standard(name: &str) -> Option<Type> {
+	Some(match name {
+		"int8_t" => Type::Signed(Size::Byte),
+		"int16_t" => Type::Signed(Size::Word),
+		"int32_t" => Type::Signed(Size::Double),
+		"int64_t" | "intptr_t" | "ssize_t" | "ptrdiff_t" => Type::Signed(Size::Quad),
+		"uint8_t" => Type::Unsigned(Size::Byte),
+		"uint16_t" => Type::Unsigned(Size::Word),
+		"uint32_t" => Type::Unsigned(Size::Double),
+		"uint64_t" | "uintptr_t" | "size_t" => Type::Unsigned(Size::Quad),
+		_ => return None,
+	})
+}
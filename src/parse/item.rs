@@ -4,13 +4,13 @@ use std::sync::Arc;
 
 use codespan::FileId;
 use indexmap::IndexMap;
-use tree_sitter::{Language, Node, Parser, Query};
+use tree_sitter::{Language, Node, Parser};
 
 use crate::context::Context;
 use crate::error::Diagnostic;
 use crate::node::*;
 use crate::parse::Include;
-use crate::span::S;
+use crate::span::{S, Span};
 
 use super::{Symbols, Unit};
 
@@ -30,21 +30,19 @@ pub fn parser() -> Parser {
 	parser
 }
 
-pub fn errors() -> Query {
-	let language = unsafe { tree_sitter_lucent() };
-	Query::new(language, "(ERROR) @error").unwrap()
-}
-
 pub fn parse(context: &Context, path: &std::path::Path) -> crate::Result<()> {
 	let (mut symbols, units) = Symbols::root(context, path)?;
-	units.iter().map(|unit| match unit {
+	let result = units.iter().map(|unit| match unit {
 		Unit::Item(path, source, node) => self::item(context,
 			&mut symbols, path.clone(), source, *node),
 		Unit::ModuleEnd => {
 			context.items.write().push(Item::ModuleEnd);
 			Ok(symbols.pop())
 		}
-	}).filter(Result::is_err).last().unwrap_or(Ok(()))
+	}).filter(Result::is_err).last().unwrap_or(Ok(()));
+	*context.definitions.write() = symbols.definitions();
+	*context.inclusions.write() = symbols.inclusions();
+	result
 }
 
 pub fn item(context: &Context, symbols: &mut Symbols, path: Path,
@@ -70,12 +68,19 @@ pub fn item(context: &Context, symbols: &mut Symbols, path: Path,
 			context.items.write().push(Item::Symbol(symbol));
 			context.statics.insert(path, variable);
 		}
+		"use" if node.child_by_field_name("with").is_some() =>
+			super::header(context, symbols, source, node)?,
+		"use" if node.child_by_field_name("path").unwrap().kind() == "string" =>
+			super::library(context, symbols, path, source, node)?,
+		"use" if node.child_by_field_name("as").filter(|node|
+			node.kind() == "signature" || node.kind() == "static").is_some() =>
+			super::import(context, symbols, path, source, node)?,
 		"use" => {
 			let node_as = node.child_by_field_name("as");
 			let S { node: Path(mut elements), span } = self::path(source,
 				node.child_by_field_name("path").unwrap());
 
-			symbols.include(S::create(match (elements.last().unwrap(), node_as) {
+			let include = match (elements.last().unwrap(), node_as) {
 				(Identifier(string), Some(_)) if string == "*" => return
 					context.pass(Diagnostic::error().label(span.label())
 						.message("wildcard imports cannot be aliased")),
@@ -86,17 +91,36 @@ pub fn item(context: &Context, symbols: &mut Symbols, path: Path,
 				(_, Some(node_as)) => Include::As(Path(elements),
 					identifier(source, node_as).node),
 				(_, None) => Include::Item(Path(elements)),
-			}, node.byte_range(), source.file));
+			};
+
+			match &include {
+				Include::Wild(path) | Include::Item(path) |
+				Include::As(path, _) => symbols.reference(context, path, &span),
+			}
+			symbols.include(S::create(include, node.byte_range(), source.file));
 		}
 		"data" => {
 			let cursor = &mut node.walk();
-			let mut fields = IndexMap::new();
+			let (mut fields, mut bits) = (IndexMap::new(), HashMap::new());
+			let mut identifiers = HashMap::new();
 			for node in node.children_by_field_name("field", cursor) {
 				let identifier = field_identifier(source, node);
-				let node_type = super::node_type(context, symbols,
-					source, node.child_by_field_name("type").unwrap())?;
+				let node = node.child_by_field_name("type").unwrap();
+				let node_type = match width(source, node) {
+					None => super::node_type(context, symbols, source, node)?,
+					Some(width) => {
+						bits.insert(identifier.node.clone(), width);
+						let size = [Size::Byte, Size::Word, Size::Double, Size::Quad]
+							.iter().find(|size| **size as u8 >= width).unwrap();
+						S::create(Type::Unsigned(*size), node.byte_range(), source.file)
+					}
+				};
+
 				match fields.get(&identifier.node) {
-					None => fields.insert(identifier.node, node_type),
+					None => {
+						identifiers.insert(identifier.node.clone(), identifier.span);
+						fields.insert(identifier.node, node_type)
+					}
 					Some(other) => return context.pass(Diagnostic::error()
 						.label(other.span.label()).label(identifier.span.label())
 						.message("duplicate field")),
@@ -104,7 +128,7 @@ pub fn item(context: &Context, symbols: &mut Symbols, path: Path,
 			}
 
 			let annotations = annotations(context, symbols, source, node);
-			context.structures.insert(path, Structure { annotations, fields });
+			context.structures.insert(path, Structure { annotations, fields, bits, identifiers });
 		}
 		"module" => {
 			let symbol = Symbol::Module(path.clone());
@@ -115,12 +139,12 @@ pub fn item(context: &Context, symbols: &mut Symbols, path: Path,
 			let module = Module { annotations, identifier, first: None, last: None };
 			context.modules.insert(path.clone(), module);
 
-			symbols.push();
-			symbols.include(S::create(Include::Wild(path),
-				node.byte_range(), source.file));
+			let span = Span::new(node.byte_range(), source.file);
+			symbols.push(span.clone());
+			symbols.include(S::new(Include::Wild(path), span));
 		}
-		"annotation" => (), // TODO: implement global annotations
-		other => panic!("invalid item kind: {}", other),
+		"global_annotation" => super::global_annotation(context, symbols, source, node)?,
+		_ => return super::unexpected(context, source, node, "item"),
 	})
 }
 
@@ -136,8 +160,12 @@ pub fn annotations(context: &Context, symbols: &Symbols,
 				.label(other.span.label()).label(name.span.label()));
 		}
 
-		let value = super::value(context, symbols, source,
-			node.child_by_field_name("value").unwrap());
+		let node = node.child_by_field_name("value").unwrap();
+		let value = match name.node == Identifier("admit".to_string()) {
+			false => super::value(context, symbols, source, node),
+			true => Ok(admission(source, node)),
+		};
+
 		if let Ok(value) = value {
 			let value = S::new(value, name.span);
 			annotations.insert(name.node, value);
@@ -146,6 +174,15 @@ pub fn annotations(context: &Context, symbols: &Symbols,
 	annotations
 }
 
+/// Admissions are identifiers that are not resolved as paths.
+fn admission(source: &Source, node: Node) -> Value {
+	let mut value = Value::default();
+	let admission = source.text[node.byte_range()].to_string();
+	let admission = S::create(ValueNode::String(admission), node.byte_range(), source.file);
+	value.root = value.insert(admission);
+	value
+}
+
 pub fn path(source: &Source, node: Node) -> S<Path> {
 	let cursor = &mut node.walk();
 	S::create(Path(node.named_children(cursor)
@@ -153,6 +190,17 @@ pub fn path(source: &Source, node: Node) -> S<Path> {
 		.collect()), node.byte_range(), source.file)
 }
 
+/// Returns the width of a bit field type such as `u1` or `u40`.
+/// Unsigned types with a width of a whole integral size are not bit fields.
+fn width(source: &Source, node: Node) -> Option<u8> {
+	let text = &source.text[node.byte_range()];
+	match text.strip_prefix('u')?.parse().ok()? {
+		8 | 16 | 32 | 64 => None,
+		width @ 1..=63 if node.kind() == "path" => Some(width),
+		_ => None,
+	}
+}
+
 pub fn field_identifier(source: &Source, node: Node) -> S<Identifier> {
 	identifier(source, node.child_by_field_name("identifier").unwrap())
 }
@@ -166,8 +214,3 @@ pub fn register(source: &Source, node: Node) -> S<Identifier> {
 	let text = source.text[node.byte_range()][1..].to_string();
 	S::create(Identifier(text), node.byte_range(), source.file)
 }
-
-pub fn string(source: &Source, node: Node) -> S<String> {
-	let text = &source.text[node.start_byte() + 1..node.end_byte() - 1];
-	S::create(text.to_string(), node.byte_range(), source.file)
-}
@@ -0,0 +1,296 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use goblin::{elf, mach, Object};
+use tree_sitter::Node;
+
+use crate::context::Context;
+use crate::error::Diagnostic;
+use crate::node::*;
+use crate::node::address::{Address, SymbolSize};
+use crate::span::{S, Span};
+
+use super::{Declarations, Source, SymbolKind, Symbols};
+
+type SymbolTable = HashMap<String, (Address, SymbolSize)>;
+
+/// The symbol table, base address and whether a library is relocatable.
+pub type Binary = (SymbolTable, Address, bool);
+
+/// Declares a library with `use "path" as identifier`.
+/// The symbol table is read when a symbol is imported.
+pub fn library(context: &Context, symbols: &Symbols, path: Path,
+			   source: &Source, node: Node) -> crate::Result<()> {
+	let file = self::file(context, source, node)?;
+	let identifier = super::identifier(source, node.child_by_field_name("as").unwrap());
+	let annotations = super::annotations(context, symbols, source, node);
+	Ok(register(context, path, annotations, identifier, file))
+}
+
+/// Returns the library file named by the path of a `use` item.
+fn file(context: &Context, source: &Source, node: Node) -> crate::Result<S<PathBuf>> {
+	let string = super::string(context, source, node.child_by_field_name("path").unwrap())?;
+	Ok(S::new(source.path.join(&string.node), string.span))
+}
+
+/// Reads the symbol table of a library file.
+pub fn binary(context: &Context, file: &S<PathBuf>) -> crate::Result<Binary> {
+	let S { node: file, span } = file;
+	let bytes = context.files.write().bytes(file).map_err(|error|
+		context.error(Diagnostic::error().label(span.label())
+			.message(format!("failed to read library: {}", file.display()))
+			.note(format!("error: {}", error))))?;
+
+	let error = |error: goblin::error::Error| context.error(Diagnostic::error()
+		.message(format!("failed to parse library: {}", file.display()))
+		.label(span.label()).note(format!("error: {}", error)));
+	Ok(match Object::parse(&bytes).map_err(error)? {
+		Object::Elf(object) => self::elf(&object),
+		Object::Mach(mach::Mach::Binary(object)) => self::mach(&object).map_err(error)?,
+		_ => return context.pass(Diagnostic::error()
+			.message("unsupported library format")
+			.note("only ELF and Mach-O libraries are supported")
+			.label(span.label())),
+	})
+}
+
+fn register(context: &Context, path: Path, annotations: Annotations,
+			identifier: S<Identifier>, file: S<PathBuf>) {
+	let library = Library { annotations, identifier, path: file };
+	context.libraries.insert(path, library);
+}
+
+fn elf(object: &elf::Elf) -> (SymbolTable, Address, bool) {
+	let mut symbols = HashMap::new();
+	let tables = [(&object.syms, &object.strtab), (&object.dynsyms, &object.dynstrtab)];
+	for (table, strings) in tables.iter() {
+		for symbol in table.iter().filter(|symbol| symbol.st_shndx != 0) {
+			if let Some(Ok(name)) = strings.get(symbol.st_name) {
+				let value = (symbol.st_value as Address, symbol.st_size as SymbolSize);
+				symbols.entry(name.to_string()).or_insert(value);
+			}
+		}
+	}
+
+	let base = object.program_headers.iter()
+		.filter(|header| header.p_type == elf::program_header::PT_LOAD)
+		.map(|header| header.p_vaddr as Address).min().unwrap_or_default();
+	(symbols, base, object.header.e_type == elf::header::ET_DYN)
+}
+
+fn mach(object: &mach::MachO) -> goblin::error::Result<(SymbolTable, Address, bool)> {
+	let mut symbols = HashMap::new();
+	for symbol in object.symbols() {
+		let (name, symbol) = symbol?;
+		if !symbol.is_undefined() && !name.is_empty() {
+			// Symbols from C are prefixed with an underscore.
+			let name = name.strip_prefix('_').unwrap_or(name);
+			symbols.entry(name.to_string()).or_insert((symbol.n_value as Address, 0));
+		}
+	}
+
+	let base = object.segments.iter().filter(|segment| segment.filesize > 0)
+		.map(|segment| segment.vmaddr as Address).min().unwrap_or_default();
+	let relocatable = object.header.filetype == mach::header::MH_DYLIB ||
+		object.header.flags & mach::header::MH_PIE != 0;
+	Ok((symbols, base, relocatable))
+}
+
+/// Imports a function or static variable from a library with
+/// `use library.symbol as fn function(type.0, ...)` or
+/// `use library.symbol as variable: type`.
+pub fn import(context: &Context, symbols: &Symbols, path: Path,
+			  source: &Source, node: Node) -> crate::Result<()> {
+	let node_as = node.child_by_field_name("as").unwrap();
+	let S { node: Path(mut elements), span } =
+		super::path(source, node.child_by_field_name("path").unwrap());
+	let Identifier(element) = elements.pop().unwrap();
+	let library = match elements.is_empty() {
+		false => symbols.resolve(context, &Path(elements), &span),
+		true => None,
+	};
+
+	let library = match library {
+		Some((path, SymbolKind::Library)) => path,
+		_ => return context.pass(Diagnostic::error().label(span.label())
+			.message("imported symbol must be within a library")),
+	};
+
+	let symbol = match integral(&element) {
+		Some(address) => ImportSymbol::Address(address),
+		None => ImportSymbol::Name(element),
+	};
+
+	let annotations = super::annotations(context, symbols, source, node);
+	let import = Import { library, symbol, span };
+	Ok(match node_as.kind() {
+		"signature" => {
+			let function = signature(context, symbols, source, node_as, annotations)?;
+			let functions = &mut context.functions.entry(path.clone()).or_default();
+			let symbol = Symbol::Function(FunctionPath(path, functions.len()));
+			context.items.write().push(Item::Import(symbol.clone()));
+			context.imports.insert(symbol, import);
+			functions.push(Arc::new(function));
+		}
+		"static" => {
+			let identifier = super::field_identifier(source, node_as);
+			let node_type = node_as.child_by_field_name("type").unwrap();
+			let node_type = Some(super::node_type(context, symbols, source, node_type)?);
+			let variable = Static { annotations, identifier, node_type, value: None };
+			let symbol = Symbol::Variable(path.clone());
+			context.items.write().push(Item::Import(symbol.clone()));
+			context.imports.insert(symbol, import);
+			context.statics.insert(path, variable);
+		}
+		_ => return super::unexpected(context, source, node_as, "import"),
+	})
+}
+
+fn signature(context: &Context, symbols: &Symbols, source: &Source,
+			 node: Node, annotations: Annotations) -> crate::Result<Function> {
+	let identifier = super::field_identifier(source, node);
+	let convention = node.child_by_field_name("convention")
+		.map(|node| super::identifier(source, node));
+
+	let cursor = &mut node.walk();
+	let parameters = node.children_by_field_name("parameter", cursor)
+		.enumerate().map(|(index, node)| {
+			let span = Span::new(node.byte_range(), source.file);
+			let variable = Variable(Identifier(index.to_string()), 0);
+			let variable = S::new(variable, span.clone());
+			let node_type = super::node_type(context, symbols, source, node)?;
+			Ok(S::new(Parameter::Variable(variable, node_type), span))
+		}).collect::<crate::Result<_>>()?;
+
+	let return_type = match node.child_by_field_name("return") {
+		Some(node) => {
+			let node_type = super::node_type(context, symbols, source, node)?;
+			let span = node_type.span.clone();
+			S::new(ReturnType::Type(node_type), span)
+		}
+		None => {
+			let void = S::new(Type::Void, identifier.span.clone());
+			S::new(ReturnType::Type(void), identifier.span.clone())
+		}
+	};
+
+	let (is_root, value) = (false, Value::default());
+	Ok(Function { is_root, annotations, identifier, convention, parameters, return_type, value })
+}
+
+fn integral(string: &str) -> Option<Address> {
+	match string.get(..2) {
+		Some("0x") => Address::from_str_radix(&string[2..], 16).ok(),
+		Some("0o") => Address::from_str_radix(&string[2..], 8).ok(),
+		Some("0b") => Address::from_str_radix(&string[2..], 2).ok(),
+		_ => string.parse().ok(),
+	}
+}
+
+/// Defines the declarations read from a C header with
+/// `use "library" with "header"`. Structures use the C
+/// representation and constants are not placed in memory.
+pub fn header(context: &Context, symbols: &mut Symbols,
+			  source: &Source, node: Node) -> crate::Result<()> {
+	let declarations = symbols.declarations(source, node);
+	let Declarations { namespace, library, identifier, header, binary } = declarations;
+	let annotations = super::annotations(context, symbols, source, node);
+	if !admitted(&annotations, "unsupported") {
+		header.unsupported.iter().for_each(|reason| context.emit(Diagnostic::warning()
+			.message(format!("unsupported declaration: {}", reason.node))
+			.label(reason.span.label()).note("the declaration is not imported")
+			.note("suppress with the annotation: @admit unsupported")));
+	}
+
+	for (identifier, fields) in header.structures {
+		let mut annotations: Annotations = HashMap::new();
+		let representation = Identifier("representation".to_string());
+		let value = ValueNode::String("C".to_string());
+		annotations.insert(representation, S::new(self::value(value, &identifier.span),
+			identifier.span.clone()));
+
+		let identifiers = fields.iter().map(|(identifier, _)|
+			(identifier.node.clone(), identifier.span.clone())).collect();
+		let fields = fields.into_iter().map(|(identifier, node_type)|
+			(identifier.node, node_type)).collect();
+		let path = namespace.push(identifier.node);
+		let bits = HashMap::new();
+		context.structures.insert(path, Structure { annotations, fields, bits, identifiers });
+	}
+
+	for (identifier, node_type, integral) in header.constants {
+		let mut annotations: Annotations = HashMap::new();
+		let constant = Identifier("constant".to_string());
+		annotations.insert(constant, S::new(self::value(ValueNode::Truth(true),
+			&identifier.span), identifier.span.clone()));
+
+		let span = identifier.span.clone();
+		let value = Some(self::value(ValueNode::Integral(integral), &span));
+		let node_type = Some(S::new(node_type, span));
+		let path = namespace.push(identifier.node.clone());
+		let variable = Static { annotations, identifier, node_type, value };
+		context.statics.insert(path, variable);
+	}
+
+	let undefined = |identifier: &S<Identifier>| {
+		let Identifier(name) = &identifier.node;
+		let symbol = binary.0.get(name).map(|_| ImportSymbol::Name(name.clone()));
+		if symbol.is_none() && !admitted(&annotations, "undefined") {
+			context.emit(Diagnostic::warning().label(identifier.span.label())
+				.message(format!("undefined symbol: {}, in library", name))
+				.note("the declaration is not imported")
+				.note("suppress with the annotation: @admit undefined"));
+		}
+		symbol
+	};
+
+	for (identifier, node_type) in header.variables {
+		if let Some(symbol) = undefined(&identifier) {
+			let span = identifier.span.clone();
+			let path = namespace.push(identifier.node.clone());
+			let import = Import { library: library.clone(), symbol, span };
+			let symbol = Symbol::Variable(path.clone());
+			context.items.write().push(Item::Import(symbol.clone()));
+			context.imports.insert(symbol, import);
+
+			let (annotations, node_type) = (HashMap::new(), Some(node_type));
+			let variable = Static { annotations, identifier, node_type, value: None };
+			context.statics.insert(path, variable);
+		}
+	}
+
+	for (identifier, parameters, node_type) in header.functions {
+		if let Some(symbol) = undefined(&identifier) {
+			let span = identifier.span.clone();
+			let parameters = parameters.into_iter().enumerate().map(|(index, node_type)| {
+				let variable = Variable(Identifier(index.to_string()), 0);
+				let variable = S::new(variable, node_type.span.clone());
+				let span = node_type.span.clone();
+				S::new(Parameter::Variable(variable, node_type), span)
+			}).collect();
+
+			let return_type = S::new(ReturnType::Type(node_type), span.clone());
+			let (is_root, annotations, value) = (false, HashMap::new(), Value::default());
+			let path = namespace.push(identifier.node.clone());
+			let function = Function { is_root, annotations, identifier,
+				convention: None, parameters, return_type, value };
+
+			let import = Import { library: library.clone(), symbol, span };
+			let functions = &mut context.functions.entry(path.clone()).or_default();
+			let symbol = Symbol::Function(FunctionPath(path, functions.len()));
+			context.items.write().push(Item::Import(symbol.clone()));
+			context.imports.insert(symbol, import);
+			functions.push(Arc::new(function));
+		}
+	}
+
+	let file = self::file(context, source, node)?;
+	Ok(register(context, library, annotations, identifier, file))
+}
+
+fn value(node: ValueNode, span: &Span) -> Value {
+	let mut value = Value::default();
+	value.root = value.insert(S::new(node, span.clone()));
+	value
+}
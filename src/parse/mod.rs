@@ -1,9 +1,19 @@
+pub use annotation::*;
 pub use function::*;
+pub use header::*;
 pub use item::*;
+pub use library::*;
+pub use string::*;
 pub use symbols::*;
+pub use syntax::*;
 pub use value::*;
 
+mod annotation;
+mod header;
 mod item;
+mod library;
+mod string;
 mod symbols;
+mod syntax;
 mod function;
 mod value;
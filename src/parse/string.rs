@@ -0,0 +1,82 @@
+use std::str::CharIndices;
+
+use tree_sitter::Node;
+
+use crate::context::Context;
+use crate::error::Diagnostic;
+use crate::span::{S, Span};
+
+use super::Source;
+
+/// Parses a quoted literal and decodes escape sequences.
+/// All invalid escape sequences are reported.
+pub fn string(context: &Context, source: &Source, node: Node) -> crate::Result<S<String>> {
+	let start = node.start_byte() + 1;
+	let text = &source.text[start..node.end_byte() - 1];
+	let (mut string, mut valid) = (String::new(), true);
+	let characters = &mut text.char_indices();
+	while let Some((offset, character)) = characters.next() {
+		if character != '\\' {
+			string.push(character);
+			continue;
+		}
+
+		match escape(characters) {
+			Ok(character) => string.push(character),
+			Err(end) => {
+				valid = false;
+				let end = end.unwrap_or(text.len());
+				let span = Span::new(start + offset..start + end, source.file);
+				context.emit(Diagnostic::error().message("invalid escape sequence")
+					.label(span.label()).note("valid escapes: \\n \\r \\t \\0 \\\\ \
+						\\\" \\' \\xHH (at most 7f) \\u{HHHHHH}"));
+			}
+		}
+	}
+
+	match valid {
+		false => Err(crate::query::QueryError::Failure),
+		true => Ok(S::create(string, node.byte_range(), source.file)),
+	}
+}
+
+/// Decodes a single escape sequence after the backslash. If the sequence
+/// is invalid then the offset after the erroneous character is returned.
+fn escape(characters: &mut CharIndices) -> Result<char, Option<usize>> {
+	let next = |characters: &mut CharIndices| characters.next().ok_or(None);
+	let end = |(offset, character): (usize, char)| Some(offset + character.len_utf8());
+	Ok(match next(characters)? {
+		(_, 'n') => '\n',
+		(_, 'r') => '\r',
+		(_, 't') => '\t',
+		(_, '0') => '\0',
+		(_, '\\') => '\\',
+		(_, '"') => '"',
+		(_, '\'') => '\'',
+		(_, 'x') => {
+			let mut value = 0;
+			for _ in 0..2 {
+				let digit = next(characters)?;
+				value = value * 16 + digit.1.to_digit(16).ok_or(end(digit))?;
+				if value > 0x7f { return Err(end(digit)); }
+			}
+			value as u8 as char
+		}
+		(_, 'u') => {
+			let open = next(characters)?;
+			if open.1 != '{' { return Err(end(open)); }
+
+			let (mut value, mut count) = (0, 0);
+			loop {
+				let digit = next(characters)?;
+				match digit.1 {
+					'}' if count > 0 => return std::char::from_u32(value).ok_or(end(digit)),
+					_ if count == 6 => return Err(end(digit)),
+					other => value = value * 16 + other.to_digit(16).ok_or(end(digit))?,
+				}
+				count += 1;
+			}
+		}
+		other => return Err(end(other)),
+	})
+}
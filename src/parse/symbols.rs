@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
-use tree_sitter::{Node, Query, QueryCursor, Tree};
+use codespan::FileId;
+use tree_sitter::{Node, Tree};
 
 use crate::arena::OwnedArena;
 use crate::context::Context;
@@ -9,9 +10,9 @@ use crate::node::{Identifier, Path};
 use crate::query::Key;
 use crate::span::{S, Span};
 
-use super::Source;
+use super::{Binary, Header, Source};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum Include {
 	Wild(Path),
 	Item(Path),
@@ -33,10 +34,32 @@ pub enum Unit<'a> {
 	ModuleEnd,
 }
 
+/// A path in a source file and the symbol it resolves to.
+#[derive(Debug, Clone)]
+pub struct Reference {
+	pub span: Span,
+	pub path: Path,
+}
+
+/// Declarations imported from a C header with
+/// `use "library" with "header"`.
+pub struct Declarations {
+	pub namespace: Path,
+	pub library: Path,
+	pub identifier: S<Identifier>,
+	pub header: Header,
+	pub binary: Binary,
+}
+
 #[derive(Default)]
 pub struct Symbols<'a> {
 	table: HashMap<Path, S<SymbolKind>>,
 	includes: Vec<Vec<S<Include>>>,
+	/// The spans of the modules on the symbol stack.
+	scopes: Vec<Span>,
+	/// Every include with the span of the module it is within.
+	inclusions: Vec<(Option<Span>, S<Include>)>,
+	headers: HashMap<(FileId, usize), Declarations>,
 	trees: OwnedArena<'a, Tree>,
 }
 
@@ -46,7 +69,8 @@ impl<'a> Symbols<'a> {
 		let mut units = Vec::new();
 		let mut symbols = Symbols { includes: vec![Vec::new()], ..Symbols::default() };
 		let internal = S::new(SymbolKind::Intrinsic, context.files.read().internal.clone());
-		["size", "start", "end"].iter().map(|intrinsic| Identifier(intrinsic.to_string()))
+		["size", "start", "end", "load", "length", "offset",
+			"align", "assert", "terminated", "include"].iter().map(|intrinsic| Identifier(intrinsic.to_string()))
 			.map(|intrinsic| Path(vec![Identifier("Intrinsic".to_string()), intrinsic]))
 			.for_each(|path| symbols.table.insert(path, internal.clone()).unwrap_none());
 		file(context, &mut symbols, &mut units, path, &Path::default(), None)?;
@@ -76,7 +100,11 @@ impl<'a> Symbols<'a> {
 
 		let (value, other) = candidates.next()?;
 		match candidates.peek().is_some() {
-			false => Some(value),
+			false => {
+				let (path, _) = &value;
+				self.reference(context, path, span);
+				Some(value)
+			}
 			true => {
 				let diagnostic = Diagnostic::error()
 					.message("ambiguous symbol resolution")
@@ -87,16 +115,44 @@ impl<'a> Symbols<'a> {
 		}
 	}
 
+	/// Records a path written in a source file that names a symbol.
+	pub fn reference(&self, context: &Context, path: &Path, span: &Span) {
+		if self.table.contains_key(path) {
+			let (span, path) = (span.clone(), path.clone());
+			context.references.write().push(Reference { span, path });
+		}
+	}
+
+	/// Returns the span of the identifier that defines each symbol.
+	pub fn definitions(&self) -> HashMap<Path, Span> {
+		self.table.iter().map(|(path, symbol)|
+			(path.clone(), symbol.span.clone())).collect()
+	}
+
+	/// Returns every include with the span of the module it is within.
+	/// Includes outside of a module have no span.
+	pub fn inclusions(&self) -> Vec<(Option<Span>, S<Include>)> {
+		self.inclusions.clone()
+	}
+
 	pub fn include(&mut self, include: S<Include>) {
+		self.inclusions.push((self.scopes.last().cloned(), include.clone()));
 		self.includes.last_mut().expect("symbol stack is empty").push(include);
 	}
 
-	pub fn push(&mut self) {
+	pub fn push(&mut self, scope: Span) {
 		self.includes.push(Vec::new());
+		self.scopes.push(scope);
 	}
 
 	pub fn pop(&mut self) {
 		self.includes.pop().expect("symbol stack is empty");
+		self.scopes.pop();
+	}
+
+	pub fn declarations(&mut self, source: &Source, node: Node) -> Declarations {
+		let key = (source.file, node.start_byte());
+		self.headers.remove(&key).expect("header declarations are not present")
 	}
 }
 
@@ -126,7 +182,10 @@ fn file<'a>(context: &Context, symbols: &mut Symbols<'a>, units: &mut Vec<Unit<'
 	let key = Key::SymbolFile(canonical.unwrap());
 	context.unit.ephemeral(None, key, span.clone(), || {
 		let file = file.parent().unwrap().to_owned();
-		let tree = super::parser().parse(text.as_bytes(), None).unwrap();
+		let previous = context.trees.lock().get(&source).cloned();
+		let tree = super::parser().parse(text.as_bytes(), previous.as_ref()).unwrap();
+		context.trees.lock().insert(source, tree.clone());
+		let tree = super::recover(context, source, &text, tree);
 		let source = super::Source { file: source, path: file, text };
 		let root_node = symbols.trees.push(tree).root_node();
 		traverse(context, symbols, units, &source, path, root_node)
@@ -135,11 +194,11 @@ fn file<'a>(context: &Context, symbols: &mut Symbols<'a>, units: &mut Vec<Unit<'
 
 fn traverse<'a>(context: &Context, symbols: &mut Symbols<'a>, units: &mut Vec<Unit<'a>>,
 				source: &Source, path: &Path, node: Node<'a>) -> crate::Result<()> {
-	let (cursor, errors) = (&mut node.walk(), &super::errors());
-	let mut nodes = node.named_children(cursor);
-	nodes.try_for_each(|node: Node| Ok(match node.kind() {
-		"ERROR" => syntax_error(context, source, node),
-		"module" => {
+	let cursor = &mut node.walk();
+	let nodes = node.named_children(cursor);
+	nodes.map(|node: Node| Ok(match node.kind() {
+		"module" if node.child_by_field_name("identifier")
+			.filter(|node| !node.has_error()).is_some() => {
 			let element = super::field_identifier(source, node);
 			let path = path.push(element.node.clone());
 			match symbols.table.get(&path) {
@@ -147,15 +206,16 @@ fn traverse<'a>(context: &Context, symbols: &mut Symbols<'a>, units: &mut Vec<Un
 					units.push(Unit::Item(path.clone(), source.clone(), node));
 					let symbol = S::new(SymbolKind::Module, element.span);
 					symbols.table.insert(path.clone(), symbol);
-					traverse(context, symbols, units, source, &path, node)?;
+					let result = traverse(context, symbols, units, source, &path, node);
 					units.push(Unit::ModuleEnd);
+					result?;
 				}
 				Some(other) => context.emit(Diagnostic::error().message("duplicate symbol")
 					.label(element.span.label()).label(other.span.label())),
 			}
 		}
-		_ if !valid(context, source, errors, node) => (),
-		"annotation" => units.push(Unit::Item(path.clone(), source.clone(), node)),
+		_ if node.has_error() => (),
+		"global_annotation" => units.push(Unit::Item(path.clone(), source.clone(), node)),
 		"function" => function(context, symbols, units, source,
 			path, node, super::field_identifier(source, node)),
 		"static" => duplicate(context, symbols, units, source, path, node,
@@ -164,22 +224,7 @@ fn traverse<'a>(context: &Context, symbols: &mut Symbols<'a>, units: &mut Vec<Un
 			SymbolKind::Structure, super::field_identifier(source, node)),
 		"use" => import(context, symbols, units, source, path, node)?,
 		_ => (),
-	}))
-}
-
-fn valid(context: &Context, source: &super::Source,
-		 errors: &Query, node: Node) -> bool {
-	let mut error_cursor = QueryCursor::new();
-	let captures = error_cursor.captures(errors, node,
-		|node| &source.text[node.byte_range()]);
-	captures.flat_map(|(capture, _)| capture.captures)
-		.map(|capture| syntax_error(context, source, capture.node))
-		.last().is_none()
-}
-
-fn syntax_error(context: &Context, source: &Source, node: Node) {
-	let label = Span::new(node.byte_range(), source.file).label();
-	context.emit(Diagnostic::error().message("syntax error").label(label));
+	})).filter(Result::is_err).last().unwrap_or(Ok(()))
 }
 
 fn import<'a>(context: &Context, symbols: &mut Symbols<'a>, units: &mut Vec<Unit<'a>>,
@@ -187,8 +232,8 @@ fn import<'a>(context: &Context, symbols: &mut Symbols<'a>, units: &mut Vec<Unit
 	let node_path = node.child_by_field_name("path").unwrap();
 	Ok(match node_path.kind() {
 		"string" => {
-			let other = super::string(source, node_path);
-			let file = &source.path.join(other.node);
+			let other = super::string(context, source, node_path)?;
+			let file = &source.path.join(&other.node);
 			let extension = file.extension()
 				.and_then(std::ffi::OsStr::to_str);
 			let path_as = node.child_by_field_name("as")
@@ -198,6 +243,11 @@ fn import<'a>(context: &Context, symbols: &mut Symbols<'a>, units: &mut Vec<Unit
 				let path = path_as.map(|node| path
 					.push(node.node)).unwrap_or_else(|| path.clone());
 				self::file(context, symbols, units, file, &path, Some(other.span))?;
+			} else if let Some(node_header) = node.child_by_field_name("with") {
+				let file = S::new(file.clone(), other.span.clone());
+				let binary = super::binary(context, &file)?;
+				header(context, symbols, units, source, path,
+					node, node_header, path_as, other, binary)?;
 			} else if let Some(identifier) = path_as {
 				duplicate(context, symbols, units, source,
 					path, node, SymbolKind::Library, identifier);
@@ -217,6 +267,68 @@ fn import<'a>(context: &Context, symbols: &mut Symbols<'a>, units: &mut Vec<Unit
 	})
 }
 
+/// Registers the declarations of a C header within the namespace
+/// of the identifier if present and the current module otherwise.
+/// Functions and variables absent from the library are omitted.
+fn header<'a>(context: &Context, symbols: &mut Symbols<'a>, units: &mut Vec<Unit<'a>>,
+			  source: &Source, path: &Path, node: Node<'a>, node_header: Node,
+			  path_as: Option<S<Identifier>>, library: S<String>,
+			  binary: Binary) -> crate::Result<()> {
+	let string = super::string(context, source, node_header)?;
+	let file = source.path.join(&string.node);
+	let canonical = file.canonicalize().ok();
+	let header = canonical.as_ref().and_then(|file| context.files.write().query(file));
+	let (file, text) = header.ok_or_else(|| context.error(Diagnostic::error()
+		.message(format!("invalid header: {}", file.display()))
+		.label(string.span.label())))?;
+
+	let (namespace, library, identifier) = match path_as {
+		Some(identifier) => {
+			let namespace = path.push(identifier.node.clone());
+			(namespace.clone(), namespace, identifier)
+		}
+		None => {
+			let identifier = S::new(Identifier(library.node), library.span);
+			(path.clone(), path.push(identifier.node.clone()), identifier)
+		}
+	};
+
+	if let Some(other) = symbols.table.get(&library) {
+		return context.pass(Diagnostic::error().message("duplicate symbol")
+			.label(identifier.span.label()).label(other.span.label()));
+	}
+
+	let header = super::declarations(&text, file, &namespace);
+	let present = |identifier: &S<Identifier>| binary.0.contains_key(&identifier.node.0);
+	let declarations = header.structures.iter()
+		.map(|(identifier, _)| (identifier, SymbolKind::Structure))
+		.chain(header.constants.iter().map(|(identifier, _, _)|
+			(identifier, SymbolKind::Variable)))
+		.chain(header.variables.iter().map(|(identifier, _)| identifier)
+			.filter(|identifier| present(identifier))
+			.map(|identifier| (identifier, SymbolKind::Variable)))
+		.chain(header.functions.iter().map(|(identifier, _, _)| identifier)
+			.filter(|identifier| present(identifier))
+			.map(|identifier| (identifier, SymbolKind::Function)));
+
+	for (identifier, kind) in declarations {
+		let path = namespace.push(identifier.node.clone());
+		match symbols.table.get(&path) {
+			Some(other) => context.emit(Diagnostic::error().message("duplicate symbol")
+				.label(identifier.span.label()).label(other.span.label())),
+			None => std::mem::drop(symbols.table.insert(path,
+				S::new(kind, identifier.span.clone()))),
+		}
+	}
+
+	let symbol = S::new(SymbolKind::Library, identifier.span.clone());
+	symbols.table.insert(library.clone(), symbol);
+	units.push(Unit::Item(path.clone(), source.clone(), node));
+	let declarations = Declarations { namespace, library, identifier, header, binary };
+	symbols.headers.insert((source.file, node.start_byte()), declarations);
+	Ok(())
+}
+
 fn function<'a>(context: &Context, symbols: &mut Symbols<'a>, units: &mut Vec<Unit<'a>>,
 				source: &Source, path: &Path, node: Node<'a>, identifier: S<Identifier>) {
 	let path = path.push(identifier.node);
@@ -0,0 +1,396 @@
+use std::ops::Range;
+
+use codespan::FileId;
+use tree_sitter::{Node, Tree};
+
+use crate::context::Context;
+use crate::error::Diagnostic;
+use crate::span::Span;
+
+use super::Source;
+
+/// Reports the first error or missing node in each item of a tree.
+/// Returns the tree of the text with each item that contains an error
+/// replaced with whitespace such that the remaining items are parsed
+/// without the errors hiding them. Offsets in the text are preserved.
+/// Errors that remain only from replacing items are not reported.
+pub fn recover(context: &Context, file: FileId, text: &str, mut tree: Tree) -> Tree {
+	let (mut text, mut reported) = (text.to_string(), false);
+	while tree.root_node().has_error() {
+		let mut items: Vec<Range<usize>> = Vec::new();
+		for node in errors(tree.root_node()) {
+			let offset = match node.is_missing() {
+				true => node.start_byte(),
+				false => trim(&text, node.byte_range()).start,
+			};
+
+			if items.iter().any(|item| item.contains(&offset)) { continue; }
+			let item = item(&text, offset);
+			let headers = &mut headers(&text, item.start);
+			items.extend(report(context, file, &text, headers, item));
+		}
+
+		if items.is_empty() {
+			let lines = lines(&text);
+			let declarations = lines.iter().filter(|(_, line)|
+				indent(line) == 0 && declaration(line).is_some());
+			for (start, _) in declarations {
+				let item = item(&text, *start);
+				items.extend(report(context, file, &text, &mut Vec::new(), item));
+			}
+		}
+
+		if items.is_empty() && !reported {
+			let node = errors(tree.root_node()).remove(0);
+			let item = item(&text, trim(&text, node.byte_range()).start);
+			context.emit(diagnostic(file, &text, node, item.clone()));
+			items.push(item);
+		}
+
+		let other = blank(&text, |index| !items.iter().any(|item| item.contains(&index)));
+		if other == text { break; }
+		text = other;
+		reported = true;
+		tree = super::parser().parse(text.as_bytes(), None).unwrap();
+	}
+	tree
+}
+
+/// Reports a node that is not of an expected kind.
+pub fn unexpected<T>(context: &Context, source: &Source,
+					 node: Node, expected: &str) -> crate::Result<T> {
+	let span = Span::new(node.byte_range(), source.file);
+	let label = span.label().with_message(format!("unexpected: {}", node.kind()));
+	context.pass(Diagnostic::error().message(format!("expected {}", expected)).label(label))
+}
+
+/// Returns the innermost error nodes and the missing nodes within a node.
+fn errors(node: Node) -> Vec<Node> {
+	let cursor = &mut node.walk();
+	let children: Vec<_> = node.children(cursor)
+		.filter(|child| child.has_error()).collect();
+	match node.is_missing() || (node.is_error() && children.is_empty()) {
+		true => vec![node],
+		false => children.into_iter().flat_map(errors).collect(),
+	}
+}
+
+fn diagnostic(file: FileId, text: &str, node: Node, item: Range<usize>) -> Diagnostic {
+	let (message, range, label) = match node.is_missing() {
+		true => {
+			let expected = describe(node);
+			let range = node.start_byte()..node.start_byte();
+			(format!("expected {}", expected), range, format!("missing {}", expected))
+		}
+		false => {
+			let mut leaves = Vec::new();
+			self::leaves(node, &mut leaves);
+			let mut expected = expected(node);
+			if expected == Some("identifier") && leaves.len() > 1
+				&& leaves[0].kind() == "identifier" {
+				leaves.remove(0);
+				expected = None;
+			}
+
+			let start = leaves.first().map_or(node.start_byte(), Node::start_byte);
+			let range = trim(text, start..node.end_byte());
+			let token = text[range.clone()].split_whitespace().next().unwrap_or_default();
+			let token = leaves.first().map_or(token, |node| &text[node.byte_range()]);
+			match (construct(node), expected) {
+				(Some(item), _) => (format!("invalid {}", item), range,
+					format!("expected a complete {}", item)),
+				(None, expected) => (expected.map(|expected| format!("expected {}", expected))
+					.unwrap_or_else(|| format!("unexpected `{}`", token)),
+					range, format!("unexpected `{}`", token)),
+			}
+		}
+	};
+
+	let label = Span::new(range.clone(), file).label().with_message(label);
+	let diagnostic = Diagnostic::error().message(message).label(label);
+	mistakes(text, range, item).into_iter().fold(diagnostic, Diagnostic::note)
+}
+
+/// Returns what is expected in place of an error node
+/// from the node that contains it and the node before it.
+fn expected(node: Node) -> Option<&'static str> {
+	let parent = node.parent()?;
+	let previous = node.prev_sibling();
+	let previous = previous.as_ref().map(Node::kind);
+	Some(match (parent.kind(), previous) {
+		("function", Some("fn")) | ("signature", Some("fn")) | ("module", Some("module")) |
+		("data", Some("data")) | ("static", Some("static")) | ("let", Some("let")) => "identifier",
+		("parameter", Some(":")) | ("static", Some(":")) |
+		("let", Some(":")) | ("cast", Some("as")) => "type",
+		("branch", Some(":")) | ("while", Some(":")) => "statement",
+		("branch", Some(_)) | ("while", Some(_)) => "`:` after condition",
+		("when", Some("when")) => "`:`",
+		("when", Some(_)) => "condition",
+		(_, Some("=")) | ("return", _) | ("binary", _) | ("unary", _) => "value",
+		("function", Some("(")) | ("function", Some(",")) => "parameter",
+		("function", Some(")")) => "return type or block",
+		("function", Some(_)) => "block",
+		("source", _) | ("module", _) => "item",
+		("block", _) => "statement",
+		("data", _) => "field",
+		_ => return None,
+	})
+}
+
+/// Returns the kind of item an error node begins
+/// if the error node is in place of an item.
+fn construct(node: Node) -> Option<&'static str> {
+	let parent = node.parent().map(|parent| parent.kind());
+	if parent.filter(|kind| *kind != "source" && *kind != "module").is_some() { return None; }
+	let cursor = &mut node.walk();
+	let mut children = node.children(cursor);
+	children.find_map(|node| match node.kind() {
+		"fn" => Some("function"),
+		"static" => Some("static variable"),
+		"data" => Some("data structure"),
+		"module" => Some("module"),
+		"use" => Some("import"),
+		_ => None,
+	})
+}
+
+/// Collects the tokens within a node in order.
+fn leaves<'a>(node: Node<'a>, leaves: &mut Vec<Node<'a>>) {
+	let cursor = &mut node.walk();
+	let children: Vec<_> = node.children(cursor).collect();
+	children.into_iter().for_each(|child| match child.child_count() {
+		0 => leaves.push(child),
+		_ => self::leaves(child, leaves),
+	});
+}
+
+/// Returns a description of the kind of a node.
+fn describe(node: Node) -> String {
+	match node.kind() {
+		"_open" => "indented block".to_string(),
+		"_close" | "_level" => "new line".to_string(),
+		kind if node.is_named() => kind.replace('_', " "),
+		kind => format!("`{}`", kind),
+	}
+}
+
+/// Returns notes for common mistakes on the lines of an error
+/// and the line before it that are within its item.
+fn mistakes(text: &str, range: Range<usize>, item: Range<usize>) -> Vec<&'static str> {
+	let line = text[..range.start].rfind('\n').map_or(0, |index| index + 1);
+	let start = text[..line.saturating_sub(1)].rfind('\n').map_or(0, |index| index + 1);
+	let end = text[range.end..].find('\n').map_or(text.len(), |index| range.end + index);
+	let start = start.max(item.start);
+	let end = end.min(item.end).max(start);
+
+	let mut notes = Vec::new();
+	for line in text[start..end].lines() {
+		let code = line.split("//").next().unwrap_or_default();
+		let indent = &code[..code.len() - code.trim_start().len()];
+		let statement = code.trim();
+		let condition = statement.starts_with("if ") || statement.starts_with("while ");
+		let note = if indent.contains(' ') && !statement.is_empty() {
+			"indent with tabs instead of spaces"
+		} else if condition && assignment(statement.split(':').next().unwrap()) {
+			"use `==` to compare values"
+		} else if condition && !statement.contains(':') {
+			"add `:` after the condition"
+		} else if statement.starts_with("let ") && statement.contains("==") {
+			"use `=` to assign a value"
+		} else { continue; };
+
+		if !notes.contains(&note) { notes.push(note); }
+	}
+
+	let code = text[start..end].lines().map(|line|
+		line.split("//").next().unwrap_or_default());
+	let (mut string, mut depths) = (false, [0, 0]);
+	for character in code.flat_map(str::chars) {
+		match character {
+			'"' => string = !string,
+			'(' if !string => depths[0] += 1,
+			')' if !string => depths[0] -= 1,
+			'[' if !string => depths[1] += 1,
+			']' if !string => depths[1] -= 1,
+			_ => (),
+		}
+	}
+
+	if depths[0] > 0 { notes.push("add a closing `)`"); }
+	if depths[1] > 0 { notes.push("add a closing `]`"); }
+
+	let last = text[start..end].lines().rev().map(|line| line.split("//")
+		.next().unwrap_or_default().trim_end()).find(|line| !line.is_empty());
+	if last.filter(|line| line.ends_with(|character| "+-*/%&|^<>=!,".contains(character))).is_some() {
+		notes.push("add a value after the operator");
+	}
+	notes
+}
+
+/// Whether a condition contains an `=` that is not part
+/// of a comparison or a structure creation.
+fn assignment(condition: &str) -> bool {
+	let bytes = condition.as_bytes();
+	!condition.contains('~') && (0..bytes.len()).any(|index| bytes[index] == b'='
+		&& !matches!(index.checked_sub(1).map(|index| bytes[index]),
+			Some(b'=') | Some(b'!') | Some(b'<') | Some(b'>'))
+		&& bytes.get(index + 1) != Some(&b'='))
+}
+
+/// Returns the range of the lines of the item that contains an offset
+/// with its annotations or the range of the line if there is no item.
+fn item(text: &str, offset: usize) -> Range<usize> {
+	let lines = lines(text);
+	let index = lines.iter().rposition(|(start, _)| *start <= offset).unwrap();
+	let (start, line) = lines[index];
+	let range = start..start + line.len();
+
+	let level = indent(line);
+	let first = (0..=index).rev().find(|index| {
+		let (_, line) = lines[*index];
+		indent(line) <= level && declaration(line).is_some()
+	});
+
+	let first = match first {
+		Some(first) => first,
+		None => return range,
+	};
+
+	let level = indent(lines[first].1);
+	let last = lines[first + 1..].iter().position(|(_, line)| !line.trim().is_empty()
+		&& indent(line) <= level && !line.trim_start_matches('\t').starts_with(' '))
+		.map_or(lines.len(), |position| first + 1 + position);
+	if last <= index { return range; }
+
+	let first = (0..first).rev().take_while(|index| lines[*index].1
+		.trim_start().starts_with('@') && indent(lines[*index].1) == level)
+		.last().unwrap_or(first);
+	let (end, line) = lines[last - 1];
+	lines[first].0..end + line.len()
+}
+
+/// Reports the items that have syntax errors within an item.
+/// Returns the ranges of the items that are reported.
+fn report(context: &Context, file: FileId, text: &str,
+		  headers: &mut Vec<Range<usize>>, item: Range<usize>) -> Vec<Range<usize>> {
+	broken(text, headers, item).into_iter().map(|(item, text)| {
+		context.emit(isolated(file, &text, item.clone()));
+		item
+	}).collect()
+}
+
+/// Returns the items that have syntax errors when parsed with only the
+/// lines that declare their enclosing items, paired with the text they
+/// are parsed in. An item is replaced with the items within it if any
+/// of them have syntax errors.
+fn broken(text: &str, headers: &mut Vec<Range<usize>>,
+		  item: Range<usize>) -> Vec<(Range<usize>, String)> {
+	let isolated = blank(text, |index| item.contains(&index)
+		|| headers.iter().any(|header| header.contains(&index)));
+	let tree = super::parser().parse(isolated.as_bytes(), None).unwrap();
+	if !tree.root_node().has_error() { return Vec::new(); }
+
+	let lines = lines(text);
+	let index = lines.iter().position(|(start, line)| *start >= item.start
+		&& declaration(line).is_some()).unwrap_or_default();
+	let (start, line) = lines[index];
+	headers.push(item.start..start + line.len());
+
+	let level = indent(line);
+	let mut broken = Vec::new();
+	let body = lines[index + 1..].iter().take_while(|(start, _)| *start < item.end);
+	if declaration(line) == Some("module") && body.clone().all(|(_, line)| line.trim().is_empty()) {
+		headers.pop();
+		return Vec::new();
+	}
+
+	let nested = body.filter(|(_, line)| indent(line) == level + 1 && declaration(line).is_some());
+	for (start, _) in nested {
+		let nested = self::item(text, *start);
+		broken.extend(self::broken(text, headers, nested));
+	}
+
+	headers.pop();
+	if broken.is_empty() { broken.push((item, isolated)); }
+	broken
+}
+
+/// Returns the ranges of the lines that declare the
+/// modules that enclose the line at an offset.
+fn headers(text: &str, offset: usize) -> Vec<Range<usize>> {
+	let mut headers = Vec::new();
+	let lines = lines(text);
+	let index = lines.iter().rposition(|(start, _)| *start <= offset).unwrap();
+	let mut level = indent(lines[index].1);
+	for (start, line) in lines[..index].iter().rev() {
+		if level > 0 && indent(line) < level && declaration(line) == Some("module") {
+			headers.push(*start..start + line.len());
+			level = indent(line);
+		}
+	}
+
+	headers.reverse();
+	headers
+}
+
+/// Returns the diagnostic for the first error or missing node
+/// within an item in a text that contains only the item and the
+/// lines that declare its enclosing items.
+fn isolated(file: FileId, text: &str, item: Range<usize>) -> Diagnostic {
+	let tree = super::parser().parse(text.as_bytes(), None).unwrap();
+	let node = errors(tree.root_node()).into_iter().find(|node|
+		item.contains(&trim(text, node.byte_range()).start));
+	if let Some(node) = node {
+		return diagnostic(file, text, node, item);
+	}
+
+	let range = trim(text, item.clone());
+	let construct = text[range.clone()].lines().find_map(declaration).unwrap_or("item");
+	let label = Span::new(range.clone(), file).label()
+		.with_message(format!("expected a complete {}", construct));
+	let diagnostic = Diagnostic::error().message(format!("invalid {}", construct)).label(label);
+	mistakes(text, range, item).into_iter().fold(diagnostic, Diagnostic::note)
+}
+
+/// Returns a text with the bytes that are not kept replaced with spaces
+/// except for new lines. The kept bytes must form whole lines.
+fn blank(text: &str, kept: impl Fn(usize) -> bool) -> String {
+	let bytes = text.bytes().enumerate().map(|(index, byte)|
+		if byte == b'\n' || kept(index) { byte } else { b' ' }).collect();
+	String::from_utf8(bytes).unwrap()
+}
+
+/// Returns the kind of item a line declares.
+fn declaration(line: &str) -> Option<&'static str> {
+	let mut words = line.split_whitespace();
+	match (words.next()?, words.next()) {
+		(_, Some("fn")) | ("fn", _) | ("root", _) => Some("function"),
+		("static", _) => Some("static variable"),
+		("data", _) => Some("data structure"),
+		("module", _) => Some("module"),
+		("use", _) => Some("import"),
+		_ => None,
+	}
+}
+
+/// Returns the start of each line in a text and the line.
+fn lines(text: &str) -> Vec<(usize, &str)> {
+	let mut start = 0;
+	text.split('\n').map(|line| {
+		let offset = start;
+		start += line.len() + 1;
+		(offset, line)
+	}).collect()
+}
+
+/// Returns the indentation level of a line.
+fn indent(line: &str) -> usize {
+	line.len() - line.trim_start_matches('\t').len()
+}
+
+/// Returns a range without leading and trailing whitespace.
+fn trim(text: &str, range: Range<usize>) -> Range<usize> {
+	let string = &text[range.clone()];
+	let start = range.start + string.len() - string.trim_start().len();
+	start..start.max(range.start + string.trim_end().len())
+}
@@ -65,10 +65,10 @@ pub fn unit(scene: &mut Scene, node: Node) -> crate::Result<ValueIndex> {
 		"continue" => ValueNode::Continue,
 		"path" => return path(scene, node),
 		"group" => return unit(scene, node.named_child(0).unwrap()),
-		"string" => ValueNode::String(super::string(scene.source, node).node),
+		"string" => ValueNode::String(super::string(scene.context, scene.source, node)?.node),
 		"register" => ValueNode::Register(super::register(scene.source, node).node),
 		"truth" => ValueNode::Truth(&scene.source.text[node.byte_range()] == "true"),
-		"rune" => super::string(scene.source, node).node
+		"rune" => super::string(scene.context, scene.source, node)?.node
 			.chars().next().map(ValueNode::Rune).unwrap(),
 		"block" => {
 			let cursor = &mut node.walk();
@@ -87,7 +87,8 @@ pub fn unit(scene: &mut Scene, node: Node) -> crate::Result<ValueIndex> {
 				Some("0o") => i128::from_str_radix(&string[2..], 8),
 				Some("0b") => i128::from_str_radix(&string[2..], 2),
 				_ => i128::from_str_radix(string, 10),
-			}.unwrap())
+			}.map_err(|_| scene.context.error(Diagnostic::error().message("integral is too large")
+				.label(Span::new(node.byte_range(), scene.source.file).label())))?)
 		}
 		"let" => {
 			let identifier = super::field_identifier(scene.source, node);
@@ -107,9 +108,11 @@ pub fn unit(scene: &mut Scene, node: Node) -> crate::Result<ValueIndex> {
 			let target = unit(scene, node.child_by_field_name("target").unwrap())?;
 			let value = unit(scene, node.child_by_field_name("value").unwrap())?;
 			let operator = node.child_by_field_name("operator").unwrap();
-			let operator = &scene.source.text[operator.byte_range()];
-			let operator = Dual::parse(operator).unwrap_or_else(||
-				panic!("invalid compound operator: {}", operator));
+			let operator = match Dual::parse(&scene.source.text[operator.byte_range()]) {
+				Some(operator) => operator,
+				None => return super::unexpected(scene.context,
+					scene.source, operator, "compound operator"),
+			};
 			ValueNode::Compound(operator, target, value)
 		}
 		"return" => {
@@ -159,7 +162,7 @@ pub fn unit(scene: &mut Scene, node: Node) -> crate::Result<ValueIndex> {
 					let value = field.child_by_field_name("value").unwrap();
 					(identifier.node, (unit(scene, value)?, identifier.span))
 				}
-				other => panic!("invalid field kind: {}", other)
+				_ => return super::unexpected(scene.context, scene.source, field, "field"),
 			})).collect::<Result<_, _>>()?)
 		}
 		"slice" => {
@@ -190,9 +193,34 @@ pub fn unit(scene: &mut Scene, node: Node) -> crate::Result<ValueIndex> {
 			match scene.symbols.resolve(scene.context, &path.node, &span) {
 				Some((path, SymbolKind::Function)) |
 				Some((path, SymbolKind::Intrinsic)) => {
+					let include = path == ["Intrinsic", "include"][..];
+					let layout = path == ["Intrinsic", "offset"][..] ||
+						path == ["Intrinsic", "align"][..];
 					let cursor = &mut node.walk();
-					let arguments = node.children_by_field_name("argument", cursor)
-						.map(|node| unit(scene, node)).collect::<Result<_, _>>()?;
+					let mut arguments = Vec::new();
+					for node in node.children_by_field_name("argument", cursor) {
+						arguments.push(match (node.kind(), arguments.first()) {
+							("string", _) if include => self::include(scene, node)?,
+							("path", None) if layout => {
+								let node_type = super::node_type(scene.context,
+									scene.symbols, scene.source, node)?;
+								scene.value(ValueNode::Type(node_type.node), node)
+							}
+							("path", Some(structure)) if layout => {
+								let structure = *structure;
+								let field = super::path(scene.source, node);
+								let span = field.span.clone();
+								let field = match field.node {
+									Path(mut elements) if elements.len() == 1 =>
+										S::new(elements.remove(0), span),
+									_ => return scene.context.pass(Diagnostic::error()
+										.message("expected field").label(span.label())),
+								};
+								scene.value(ValueNode::Field(structure, field), node)
+							}
+							_ => unit(scene, node)?,
+						});
+					}
 					ValueNode::Call(S::new(path, span), arguments)
 				}
 				Some(_) => return scene.context.pass(Diagnostic::error().label(span.label())
@@ -218,19 +246,22 @@ pub fn unit(scene: &mut Scene, node: Node) -> crate::Result<ValueIndex> {
 				"-" => ValueNode::Unary(Unary::Negate, value),
 				"&" => ValueNode::Unary(Unary::Reference, value),
 				"*" => ValueNode::Unary(Unary::Dereference, value),
-				other => panic!("invalid unary operator: {}", other),
+				_ => return super::unexpected(scene.context,
+					scene.source, operator, "unary operator"),
 			}
 		}
 		"binary" => {
 			let left = unit(scene, node.child_by_field_name("left").unwrap())?;
 			let right = unit(scene, node.child_by_field_name("right").unwrap())?;
 			let operator = node.child_by_field_name("operator").unwrap();
-			let operator = &scene.source.text[operator.byte_range()];
-			let operator = Binary::parse(operator).unwrap_or_else(||
-				panic!("invalid binary operator: {}", operator));
+			let operator = match Binary::parse(&scene.source.text[operator.byte_range()]) {
+				Some(operator) => operator,
+				None => return super::unexpected(scene.context,
+					scene.source, operator, "binary operator"),
+			};
 			ValueNode::Binary(operator, left, right)
 		}
-		other => panic!("invalid value kind: {}", other),
+		_ => return super::unexpected(scene.context, scene.source, node, "value"),
 	};
 
 	Ok(scene.value(value, node))
@@ -259,6 +290,7 @@ fn path(scene: &mut Scene, node: Node) -> crate::Result<ValueIndex> {
 
 		match scene.symbols.resolve(scene.context, &path, &span) {
 			Some((path, SymbolKind::Variable)) |
+			Some((path, SymbolKind::Function)) |
 			Some((path, SymbolKind::Module)) => {
 				fields.reverse();
 				let path = S::new(ValueNode::Path(path), span);
@@ -281,3 +313,12 @@ fn path_fields(scene: &mut Scene, node: ValueIndex,
 	fields.into_iter().rev().fold(node, |field, (node, identifier)|
 		scene.value(ValueNode::Field(field, identifier), node))
 }
+
+/// Resolves the path of a file included with
+/// `Intrinsic.include` relative to the including file.
+fn include(scene: &mut Scene, node: Node) -> crate::Result<ValueIndex> {
+	let string = super::string(scene.context, scene.source, node)?;
+	let path = scene.source.path.join(string.node);
+	let path = path.to_string_lossy().to_string();
+	Ok(scene.value(ValueNode::String(path), node))
+}
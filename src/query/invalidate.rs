@@ -0,0 +1,119 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::context::Context;
+
+use super::{Key, Refresh};
+
+/// Invalidates queries and every query that transitively depends on
+/// them. Invalidated queries are recomputed in dependency order and
+/// the dependents of a query are only recomputed if its value has
+/// changed. Returns the queries that were recomputed.
+pub fn invalidate(context: &Context, keys: &[Key]) -> Vec<Key> {
+	let (mut stale, mut absent) = (HashMap::new(), HashSet::new());
+	let mut order = Vec::new();
+	let mut pending = keys.to_vec();
+	while let Some(key) = pending.pop() {
+		if stale.contains_key(&key) { continue; }
+		let dependents = table(context, &key).stale(&key);
+		if dependents.is_none() { absent.insert(key.clone()); }
+		let dependents = dependents.unwrap_or_default();
+		pending.extend(dependents.iter().cloned());
+		stale.insert(key.clone(), dependents);
+		order.push(key);
+	}
+
+	let mut changed: HashSet<Key> = keys.iter().cloned().collect();
+	let mut recomputed = Vec::new();
+	for key in topological(&stale, order) {
+		let table = table(context, &key);
+		if absent.contains(&key) {
+			continue;
+		} else if !changed.contains(&key) {
+			table.restore(&key);
+			continue;
+		}
+
+		if !table.contains(&key) {
+			compute(context, &key);
+		}
+
+		if table.refresh(&key) {
+			changed.extend(stale[&key].iter().cloned());
+		}
+		recomputed.push(key);
+	}
+	recomputed
+}
+
+/// Orders keys such that every key precedes its dependents.
+/// Keys that form a cycle are ordered after all other keys.
+fn topological(stale: &HashMap<Key, Vec<Key>>, order: Vec<Key>) -> Vec<Key> {
+	let mut degrees: HashMap<&Key, usize> = HashMap::new();
+	stale.values().flatten().for_each(|key| *degrees.entry(key).or_default() += 1);
+
+	let mut pending: Vec<_> = order.iter().filter(|key|
+		!degrees.contains_key(key)).cloned().collect();
+	let (mut sorted, mut visited) = (Vec::new(), HashSet::new());
+	while let Some(key) = pending.pop() {
+		for dependent in &stale[&key] {
+			let degree = degrees.get_mut(dependent).unwrap();
+			*degree -= 1;
+			if *degree == 0 {
+				pending.push(dependent.clone());
+			}
+		}
+
+		visited.insert(key.clone());
+		sorted.push(key);
+	}
+
+	sorted.extend(order.into_iter().filter(|key| !visited.contains(key)));
+	sorted
+}
+
+/// Returns the table that stores the result of a query.
+fn table<'a>(context: &'a Context, key: &Key) -> &'a dyn Refresh {
+	match key {
+		Key::TypeFunction(_) | Key::TypeVariable(_) => &context.type_contexts,
+		Key::Offsets(_) => &context.offsets,
+		Key::SymbolSize(_) | Key::LoadAddress(_) |
+		Key::VirtualAddress(_) => &context.address,
+		Key::Section(_) => &context.names,
+		Key::Generate(_) => &context.sections,
+		Key::Library(_) => &context.binaries,
+		Key::Include(_) => &context.included,
+		Key::SymbolFile(_) | Key::TraverseRoots |
+		Key::Analyze(_) => &context.unit,
+	}
+}
+
+/// Evaluates a query without a parent. Failures are
+/// recorded in the table and reported as diagnostics.
+fn compute(context: &Context, key: &Key) {
+	use crate::node::address;
+	let _ = match key {
+		Key::TypeFunction(path) => crate::inference::type_function(context,
+			None, path, None).map(std::mem::drop),
+		Key::TypeVariable(path) => crate::inference::type_variable(context,
+			None, path.clone(), None).map(std::mem::drop),
+		Key::Offsets(path) => crate::node::offsets(context,
+			None, path, None).map(std::mem::drop),
+		Key::SymbolSize(symbol) => address::size(context,
+			None, symbol, None).map(std::mem::drop),
+		Key::LoadAddress(symbol) => address::load(context,
+			None, symbol, None).map(std::mem::drop),
+		Key::VirtualAddress(symbol) => address::start(context,
+			None, symbol, None).map(std::mem::drop),
+		Key::Section(symbol) => address::section(context,
+			None, symbol, None).map(std::mem::drop),
+		Key::Generate(path) => crate::generate::x86::lower(context,
+			None, path, None).map(std::mem::drop),
+		Key::Analyze(path) => crate::node::function(context,
+			None, path, None).map(std::mem::drop),
+		Key::Library(path) => crate::node::library(context,
+			None, path, None).map(std::mem::drop),
+		Key::Include(path) => crate::evaluate::included(context,
+			None, path, None).map(std::mem::drop),
+		Key::SymbolFile(_) | Key::TraverseRoots => Ok(()),
+	};
+}
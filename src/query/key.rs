@@ -1,9 +1,13 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
 use crate::context::Context;
 use crate::error::Diagnostic;
 use crate::node::{FunctionPath, Path, Symbol};
 use crate::query::QueryError;
 
-#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub enum Key {
 	SymbolFile(std::path::PathBuf),
 	TypeFunction(FunctionPath),
@@ -13,8 +17,11 @@ pub enum Key {
 	SymbolSize(Symbol),
 	LoadAddress(Symbol),
 	VirtualAddress(Symbol),
+	Section(Symbol),
 	Generate(FunctionPath),
 	Analyze(FunctionPath),
+	Library(Path),
+	Include(std::path::PathBuf),
 }
 
 impl Key {
@@ -28,18 +35,59 @@ impl Key {
 			Key::SymbolSize(_) => "in deriving symbol size",
 			Key::LoadAddress(_) => "in deriving load address",
 			Key::VirtualAddress(_) => "in deriving virtual address",
+			Key::Section(_) => "in deriving output section",
 			Key::Generate(_) => "in generating function",
 			Key::Analyze(_) => "in analyzing function",
+			Key::Library(_) => "in reading library",
+			Key::Include(_) => "in reading included file",
+		}
+	}
+
+	pub fn variant(&self) -> &'static str {
+		match self {
+			Key::SymbolFile(_) => "SymbolFile",
+			Key::TypeFunction(_) => "TypeFunction",
+			Key::TypeVariable(_) => "TypeVariable",
+			Key::Offsets(_) => "Offsets",
+			Key::TraverseRoots => "TraverseRoots",
+			Key::SymbolSize(_) => "SymbolSize",
+			Key::LoadAddress(_) => "LoadAddress",
+			Key::VirtualAddress(_) => "VirtualAddress",
+			Key::Section(_) => "Section",
+			Key::Generate(_) => "Generate",
+			Key::Analyze(_) => "Analyze",
+			Key::Library(_) => "Library",
+			Key::Include(_) => "Include",
+		}
+	}
+}
+
+impl fmt::Display for Key {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}", self.variant())?;
+		match self {
+			Key::SymbolFile(path) | Key::Include(path) => write!(f, " {}", path.display()),
+			Key::TypeFunction(FunctionPath(path, _)) | Key::Generate(FunctionPath(path, _)) |
+			Key::Analyze(FunctionPath(path, _)) => write!(f, " {}", path),
+			Key::TypeVariable(path) | Key::Offsets(path) |
+			Key::Library(path) => write!(f, " {}", path),
+			Key::SymbolSize(symbol) | Key::LoadAddress(symbol) |
+			Key::VirtualAddress(symbol) | Key::Section(symbol) => write!(f, " {}", symbol),
+			Key::TraverseRoots => Ok(()),
 		}
 	}
 }
 
+/// Issues a diagnostic with the trace of a cycle. Queries in the
+/// trace without a span are included as notes.
 pub fn emit<T>(context: &Context, result: crate::Result<T>) {
 	if let Err(QueryError::Cycle(keys)) = result {
 		let diagnostic = Diagnostic::error().message("compilation cycle");
-		let diagnostic = keys.into_iter().rev().filter_map(|(key, span)|
-			span.map(|span| (key, span))).fold(diagnostic, |diagnostic, (key, span)|
-			diagnostic.label(span.other().with_message(key.action())));
+		let diagnostic = keys.into_iter().rev().fold(diagnostic, |diagnostic, (key, span)|
+			match span {
+				Some(span) => diagnostic.label(span.other().with_message(key.action())),
+				None => diagnostic.note(format!("{}: {}", key.action(), key)),
+			});
 		context.emit(diagnostic);
 	}
 }
@@ -1,5 +1,12 @@
+pub use invalidate::*;
 pub use key::*;
+pub use profile::*;
 pub use table::*;
 
+mod invalidate;
 mod key;
+mod profile;
 mod table;
+
+#[cfg(test)]
+mod tests;
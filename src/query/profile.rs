@@ -0,0 +1,94 @@
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use crate::context::Context;
+use crate::error::Diagnostic;
+
+use super::{Key, Table, Timing};
+
+/// Reports the time spent computing queries for each kind of query and
+/// for each query. Times exclude the queries that a query depends on.
+pub fn timings(context: &Context) {
+	let mut timings = Vec::new();
+	timings.extend(context.unit.timings());
+	timings.extend(context.type_contexts.timings());
+	timings.extend(context.sections.timings());
+	timings.extend(context.offsets.timings());
+	timings.extend(context.address.timings());
+	timings.extend(context.names.timings());
+	timings.extend(context.binaries.timings());
+	timings.extend(context.included.timings());
+
+	let mut variants: HashMap<_, Timing> = HashMap::new();
+	for (key, timing) in &timings {
+		let variant = variants.entry(key.variant()).or_default();
+		variant.time += timing.time;
+		variant.computed += timing.computed;
+		variant.hits += timing.hits;
+	}
+
+	let mut variants: Vec<_> = variants.into_iter().collect();
+	variants.sort_by(|(left, left_timing), (right, right_timing)|
+		(right_timing.time, left).cmp(&(left_timing.time, right)));
+	let mut timings: Vec<_> = timings.into_iter()
+		.map(|(key, timing)| (key.to_string(), timing)).collect();
+	timings.sort_by(|(left, left_timing), (right, right_timing)|
+		(right_timing.time, left).cmp(&(left_timing.time, right)));
+
+	eprintln!("{:>12} {:>9} {:>9}  query", "time", "computed", "hits");
+	variants.iter().for_each(|(variant, timing)| row(variant, timing));
+	eprintln!();
+	timings.iter().for_each(|(key, timing)| row(key, timing));
+}
+
+fn row(name: &str, Timing { time, computed, hits }: &Timing) {
+	let time = format!("{:.3}ms", time.as_secs_f64() * 1000.0);
+	eprintln!("{:>12} {:>9} {:>9}  {}", time, computed, hits, name);
+}
+
+/// Writes the dependencies between every query as a Graphviz graph.
+/// Edges are directed from a query to the queries that depend on it.
+pub fn graph(context: &Context, path: &Path) -> crate::Result<()> {
+	let mut edges = Vec::new();
+	self::edges(&context.unit, &mut edges);
+	self::edges(&context.type_contexts, &mut edges);
+	self::edges(&context.sections, &mut edges);
+	self::edges(&context.offsets, &mut edges);
+	self::edges(&context.address, &mut edges);
+	self::edges(&context.names, &mut edges);
+	self::edges(&context.binaries, &mut edges);
+	self::edges(&context.included, &mut edges);
+
+	let mut nodes: Vec<_> = edges.iter().flat_map(|(key, dependents)|
+		dependents.iter().chain(std::iter::once(key))).collect::<HashSet<_>>()
+		.into_iter().map(|key| (key.to_string(), format!("{:?}", key), key)).collect();
+	nodes.sort_by(|(left, left_debug, _), (right, right_debug, _)|
+		(left, left_debug).cmp(&(right, right_debug)));
+
+	let mut graph = String::from("digraph queries {\n");
+	let mut identifiers = HashMap::new();
+	for (index, (label, _, key)) in nodes.iter().enumerate() {
+		let label = label.replace('\\', "\\\\").replace('"', "\\\"");
+		graph += &format!("\t{} [label=\"{}\"];\n", index, label);
+		identifiers.insert(*key, index);
+	}
+
+	let identifiers = &identifiers;
+	let mut lines: Vec<_> = edges.iter().flat_map(|(key, dependents)| dependents
+		.iter().map(move |dependent| (identifiers[key], identifiers[dependent]))).collect();
+	lines.sort();
+	lines.dedup();
+	for (key, dependent) in lines {
+		graph += &format!("\t{} -> {};\n", key, dependent);
+	}
+
+	graph += "}\n";
+	std::fs::write(path, graph).map_err(|error| context.error(Diagnostic::error()
+		.message("failed to write query graph to file")
+		.note(format!("error: {}", error))))
+}
+
+fn edges<V>(table: &Table<V>, edges: &mut Vec<(Key, Vec<Key>)>) {
+	edges.extend(table.entries().into_iter()
+		.map(|(key, _, dependents)| (key, dependents)));
+}
@@ -1,11 +1,24 @@
+use std::cell::Cell;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread::ThreadId;
+use std::time::{Duration, Instant};
 
 use dashmap::DashMap;
+use parking_lot::{Condvar, Mutex};
 
 use crate::span::Span;
 
 use super::Key;
 
+/// The threads waiting on a query and the threads computing them.
+static WAITING: Mutex<Vec<(ThreadId, ThreadId, Key)>> = parking_lot::const_mutex(Vec::new());
+
+thread_local! {
+	/// The time spent computing queries nested in the current query.
+	static NESTED: Cell<Duration> = Cell::new(Duration::default());
+}
+
 #[derive(Debug)]
 pub enum QueryError {
 	Cycle(Vec<(Key, Option<Span>)>),
@@ -15,58 +28,257 @@ pub enum QueryError {
 #[derive(Debug)]
 pub struct Table<V> {
 	table: DashMap<Key, (Entry<V>, Vec<Key>)>,
+	stale: DashMap<Key, (Entry<V>, Vec<Key>)>,
+	loaded: DashMap<Key, ()>,
+	hits: AtomicUsize,
+	misses: AtomicUsize,
+	timings: DashMap<Key, Timing>,
+	lock: Mutex<()>,
+	condvar: Condvar,
+}
+
+/// The time spent computing a query excluding the queries it depends
+/// on, the number of times it was computed and the number reused.
+#[derive(Debug, Default, Clone)]
+pub struct Timing {
+	pub time: Duration,
+	pub computed: usize,
+	pub hits: usize,
+}
+
+/// A table whose entries can be invalidated and recomputed
+/// without invalidating dependents if the value is unchanged.
+pub trait Refresh {
+	/// Moves an entry aside such that it is recomputed when next
+	/// queried. Returns the dependents if the entry exists.
+	fn stale(&self, key: &Key) -> Option<Vec<Key>>;
+
+	/// Restores a stale entry whose dependencies are unchanged.
+	fn restore(&self, key: &Key);
+
+	/// Compares a recomputed entry against its stale entry and
+	/// returns whether the value has changed. Spans are compared
+	/// without their positions as edits displace the spans of
+	/// unchanged values. The dependents of an unchanged entry
+	/// are retained as they remain valid.
+	fn refresh(&self, key: &Key) -> bool;
+
+	/// Returns whether the table contains a current entry.
+	fn contains(&self, key: &Key) -> bool;
 }
 
 impl<V> Table<V> {
+	/// Returns the value of a query and computes it if absent. A query
+	/// that is being computed by another thread is waited upon. Waiting
+	/// on a query that is waiting on this thread is reported as a cycle.
 	pub fn scope<F>(&self, parent: Option<Key>, key: Key, span: Option<Span>,
 					function: F) -> Result<Arc<V>, QueryError>
 		where F: FnOnce() -> Result<V, QueryError> {
-		if !self.table.contains_key(&key) {
-			self.table.insert(key.clone(), (Entry::Pending, Vec::new()));
-			self.table.insert(key.clone(), (match function() {
-				Ok(value) => Entry::Value(Arc::new(value)),
-				Err(QueryError::Failure) => Entry::Failure,
-				Err(QueryError::Cycle(mut keys)) => {
-					keys.push((key.clone(), span));
-					self.table.insert(key, (Entry::Failure, Vec::new()));
-					return Err(QueryError::Cycle(keys));
+		let thread = std::thread::current().id();
+		let (mut function, mut computed) = (Some(function), false);
+		let mut guard = self.lock.lock();
+		loop {
+			let owner = self.table.get(&key).map(|entry| match entry.value() {
+				(Entry::Pending(owner), _) => Some(*owner),
+				_ => None,
+			});
+
+			match owner {
+				Some(None) => break,
+				Some(Some(owner)) if owner == thread =>
+					return Err(QueryError::Cycle(vec![(key, span)])),
+				Some(Some(owner)) => {
+					if !wait(thread, owner, &key) {
+						return Err(QueryError::Cycle(vec![(key, span)]));
+					}
+
+					// Time spent waiting is excluded from the
+					// time of the query that is waiting.
+					let start = Instant::now();
+					self.condvar.wait(&mut guard);
+					NESTED.with(|nested| nested.set(nested.get() + start.elapsed()));
+					WAITING.lock().retain(|(waiter, _, _)| *waiter != thread);
 				}
-			}, Vec::new()));
+				None => {
+					self.misses.fetch_add(1, Ordering::Relaxed);
+					self.table.insert(key.clone(), (Entry::Pending(thread), Vec::new()));
+					std::mem::drop(guard);
+
+					let (start, outer) = (Instant::now(), NESTED.with(|nested|
+						nested.replace(Duration::default())));
+					let result = function.take().unwrap()();
+					let elapsed = start.elapsed();
+					let nested = NESTED.with(|nested| nested.replace(outer + elapsed));
+					let mut timing = self.timings.entry(key.clone()).or_default();
+					timing.time += elapsed - nested;
+					timing.computed += 1;
+					std::mem::drop(timing);
+					computed = true;
+
+					let (entry, cycle) = match result {
+						Ok(value) => (Entry::Value(Arc::new(value)), None),
+						Err(QueryError::Failure) => (Entry::Failure, None),
+						Err(QueryError::Cycle(mut keys)) => {
+							keys.push((key.clone(), span.clone()));
+							(Entry::Failure, Some(keys))
+						}
+					};
+
+					guard = self.lock.lock();
+					self.table.insert(key.clone(), (entry, Vec::new()));
+					WAITING.lock().retain(|(_, _, other)| *other != key);
+					self.condvar.notify_all();
+					if let Some(keys) = cycle {
+						return Err(QueryError::Cycle(keys));
+					}
+				}
+			}
+		}
+
+		if self.loaded.remove(&key).is_some() {
+			self.hits.fetch_add(1, Ordering::Relaxed);
+		}
+
+		if !computed {
+			self.timings.entry(key.clone()).or_default().hits += 1;
 		}
 
 		let mut entry = self.table.get_mut(&key).unwrap();
 		let (entry, dependents) = entry.value_mut();
-		dependents.extend(parent);
+		merge(dependents, parent.into_iter().collect());
 		match entry {
 			Entry::Value(value) => Ok(value.clone()),
 			Entry::Failure => Err(QueryError::Failure),
-			Entry::Pending => Err(QueryError::Cycle(vec![(key, span)])),
+			Entry::Pending(_) => unreachable!(),
 		}
 	}
 
+	/// Returns the value of a query and removes it once computed such
+	/// that it is recomputed when next queried. The entry is removed
+	/// under the lock as waiting threads read it while holding the lock.
 	pub fn ephemeral<F>(&self, parent: Option<Key>, key: Key, span: Option<Span>,
 						function: F) -> Result<Arc<V>, QueryError>
 		where F: FnOnce() -> Result<V, QueryError> {
 		let result = self.scope(parent, key.clone(), span, function);
-		self.invalidate(&key);
+		let _guard = self.lock.lock();
+		self.table.remove_if(&key, |_, (entry, _)|
+			!matches!(entry, Entry::Pending(_)));
 		result
 	}
 
-	pub fn invalidate(&self, key: &Key) {
-		self.table.remove(key).unwrap_or_else(||
-			panic!("key: {:?}, absent from query table", key));
+	pub fn clear(&self) {
+		self.table.clear();
+		self.stale.clear();
+		self.loaded.clear();
+	}
+
+	/// Inserts a value restored from a previous compilation.
+	pub fn load(&self, key: Key, value: V, dependents: Vec<Key>) {
+		self.loaded.insert(key.clone(), ());
+		self.table.insert(key, (Entry::Value(Arc::new(value)), dependents));
+	}
+
+	/// Returns every entry with its value if it succeeded and its dependents.
+	pub fn entries(&self) -> Vec<(Key, Option<Arc<V>>, Vec<Key>)> {
+		self.table.iter().map(|entry| {
+			let (value, dependents) = entry.value();
+			let value = match value {
+				Entry::Value(value) => Some(value.clone()),
+				Entry::Pending(_) | Entry::Failure => None,
+			};
+			(entry.key().clone(), value, dependents.clone())
+		}).collect()
+	}
+
+	/// Returns the number of loaded values that have been queried and
+	/// the number of values that have been computed since last called.
+	pub fn statistics(&self) -> (usize, usize) {
+		(self.hits.swap(0, Ordering::Relaxed), self.misses.swap(0, Ordering::Relaxed))
+	}
+
+	/// Returns the timing of every query since last called.
+	pub fn timings(&self) -> Vec<(Key, Timing)> {
+		let keys: Vec<_> = self.timings.iter().map(|entry| entry.key().clone()).collect();
+		keys.into_iter().filter_map(|key| self.timings.remove(&key)).collect()
+	}
+}
+
+impl<V: PartialEq> Refresh for Table<V> {
+	fn stale(&self, key: &Key) -> Option<Vec<Key>> {
+		let (key, (entry, dependents)) = self.table.remove(key)?;
+		self.stale.insert(key, (entry, dependents.clone()));
+		Some(dependents)
+	}
+
+	fn restore(&self, key: &Key) {
+		if let Some((key, (entry, dependents))) = self.stale.remove(key) {
+			let mut current = self.table.entry(key).or_insert((entry, Vec::new()));
+			merge(&mut current.1, dependents);
+		}
+	}
+
+	fn refresh(&self, key: &Key) -> bool {
+		let (stale, dependents) = match self.stale.remove(key) {
+			Some((_, stale)) => stale,
+			None => return true,
+		};
+
+		let mut current = match self.table.get_mut(key) {
+			Some(current) => current,
+			None => return true,
+		};
+
+		let (entry, current) = current.value_mut();
+		let changed = match (entry, &stale) {
+			(Entry::Value(left), Entry::Value(right)) =>
+				!crate::span::displaced(left, right),
+			(Entry::Failure, Entry::Failure) => false,
+			_ => true,
+		};
+
+		if !changed { merge(current, dependents); }
+		changed
+	}
+
+	fn contains(&self, key: &Key) -> bool {
+		self.table.contains_key(key)
 	}
 }
 
 impl<V> Default for Table<V> {
 	fn default() -> Self {
-		Table { table: DashMap::new() }
+		let (hits, misses) = (AtomicUsize::new(0), AtomicUsize::new(0));
+		let (table, stale, loaded) = (DashMap::new(), DashMap::new(), DashMap::new());
+		let (timings, lock, condvar) = (DashMap::new(), Mutex::new(()), Condvar::new());
+		Table { table, stale, loaded, hits, misses, timings, lock, condvar }
 	}
 }
 
+fn merge(dependents: &mut Vec<Key>, other: Vec<Key>) {
+	other.into_iter().for_each(|key| if !dependents.contains(&key) {
+		dependents.push(key);
+	});
+}
+
+/// Registers a thread as waiting on a query computed by another
+/// thread. Returns false if the other thread is waiting on this
+/// thread, directly or transitively, as neither would progress.
+fn wait(thread: ThreadId, owner: ThreadId, key: &Key) -> bool {
+	let mut waiting = WAITING.lock();
+	let mut current = Some(owner);
+	while let Some(other) = current {
+		if other == thread { return false; }
+		current = waiting.iter().find(|(waiter, _, _)|
+			*waiter == other).map(|(_, owner, _)| *owner);
+	}
+
+	waiting.push((thread, owner, key.clone()));
+	true
+}
+
 #[derive(Debug)]
 enum Entry<V> {
-	Pending,
+	Pending(ThreadId),
 	Failure,
 	Value(Arc<V>),
 }
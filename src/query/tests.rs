@@ -0,0 +1,139 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::context::Context;
+use crate::node::{FunctionPath, Identifier, Path};
+
+use super::{Key, Refresh};
+
+const SOURCE: &str = "
+module Main
+	data Point
+		x: u32
+		y: u32
+
+	root fn main() u64
+		helper() + 1
+
+	root fn origin() u32
+		let point = Point ~ x = 1, y = 2
+		point.y
+
+	fn helper() u64
+		2
+";
+
+fn compile(text: &str) -> Context {
+	static FILES: AtomicUsize = AtomicUsize::new(0);
+	let file = FILES.fetch_add(1, Ordering::SeqCst);
+	let name = format!("lucent-query-{}-{}.lc", std::process::id(), file);
+	let path = std::env::temp_dir().join(name);
+	std::fs::write(&path, text).unwrap();
+
+	let context = Context::default();
+	crate::parse::parse(&context, &path).unwrap();
+	std::fs::remove_file(&path).unwrap();
+	for function in &["main", "origin", "helper"] {
+		crate::generate::x86::lower(&context, None, &function_path(function), None).unwrap();
+	}
+	context
+}
+
+fn path(elements: &[&str]) -> Path {
+	Path(elements.iter().map(|element| Identifier(element.to_string())).collect())
+}
+
+fn function_path(function: &str) -> FunctionPath {
+	FunctionPath(path(&["Main", function]), 0)
+}
+
+/// Replaces the body of a function with its definition in another
+/// context and returns the queries that read the function directly.
+fn replace(context: &Context, other: &Context, function: &str) -> Vec<Key> {
+	let path = function_path(function);
+	let FunctionPath(name, _) = &path;
+	let functions = other.functions.get(name).unwrap().clone();
+	context.functions.insert(name.clone(), functions);
+	vec![Key::Analyze(path.clone()), Key::TypeFunction(path.clone()), Key::Generate(path)]
+}
+
+fn generated(recomputed: &[Key]) -> Vec<&str> {
+	let mut functions: Vec<_> = recomputed.iter().filter_map(|key| match key {
+		Key::Generate(FunctionPath(Path(elements), _)) =>
+			elements.last().map(|Identifier(element)| element.as_str()),
+		_ => None,
+	}).collect();
+	functions.sort();
+	functions
+}
+
+#[test]
+fn changed_function_lowers_only_function() {
+	let context = &compile(SOURCE);
+	let helper = crate::generate::x86::lower(context, None, &function_path("helper"), None).unwrap();
+	let other = &compile(&SOURCE.replace("\t\t2\n", "\t\t3\n"));
+	let recomputed = super::invalidate(context, &replace(context, other, "helper"));
+
+	assert_eq!(generated(&recomputed), ["helper"]);
+	for function in &["main", "origin", "helper"] {
+		let key = Key::Generate(function_path(function));
+		assert!(context.sections.contains(&key));
+	}
+
+	let changed = crate::generate::x86::lower(context, None, &function_path("helper"), None).unwrap();
+	assert_ne!(helper.bytes, changed.bytes);
+}
+
+#[test]
+fn unchanged_function_is_cut_off() {
+	let context = &compile(SOURCE);
+	let other = &compile(SOURCE);
+	let recomputed = super::invalidate(context, &replace(context, other, "helper"));
+	assert_eq!(generated(&recomputed), ["helper"]);
+	assert!(!recomputed.contains(&Key::Generate(function_path("main"))));
+}
+
+#[test]
+fn unchanged_offsets_are_cut_off() {
+	let context = &compile(SOURCE);
+	let key = Key::Offsets(path(&["Main", "Point"]));
+	let recomputed = super::invalidate(context, &[key.clone()]);
+	assert_eq!(recomputed, [key]);
+	assert!(context.sections.contains(&Key::Generate(function_path("origin"))));
+}
+
+#[test]
+fn changed_offsets_invalidate_dependents() {
+	let context = &compile(SOURCE);
+	let other = &compile(&SOURCE.replace("x: u32", "x: u64"));
+	let structure = path(&["Main", "Point"]);
+	let (_, replacement) = other.structures.remove(&structure).unwrap();
+	context.structures.insert(structure.clone(), replacement);
+
+	let recomputed = super::invalidate(context, &[Key::Offsets(structure)]);
+	assert_eq!(generated(&recomputed), ["origin"]);
+	assert!(context.sections.contains(&Key::Generate(function_path("main"))));
+}
+
+#[test]
+fn invalidation_is_transitive() {
+	let context = &compile(SOURCE);
+	let other = &compile(&SOURCE.replace("\t\t2\n", "\t\t2 + 1\n"));
+	let path = function_path("helper");
+	replace(context, other, "helper");
+
+	let recomputed = super::invalidate(context, &[Key::TypeFunction(path.clone())]);
+	assert_eq!(recomputed.first(), Some(&Key::TypeFunction(path)));
+	assert_eq!(generated(&recomputed), ["helper"]);
+}
+
+#[test]
+fn displaced_function_is_cut_off() {
+	let source = SOURCE.replace("\t\tpoint.y\n", "\t\tlet address = &point\n\t\tpoint.y\n");
+	let context = &compile(&source);
+	let other = &compile(&source.replace("\troot fn origin", "\t// displaced\n\troot fn origin"));
+	let path = function_path("origin");
+	replace(context, other, "origin");
+
+	let recomputed = super::invalidate(context, &[Key::TypeFunction(path.clone())]);
+	assert_eq!(recomputed, [Key::TypeFunction(path)]);
+}
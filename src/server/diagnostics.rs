@@ -0,0 +1,57 @@
+use std::path::PathBuf;
+
+use codespan::FileId;
+use codespan_reporting::diagnostic::{Label, LabelStyle, Severity};
+use serde_json::{json, Value};
+
+use crate::context::Files;
+use crate::error::Diagnostic;
+
+use super::protocol;
+
+/// Converts a diagnostic into its protocol representation. The primary
+/// label is the range of the diagnostic and other labels are related
+/// information. Returns the path of the file that contains the primary
+/// label or `None` if no label is within a source file.
+pub fn diagnostic(files: &Files, Diagnostic(diagnostic): &Diagnostic) -> (Option<PathBuf>, Value) {
+	let labels: Vec<_> = diagnostic.labels.iter()
+		.filter(|label| files.path(label.file_id).is_some()).collect();
+	let primary = labels.iter().position(|label| label.style == LabelStyle::Primary)
+		.or_else(|| labels.first().map(|_| 0));
+	let location = |label: &Label<FileId>| json!({
+		"uri": protocol::uri(files.path(label.file_id).unwrap()),
+		"range": protocol::range(files.source(label.file_id), label.range.clone()),
+	});
+
+	let related: Vec<_> = labels.iter().enumerate()
+		.filter(|(index, _)| Some(*index) != primary).map(|(_, label)| json!({
+			"location": location(label),
+			"message": match label.message.is_empty() {
+				true => &diagnostic.message,
+				false => &label.message,
+			},
+		})).collect();
+
+	let primary = primary.map(|index| labels[index]);
+	let mut message = diagnostic.message.clone();
+	primary.filter(|label| !label.message.is_empty())
+		.into_iter().for_each(|label| message += &format!("\n{}", label.message));
+	diagnostic.notes.iter().for_each(|note| message += &format!("\n{}", note));
+
+	let severity = match diagnostic.severity {
+		Severity::Bug | Severity::Error => 1,
+		Severity::Warning => 2,
+		Severity::Note => 3,
+		Severity::Help => 4,
+	};
+
+	let range = primary.map(|label| location(label)["range"].clone());
+	let path = primary.and_then(|label| files.path(label.file_id)).map(|path| path.to_owned());
+	(path, json!({
+		"range": range.unwrap_or_else(|| protocol::range("", 0..0)),
+		"severity": severity,
+		"source": "lucent",
+		"message": message,
+		"relatedInformation": related,
+	}))
+}
@@ -0,0 +1,116 @@
+use std::sync::Arc;
+
+use codespan::FileId;
+
+use crate::context::Context;
+use crate::node::*;
+
+/// Describes the symbol or field at an offset or the inferred type of the value
+/// at an offset. Symbols that are present in the binary include their address.
+pub fn hover(context: &Context, file: FileId, offset: usize) -> Option<String> {
+	match crate::node::target(context, file, offset) {
+		Some((Target::Symbol(path), _)) => describe(context, &path),
+		Some((Target::Field(path, field), _)) => self::field(context, &path, &field),
+		None => value(context, file, offset),
+	}
+}
+
+fn describe(context: &Context, path: &Path) -> Option<String> {
+	let mut lines = Vec::new();
+	let functions = context.functions.get(path).map(|functions| functions.clone());
+	if let Some(functions) = functions {
+		for (kind, function) in functions.iter().enumerate() {
+			lines.push(signature(path, function));
+			let function = FunctionPath(path.clone(), kind);
+			if crate::node::present(context, None, &function, None).unwrap_or(false) {
+				lines.extend(address(context, &Symbol::Function(function.clone())));
+			}
+
+			let callers = crate::node::callers(context, &function);
+			let callers: Vec<_> = callers.iter().map(|FunctionPath(path, _)|
+				path.to_string()).collect();
+			if !callers.is_empty() {
+				lines.push(format!("called by: {}", callers.join(", ")));
+			}
+		}
+	} else if context.statics.contains_key(path) {
+		let node_type = crate::inference::type_variable(context, None, path.clone(), None);
+		lines.push(format!("static {}: {}", path, node_type.ok()?));
+		lines.extend(address(context, &Symbol::Variable(path.clone())));
+	} else if context.structures.contains_key(path) {
+		lines.push(format!("data {}", path));
+		let offsets = crate::node::offsets(context, None, path, None);
+		lines.extend(offsets.map(|offsets| format!("size: {} bytes", offsets.size)));
+	} else if context.modules.contains_key(path) {
+		lines.push(format!("module {}", path));
+	} else if context.libraries.contains_key(path) {
+		lines.push(format!("library {}", path));
+	} else {
+		lines.push(format!("intrinsic {}", path));
+	}
+
+	let (first, other) = lines.split_first()?;
+	Some(std::iter::once(format!("```lucent\n{}\n```", first))
+		.chain(other.iter().cloned()).collect::<Vec<_>>().join("\n\n"))
+}
+
+fn field(context: &Context, path: &Path, field: &Identifier) -> Option<String> {
+	let node_type = context.structures.get(path)?.fields.get(field)?.node.clone();
+	let offsets = crate::node::offsets(context, None, path, None).ok();
+	let offset = offsets.as_ref().and_then(|offsets| offsets.fields.get(field));
+	Some(format!("```lucent\n{}.{}: {}\n```", path, field, node_type) + &offset
+		.map(|offset| format!("\n\noffset: {} bytes", offset)).unwrap_or_default())
+}
+
+fn signature(path: &Path, function: &Arc<Function>) -> String {
+	let parameters: Vec<_> = function.parameters.iter().map(|parameter|
+		match &parameter.node {
+			Parameter::Register(register) => format!("${}", register),
+			Parameter::Variable(variable, node_type) =>
+				format!("{}: {}", variable.node.0, node_type),
+		}).collect();
+
+	let return_type = match &function.return_type.node {
+		ReturnType::Register(register) => format!("${}", register),
+		ReturnType::Type(node_type) => node_type.to_string(),
+	};
+	format!("fn {}({}) {}", path, parameters.join(", "), return_type)
+}
+
+fn address(context: &Context, symbol: &Symbol) -> Option<String> {
+	let address = crate::node::address::start(context, None, symbol, None).ok()?;
+	let load = crate::node::address::load(context, None, symbol, None).ok()?;
+	let size = crate::node::address::size(context, None, symbol, None).ok()?;
+	Some(format!("address: {:#x}, load: {:#x}, size: {} bytes", address, load, size))
+}
+
+/// Returns the type of the innermost value that contains an offset.
+fn value(context: &Context, file: FileId, offset: usize) -> Option<String> {
+	let functions: Vec<_> = context.functions.iter().flat_map(|entry|
+		entry.value().iter().cloned().enumerate().map(|(kind, function)|
+			(FunctionPath(entry.key().clone(), kind), function)).collect::<Vec<_>>()).collect();
+	let (path, function) = functions.into_iter().find(|(_, function)|
+		function.value.values.get(function.value.root).filter(|node|
+			node.span.contains(file, offset)).is_some() || function.parameters
+			.iter().any(|parameter| parameter.span.contains(file, offset)))?;
+
+	for parameter in &function.parameters {
+		if let Parameter::Variable(variable, node_type) = &parameter.node {
+			if variable.span.contains(file, offset) {
+				return Some(format!("```lucent\n{}: {}\n```", variable.node.0, node_type));
+			}
+		}
+	}
+
+	let types = crate::inference::type_function(context, None, &path, None).ok()?;
+	let (index, node) = function.value.values.iter().enumerate()
+		.filter(|(_, node)| node.span.contains(file, offset))
+		.min_by_key(|(_, node)| node.span.range().len())?;
+	let node_type = match &node.node {
+		ValueNode::Let(variable, _, _) if variable.span.contains(file, offset) =>
+			return types.variables.get(&variable.node).map(|node_type|
+				format!("```lucent\n{}: {}\n```", variable.node.0, node_type)),
+		_ => types.types.get(&index)?,
+	};
+	Some(format!("```lucent\n{}\n```", node_type))
+}
@@ -0,0 +1,10 @@
+pub use diagnostics::*;
+pub use hover::*;
+pub use navigate::*;
+pub use serve::*;
+
+mod diagnostics;
+mod hover;
+mod navigate;
+mod protocol;
+mod serve;
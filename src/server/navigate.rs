@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use codespan::FileId;
+use serde_json::{json, Value};
+
+use crate::context::Context;
+use crate::node::{FunctionPath, Identifier, Item, Symbol, Target};
+use crate::span::{S, Span};
+
+use super::protocol;
+
+/// The protocol kinds of document symbols.
+const MODULE: u8 = 2;
+const FUNCTION: u8 = 12;
+const VARIABLE: u8 = 13;
+
+/// Returns the definition of the symbol or field named at an offset.
+pub fn definition(context: &Context, file: FileId, offset: usize) -> Option<Span> {
+	match crate::node::target(context, file, offset)?.0 {
+		Target::Symbol(path) => context.definitions.read().get(&path).cloned(),
+		Target::Field(path, field) => context.structures.get(&path)?.identifiers.get(&field).cloned(),
+	}
+}
+
+/// Returns the locations of every identifier that names the symbol
+/// or field at an offset optionally including its definition.
+pub fn references(context: &Context, file: FileId, offset: usize, declaration: bool) -> Value {
+	let target = crate::node::target(context, file, offset);
+	let definition = definition(context, file, offset);
+	let references = target.map(|(target, _)| crate::node::references(context, &target));
+	references.unwrap_or_default().iter().filter(|span| declaration || Some(*span) != definition.as_ref())
+		.filter_map(|span| super::location(context, span)).collect::<Vec<_>>().into()
+}
+
+/// Returns the range and text of the identifier at an offset if it can be renamed.
+pub fn prepare(context: &Context, file: FileId, offset: usize) -> Option<Value> {
+	let (_, span) = crate::node::target(context, file, offset)?;
+	let files = context.files.read();
+	let text = files.source(file);
+	Some(json!({"range": protocol::range(text, span.range()), "placeholder": &text[span.range()]}))
+}
+
+/// Converts replacements into edits grouped by the document they change.
+pub fn edit(context: &Context, replacements: &[(Span, String)]) -> Value {
+	let files = context.files.read();
+	let mut changes: HashMap<_, Vec<_>> = HashMap::new();
+	for (span, text) in replacements {
+		if let Some(path) = files.path(span.file()) {
+			let range = protocol::range(files.source(span.file()), span.range());
+			changes.entry(protocol::uri(path)).or_default()
+				.push(json!({"range": range, "newText": text}));
+		}
+	}
+	json!({"changes": changes})
+}
+
+/// Returns the modules, functions and static variables
+/// in a file nested within the modules that contain them.
+pub fn symbols(context: &Context, file: FileId) -> Value {
+	let text = context.files.read().source(file).clone();
+	let symbol = |identifier: &S<Identifier>, kind| {
+		if identifier.span.file() != file { return None; }
+		let range = protocol::range(&text, identifier.span.range());
+		Some(json!({"name": identifier.node.to_string(), "kind": kind,
+			"range": range, "selectionRange": range, "children": []}))
+	};
+
+	let mut modules = vec![(None, Vec::new())];
+	for item in context.items.read().iter() {
+		let (_, symbols) = modules.last_mut().unwrap();
+		match item {
+			Item::Symbol(Symbol::Module(path)) => {
+				let module = context.modules.get(path).unwrap();
+				modules.push((symbol(&module.identifier, MODULE), Vec::new()));
+			}
+			Item::Symbol(Symbol::Function(FunctionPath(path, kind))) |
+			Item::Import(Symbol::Function(FunctionPath(path, kind))) => {
+				let function = context.functions.get(path).unwrap()[*kind].clone();
+				symbols.extend(symbol(&function.identifier, FUNCTION));
+			}
+			Item::Symbol(Symbol::Variable(path)) |
+			Item::Import(Symbol::Variable(path)) => {
+				let variable = context.statics.get(path).unwrap();
+				symbols.extend(symbol(&variable.identifier, VARIABLE));
+			}
+			Item::Import(Symbol::Module(_)) => (),
+			Item::ModuleEnd => {
+				let (module, children) = modules.pop().unwrap();
+				let (_, symbols) = modules.last_mut().unwrap();
+				match module {
+					None => symbols.extend(children),
+					Some(mut module) => {
+						module["children"] = children.into();
+						symbols.push(module);
+					}
+				}
+			}
+		}
+	}
+
+	let (_, symbols) = modules.swap_remove(0);
+	symbols.into()
+}
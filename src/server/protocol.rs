@@ -0,0 +1,90 @@
+use std::io::{self, BufRead, Write};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use serde_json::{json, Value};
+
+/// Reads a message framed with a content length header.
+/// Returns `None` if the input has ended.
+pub fn read(input: &mut impl BufRead) -> io::Result<Option<Value>> {
+	let mut length = None;
+	loop {
+		let line = &mut String::new();
+		if input.read_line(line)? == 0 { return Ok(None); }
+		let line = line.trim_end();
+		if line.is_empty() { break; }
+		if let Some(value) = line.strip_prefix("Content-Length:") {
+			length = value.trim().parse().ok();
+		}
+	}
+
+	let invalid = |error| io::Error::new(io::ErrorKind::InvalidData, error);
+	let length = length.ok_or_else(|| invalid("missing content length".to_string()))?;
+	let mut bytes = vec![0; length];
+	input.read_exact(&mut bytes)?;
+	serde_json::from_slice(&bytes).map(Some)
+		.map_err(|error| invalid(error.to_string()))
+}
+
+pub fn write(output: &mut impl Write, message: &Value) -> io::Result<()> {
+	let message = message.to_string();
+	write!(output, "Content-Length: {}\r\n\r\n{}", message.len(), message)?;
+	output.flush()
+}
+
+/// Converts a byte offset into a line and a character in UTF-16 code units.
+pub fn position(text: &str, offset: usize) -> Value {
+	let offset = offset.min(text.len());
+	let start = text[..offset].rfind('\n').map(|line| line + 1).unwrap_or(0);
+	let line = text[..start].matches('\n').count();
+	let character: usize = text[start..offset].chars().map(char::len_utf16).sum();
+	json!({"line": line, "character": character})
+}
+
+pub fn range(text: &str, range: Range<usize>) -> Value {
+	json!({"start": position(text, range.start), "end": position(text, range.end)})
+}
+
+/// Converts a line and a character in UTF-16 code units into a byte offset.
+pub fn offset(text: &str, position: &Value) -> Option<usize> {
+	let line = position["line"].as_u64()? as usize;
+	let character = position["character"].as_u64()? as usize;
+	let start = match line {
+		0 => 0,
+		_ => text.match_indices('\n').nth(line - 1)?.0 + 1,
+	};
+
+	let mut units = 0;
+	for (index, other) in text[start..].char_indices() {
+		if units >= character || other == '\n' { return Some(start + index); }
+		units += other.len_utf16();
+	}
+	Some(text.len())
+}
+
+/// Converts a file URI into a path. Returns `None` for other schemes.
+pub fn path(uri: &str) -> Option<PathBuf> {
+	let (mut bytes, mut path) = (uri.strip_prefix("file://")?.bytes(), Vec::new());
+	while let Some(byte) = bytes.next() {
+		path.push(match byte {
+			b'%' => {
+				let digits = [bytes.next()?, bytes.next()?];
+				u8::from_str_radix(std::str::from_utf8(&digits).ok()?, 16).ok()?
+			}
+			byte => byte,
+		});
+	}
+	String::from_utf8(path).ok().map(PathBuf::from)
+}
+
+pub fn uri(path: &Path) -> String {
+	let mut uri = String::from("file://");
+	for byte in path.to_string_lossy().bytes() {
+		match byte {
+			b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' |
+			b'/' | b'-' | b'_' | b'.' | b'~' => uri.push(byte as char),
+			byte => uri += &format!("%{:02X}", byte),
+		}
+	}
+	uri
+}
@@ -0,0 +1,235 @@
+use std::collections::{HashMap, HashSet};
+use std::io::{self, Write};
+use std::panic::AssertUnwindSafe;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use codespan::FileId;
+use serde_json::{json, Value};
+
+use crate::context::{Context, Files};
+use crate::error::Diagnostic;
+use crate::node::{FunctionPath, Symbol};
+use crate::options::Options;
+use crate::span::Span;
+
+use super::protocol;
+
+/// The error code for a request with an unsupported method.
+const METHOD_NOT_FOUND: i64 = -32601;
+/// The error code for a request with invalid parameters.
+const INVALID_PARAMS: i64 = -32602;
+/// The error code for a request that failed within the compiler.
+const INTERNAL_ERROR: i64 = -32603;
+
+struct Server {
+	root: PathBuf,
+	threads: usize,
+	documents: HashMap<PathBuf, Arc<str>>,
+	published: HashSet<PathBuf>,
+	context: Context,
+}
+
+/// Serves the language server protocol over the standard input and output.
+/// Sources are compiled from the root file whenever a document changes
+/// and the text of open documents is used in place of the file system.
+/// Queries are retained between compilations as in watch mode.
+pub fn serve(options: &Options) -> io::Result<()> {
+	let (input, output) = (io::stdin(), io::stdout());
+	let (input, output) = (&mut input.lock(), &mut output.lock());
+	let root = options.path.canonicalize().unwrap_or_else(|_| options.path.clone());
+	let (documents, published) = (HashMap::new(), HashSet::new());
+	let server = &mut Server { root, threads: options.threads,
+		documents, published, context: Context::default() };
+
+	while let Some(message) = protocol::read(input)? {
+		let method = match message["method"].as_str() {
+			Some("exit") => break,
+			Some(method) => method,
+			None => continue,
+		};
+
+		let parameters = &message["params"];
+		match message.get("id") {
+			None => server.notify(output, method, parameters)?,
+			Some(identifier) => {
+				let result = std::panic::catch_unwind(AssertUnwindSafe(||
+					server.request(method, parameters))).unwrap_or_else(|_|
+					Err((INTERNAL_ERROR, "internal compiler error".to_string())));
+				protocol::write(output, &match result {
+					Ok(result) => json!({"jsonrpc": "2.0", "id": identifier, "result": result}),
+					Err((code, message)) => json!({"jsonrpc": "2.0", "id": identifier,
+						"error": {"code": code, "message": message}}),
+				})?;
+			}
+		}
+	}
+	Ok(())
+}
+
+impl Server {
+	fn request(&self, method: &str, parameters: &Value) -> Result<Value, (i64, String)> {
+		let context = &self.context;
+		let position = || self.position(parameters);
+		Ok(match method {
+			"initialize" => json!({
+				"capabilities": {
+					"textDocumentSync": 1,
+					"hoverProvider": true,
+					"definitionProvider": true,
+					"documentSymbolProvider": true,
+					"referencesProvider": true,
+					"renameProvider": {"prepareProvider": true},
+				},
+				"serverInfo": {"name": "lucent"},
+			}),
+			"shutdown" => Value::Null,
+			"textDocument/hover" => position().and_then(|(file, offset)|
+				super::hover(context, file, offset)).map(|hover| json!({
+				"contents": {"kind": "markdown", "value": hover},
+			})).unwrap_or(Value::Null),
+			"textDocument/definition" => position().and_then(|(file, offset)|
+				super::definition(context, file, offset))
+				.and_then(|span| location(context, &span)).unwrap_or(Value::Null),
+			"textDocument/references" => position().map(|(file, offset)| {
+				let declaration = parameters["context"]["includeDeclaration"].as_bool();
+				super::references(context, file, offset, declaration.unwrap_or(true))
+			}).unwrap_or(Value::Null),
+			"textDocument/prepareRename" => position().and_then(|(file, offset)|
+				super::prepare(context, file, offset)).unwrap_or(Value::Null),
+			"textDocument/rename" => {
+				let name = parameters["newName"].as_str().unwrap_or_default();
+				let target = position().and_then(|(file, offset)|
+					crate::node::target(context, file, offset)).map(|(target, _)| target);
+				let target = target.ok_or_else(|| (INVALID_PARAMS, "no symbol to rename".to_string()))?;
+				let replacements = crate::node::rename(context, &target, name)
+					.map_err(|Diagnostic(diagnostic)| (INVALID_PARAMS, diagnostic.message))?;
+				super::edit(context, &replacements)
+			}
+			"textDocument/documentSymbol" => self.file(parameters).map(|file|
+				super::symbols(context, file)).unwrap_or(Value::Null),
+			_ => return Err((METHOD_NOT_FOUND, format!("unsupported method: {}", method))),
+		})
+	}
+
+	fn notify(&mut self, output: &mut impl Write, method: &str,
+			  parameters: &Value) -> io::Result<()> {
+		let document = &parameters["textDocument"];
+		let path = document["uri"].as_str().and_then(protocol::path)
+			.map(|path| path.canonicalize().unwrap_or(path));
+		let path = match (method, path) {
+			("initialized", _) | ("textDocument/didSave", _) => None,
+			("textDocument/didOpen", Some(path)) => {
+				let text = document["text"].as_str().unwrap_or_default();
+				self.documents.insert(path.clone(), text.into());
+				Some(path)
+			}
+			("textDocument/didChange", Some(path)) => {
+				let changes = parameters["contentChanges"].as_array();
+				let change = changes.and_then(|changes| changes.last());
+				if let Some(text) = change.and_then(|change| change["text"].as_str()) {
+					self.documents.insert(path.clone(), text.into());
+				}
+				Some(path)
+			}
+			("textDocument/didClose", Some(path)) => {
+				self.documents.remove(&path);
+				Some(path)
+			}
+			_ => return Ok(()),
+		};
+
+		self.compile(path);
+		self.publish(output)
+	}
+
+	/// Compiles again with the queries of the previous compilation retained
+	/// and the queries affected by the changed documents invalidated. Closed
+	/// documents are read from the file system. The queries are discarded if
+	/// the compiler panics as the queries may be incomplete.
+	fn compile(&mut self, path: Option<PathBuf>) {
+		let paths: Vec<_> = self.documents.keys().cloned().chain(path).collect();
+		let documents = &self.documents;
+		let update = |files: &mut Files, path: &Path| match documents.get(path) {
+			Some(text) => Ok(files.insert(path, text.clone())),
+			None => files.update(path),
+		};
+
+		let context = std::mem::take(&mut self.context);
+		let present = context.present.read().clone();
+		let (root, threads) = (&self.root, self.threads);
+		let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+			let (context, result) = crate::watch::reparse(context, root, &paths, update);
+			if result.is_ok() { check(&context, &present); }
+			crate::query::emit(&context, result);
+			context
+		}));
+
+		self.context = result.unwrap_or_default();
+		self.context.threads = threads;
+	}
+
+	/// Publishes the diagnostics of every file and clears
+	/// the diagnostics of files that no longer have any.
+	fn publish(&mut self, output: &mut impl Write) -> io::Result<()> {
+		let mut diagnostics: HashMap<_, Vec<_>> = self.published.drain()
+			.map(|path| (path, Vec::new())).collect();
+		let files = self.context.files.read();
+		for diagnostic in crate::context::diagnostics(&self.context) {
+			let (path, diagnostic) = super::diagnostic(&files, &diagnostic);
+			let path = path.unwrap_or_else(|| self.root.clone());
+			diagnostics.entry(path).or_default().push(diagnostic);
+		}
+
+		for (path, diagnostics) in diagnostics {
+			let uri = protocol::uri(&path);
+			if !diagnostics.is_empty() { self.published.insert(path); }
+			protocol::write(output, &json!({
+				"jsonrpc": "2.0",
+				"method": "textDocument/publishDiagnostics",
+				"params": {"uri": uri, "diagnostics": diagnostics},
+			}))?;
+		}
+		Ok(())
+	}
+
+	fn file(&self, parameters: &Value) -> Option<FileId> {
+		let path = protocol::path(parameters["textDocument"]["uri"].as_str()?)?;
+		let path = path.canonicalize().unwrap_or(path);
+		self.context.files.read().file(&path)
+	}
+
+	fn position(&self, parameters: &Value) -> Option<(FileId, usize)> {
+		let file = self.file(parameters)?;
+		let text = self.context.files.read().source(file).clone();
+		Some((file, protocol::offset(&text, &parameters["position"])?))
+	}
+}
+
+/// Type checks every function and static variable of a parsed compilation.
+/// Functions are not generated as generation is not required
+/// for any request and addresses are derived when requested.
+fn check(context: &Context, present: &HashSet<FunctionPath>) {
+	crate::query::emit(context, crate::node::present_all(context));
+	crate::cache::verify(context, present);
+
+	let functions: Vec<_> = context.functions.iter().flat_map(|entry|
+		(0..entry.value().len()).map(|kind| FunctionPath(entry.key().clone(), kind))
+			.collect::<Vec<_>>()).filter(|path| !context.imports
+		.contains_key(&Symbol::Function(path.clone()))).collect();
+	let results = crate::context::parallel(context, &functions, |path|
+		crate::inference::type_function(context, None, path, None).map(std::mem::drop));
+	results.into_iter().flatten().for_each(|result| crate::query::emit(context, result));
+
+	let statics: Vec<_> = context.statics.iter().map(|entry| entry.key().clone()).collect();
+	statics.into_iter().for_each(|path| crate::query::emit(context,
+		crate::inference::type_variable(context, None, path, None)));
+}
+
+/// Returns the location of a span in a source file.
+pub fn location(context: &Context, span: &Span) -> Option<Value> {
+	let files = context.files.read();
+	let uri = protocol::uri(files.path(span.file())?);
+	let range = protocol::range(files.source(span.file()), span.range());
+	Some(json!({"uri": uri, "range": range}))
+}
@@ -1,8 +1,15 @@
+use std::cell::Cell;
 use std::fmt;
 use std::ops::Range;
 
 use codespan::FileId;
 use codespan_reporting::diagnostic::Label;
+use serde::{Deserialize, Serialize};
+
+thread_local! {
+	/// Whether spans are compared without their positions.
+	static DISPLACED: Cell<bool> = Cell::new(false);
+}
 
 #[derive(Debug, Clone)]
 pub struct Span {
@@ -10,12 +17,41 @@ pub struct Span {
 	file: FileId,
 }
 
+impl PartialEq for Span {
+	fn eq(&self, other: &Self) -> bool {
+		self.file == other.file && (DISPLACED.with(Cell::get) || self.span == other.span)
+	}
+}
+
+/// Compares two values with the positions of their spans ignored
+/// such that values displaced by an edit compare as equal.
+pub fn displaced<T: PartialEq>(left: &T, right: &T) -> bool {
+	let previous = DISPLACED.with(|displaced| displaced.replace(true));
+	let equal = left == right;
+	DISPLACED.with(|displaced| displaced.set(previous));
+	equal
+}
+
 impl Span {
 	pub fn new(range: Range<usize>, file: codespan::FileId) -> Self {
 		let range = range.start as u32..range.end as u32;
 		Span { span: range.into(), file }
 	}
 
+	pub fn file(&self) -> FileId {
+		self.file
+	}
+
+	pub fn range(&self) -> Range<usize> {
+		self.span.start().to_usize()..self.span.end().to_usize()
+	}
+
+	/// Whether an offset is within the span or at its end.
+	pub fn contains(&self, file: FileId, offset: usize) -> bool {
+		let range = self.range();
+		self.file == file && range.start <= offset && offset <= range.end
+	}
+
 	pub fn label(&self) -> Label<FileId> {
 		Label::primary(self.file, self.span)
 	}
@@ -25,7 +61,7 @@ impl Span {
 	}
 }
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
 pub struct S<T> {
 	pub span: Span,
 	pub node: T,
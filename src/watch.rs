@@ -0,0 +1,224 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use codespan::FileId;
+use tree_sitter::{InputEdit, Point, Tree};
+
+use crate::context::{Context, Files};
+use crate::node::{FunctionPath, Item, Symbol};
+use crate::options::Options;
+use crate::query::{Key, Refresh, Table};
+
+/// The interval between checks for modified files.
+const INTERVAL: Duration = Duration::from_millis(250);
+
+/// An edit to a source file and the syntax tree before the edit.
+type Edit = (FileId, Tree, InputEdit);
+
+/// Compiles again whenever a file read by the previous compilation
+/// is modified. Queries are retained between compilations and only
+/// the queries of items affected by an edit are invalidated.
+pub fn watch(mut context: Context, options: &Options) -> std::io::Result<()> {
+	let mut times = modified(&context);
+	loop {
+		std::thread::sleep(INTERVAL);
+		let other = modified(&context);
+		let paths: Vec<_> = other.iter().filter(|(path, time)|
+			times.get(*path) != Some(time)).map(|(path, _)| path.clone()).collect();
+		if paths.is_empty() { continue; }
+
+		context = rebuild(context, options, &paths);
+		crate::context::display(&context, &options.diagnostics)?;
+		if options.statistics { crate::cache::report(&context); }
+		if options.timings { crate::query::timings(&context); }
+		times = modified(&context);
+	}
+}
+
+fn modified(context: &Context) -> HashMap<PathBuf, SystemTime> {
+	let files = context.files.read();
+	let sources = files.sources().map(|(path, _)| path);
+	let paths = sources.chain(files.binaries().map(|(path, _)| path));
+	paths.filter_map(|path| std::fs::metadata(path).and_then(|metadata|
+		metadata.modified()).ok().map(|time| (path.to_owned(), time))).collect()
+}
+
+fn rebuild(context: Context, options: &Options, paths: &[PathBuf]) -> Context {
+	let present = context.present.read().clone();
+	let (context, result) = reparse(context, &options.path, paths, Files::update);
+	let result = result.and_then(|_| {
+		crate::node::present_all(&context)?;
+		crate::cache::verify(&context, &present);
+		crate::compile(&context, options)
+	});
+
+	crate::query::emit(&context, crate::graph(&context, options, result));
+	context
+}
+
+/// Returns a context for another compilation that parses the root file
+/// again after updating the files at the paths. Only the queries affected
+/// by the edits to the files are invalidated. The update function returns
+/// whether the contents of a file have changed.
+pub fn reparse(context: Context, root: &Path, paths: &[PathBuf],
+			   update: impl FnMut(&mut Files, &Path) -> std::io::Result<bool>)
+			   -> (Context, crate::Result<()>) {
+	let included: Vec<_> = paths.iter().map(|path| Key::Include(path.clone()))
+		.filter(|key| context.included.contains(key)).collect();
+	let edits = edits(&context, paths, update);
+	let items = symbols(&context);
+
+	let mut failures = Vec::new();
+	failed(&context.unit, &mut failures);
+	failed(&context.type_contexts, &mut failures);
+	failed(&context.sections, &mut failures);
+	failed(&context.offsets, &mut failures);
+	failed(&context.address, &mut failures);
+	failed(&context.names, &mut failures);
+	failed(&context.binaries, &mut failures);
+	failed(&context.included, &mut failures);
+
+	let context = context.successor();
+	let result = crate::parse::parse(&context, root);
+	if result.is_err() { return (context, result); }
+	crate::node::positions(&context);
+	let affected = edits.and_then(|edits| match symbols(&context) == items {
+		true => affected(&context, &edits),
+		false => None,
+	});
+
+	match affected {
+		None => clear(&context),
+		Some(mut keys) => {
+			keys.extend(included);
+			keys.extend(failures);
+			crate::query::invalidate(&context, &keys);
+		}
+	}
+	(context, Ok(()))
+}
+
+/// Updates modified files and applies their edits to their syntax trees.
+/// Returns `None` if a modified file is not a source file with a tree
+/// or a file included as data whose queries are invalidated instead.
+fn edits(context: &Context, paths: &[PathBuf],
+		 mut update: impl FnMut(&mut Files, &Path) -> std::io::Result<bool>)
+		 -> Option<Vec<Edit>> {
+	let mut edits = Some(Vec::new());
+	let mut files = context.files.write();
+	for path in paths {
+		let previous = files.file(path).map(|file| (file, files.source(file).clone()));
+		match (update(&mut files, path), previous) {
+			(Ok(false), _) => continue,
+			(Ok(true), None) if context.included
+				.contains(&Key::Include(path.clone())) => continue,
+			(Ok(true), Some((file, previous))) => {
+				let edit = edit(&previous, files.source(file));
+				let mut trees = context.trees.lock();
+				match (trees.get_mut(&file), &mut edits) {
+					(Some(tree), Some(edits)) => {
+						tree.edit(&edit);
+						edits.push((file, tree.clone(), edit));
+					}
+					(Some(_), None) => (),
+					(None, _) => edits = None,
+				}
+			}
+			_ => edits = None,
+		}
+	}
+	edits
+}
+
+/// Derives a single edit that spans every difference between two texts.
+fn edit(previous: &str, text: &str) -> InputEdit {
+	let (previous_bytes, bytes) = (previous.as_bytes(), text.as_bytes());
+	let start = Iterator::zip(previous_bytes.iter(), bytes.iter())
+		.take_while(|(left, right)| left == right).count();
+	let suffix = Iterator::zip(previous_bytes[start..].iter().rev(),
+		bytes[start..].iter().rev()).take_while(|(left, right)| left == right).count();
+
+	let old_end_byte = previous.len() - suffix;
+	let new_end_byte = text.len() - suffix;
+	InputEdit {
+		start_byte: start,
+		old_end_byte,
+		new_end_byte,
+		start_position: point(bytes, start),
+		old_end_position: point(previous_bytes, old_end_byte),
+		new_end_position: point(bytes, new_end_byte),
+	}
+}
+
+fn point(bytes: &[u8], offset: usize) -> Point {
+	let bytes = &bytes[..offset];
+	let row = bytes.iter().filter(|byte| **byte == b'\n').count();
+	let line = bytes.iter().rposition(|byte| *byte == b'\n');
+	Point::new(row, offset - line.map(|line| line + 1).unwrap_or(0))
+}
+
+fn symbols(context: &Context) -> Vec<Symbol> {
+	context.items.read().iter().filter_map(|item| match item {
+		Item::Symbol(symbol) | Item::Import(symbol) => Some(symbol.clone()),
+		Item::ModuleEnd => None,
+	}).collect()
+}
+
+/// Maps the changed ranges of each edit to the functions whose bodies
+/// contain them. Returns `None` if a range is outside of a function body.
+/// Items that follow an edit that changes the length of a file are also
+/// affected as the spans in their queries are displaced.
+fn affected(context: &Context, edits: &[Edit]) -> Option<Vec<Key>> {
+	let (mut functions, mut paths, mut keys) = (Vec::new(), Vec::new(), Vec::new());
+	for entry in context.functions.iter() {
+		for (kind, function) in entry.value().iter().enumerate() {
+			let value = &function.value;
+			if let Some(node) = value.values.get(value.root) {
+				let path = FunctionPath(entry.key().clone(), kind);
+				functions.push((path, node.span.clone()));
+			}
+		}
+	}
+
+	for (file, previous, edit) in edits {
+		let tree = context.trees.lock().get(file).cloned()?;
+		let changed = previous.changed_ranges(&tree).map(|range|
+			range.start_byte..range.end_byte);
+		for range in changed.chain(std::iter::once(edit.start_byte..edit.new_end_byte)) {
+			let (path, _) = functions.iter().find(|(_, span)| span.file() == *file
+				&& span.range().start <= range.start && range.end <= span.range().end)?;
+			paths.push(path.clone());
+		}
+
+		if edit.new_end_byte != edit.old_end_byte {
+			let displaced = |start: usize| start >= edit.new_end_byte;
+			paths.extend(functions.iter().filter(|(_, span)| span.file() == *file
+				&& displaced(span.range().start)).map(|(path, _)| path.clone()));
+			keys.extend(context.statics.iter().filter(|entry| entry.identifier.span.file()
+				== *file && displaced(entry.identifier.span.range().start))
+				.map(|entry| Key::TypeVariable(entry.key().clone())));
+		}
+	}
+
+	keys.extend(paths.into_iter().flat_map(|path| vec![Key::Analyze(path.clone()),
+		Key::TypeFunction(path.clone()), Key::Generate(path)]));
+	Some(keys)
+}
+
+fn failed<V>(table: &Table<V>, keys: &mut Vec<Key>) {
+	keys.extend(table.entries().into_iter().filter(|(_, value, _)|
+		value.is_none()).map(|(key, _, _)| key));
+}
+
+fn clear(context: &Context) {
+	context.unit.clear();
+	context.type_contexts.clear();
+	context.sections.clear();
+	context.offsets.clear();
+	context.address.clear();
+	context.names.clear();
+	context.binaries.clear();
+	context.included.clear();
+	context.far.write().clear();
+}
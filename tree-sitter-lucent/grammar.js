@@ -88,7 +88,7 @@ module.exports = grammar({
             field('return', optional($._type)),
         )),
 
-        data: $ => seq(
+        data: $ => seq(annotations($),
             'data', field('identifier', $.identifier),
             enclose($, field('field', alias($.parameter, $.field))),
         ),
@@ -6,7 +6,7 @@
 #endif
 
 #define LANGUAGE_VERSION 11
-#define STATE_COUNT 1217
+#define STATE_COUNT 1231
 #define LARGE_STATE_COUNT 2
 #define SYMBOL_COUNT 123
 #define ALIAS_COUNT 1
@@ -951,7 +951,7 @@ static const char *ts_field_names[] = {
   [field_with] = "with",
 };
 
-static const TSFieldMapSlice ts_field_map_slices[193] = {
+static const TSFieldMapSlice ts_field_map_slices[195] = {
   [1] = {.index = 0, .length = 1},
   [2] = {.index = 1, .length = 1},
   [3] = {.index = 2, .length = 2},
@@ -1142,6 +1142,8 @@ static const TSFieldMapSlice ts_field_map_slices[193] = {
   [190] = {.index = 742, .length = 8},
   [191] = {.index = 750, .length = 7},
   [192] = {.index = 757, .length = 8},
+  [193] = {.index = 765, .length = 3},
+  [194] = {.index = 768, .length = 4},
 };
 
 static const TSFieldMapEntry ts_field_map_entries[] = {
@@ -2098,9 +2100,18 @@ static const TSFieldMapEntry ts_field_map_entries[] = {
     {field_parameter, 7, .inherited = true},
     {field_return, 10},
     {field_root, 1},
+  [765] =
+    {field_annotation, 0},
+    {field_field, 4},
+    {field_identifier, 2},
+  [768] =
+    {field_annotation, 0},
+    {field_field, 4},
+    {field_field, 5, .inherited = true},
+    {field_identifier, 2},
 };
 
-static TSSymbol ts_alias_sequences[193][MAX_ALIAS_SEQUENCE_LENGTH] = {
+static TSSymbol ts_alias_sequences[195][MAX_ALIAS_SEQUENCE_LENGTH] = {
   [0] = {0},
   [11] = {
     [2] = sym_static,
@@ -2120,6 +2131,12 @@ static TSSymbol ts_alias_sequences[193][MAX_ALIAS_SEQUENCE_LENGTH] = {
   [42] = {
     [3] = sym_field,
   },
+  [193] = {
+    [4] = sym_field,
+  },
+  [194] = {
+    [4] = sym_field,
+  },
 };
 
 static bool ts_lex(TSLexer *lexer, TSStateId state) {
@@ -3937,6 +3954,20 @@ static TSLexMode ts_lex_modes[STATE_COUNT] = {
   [1214] = {.lex_state = 1},
   [1215] = {.lex_state = 0},
   [1216] = {.lex_state = 0},
+  [1217] = {.lex_state = 0},
+  [1218] = {.lex_state = 0, .external_lex_state = 2},
+  [1219] = {.lex_state = 0},
+  [1220] = {.lex_state = 0, .external_lex_state = 5},
+  [1221] = {.lex_state = 0, .external_lex_state = 5},
+  [1222] = {.lex_state = 0},
+  [1223] = {.lex_state = 0},
+  [1224] = {.lex_state = 0},
+  [1225] = {.lex_state = 0, .external_lex_state = 2},
+  [1226] = {.lex_state = 0},
+  [1227] = {.lex_state = 0, .external_lex_state = 5},
+  [1228] = {.lex_state = 0, .external_lex_state = 5},
+  [1229] = {.lex_state = 0, .external_lex_state = 5},
+  [1230] = {.lex_state = 0, .external_lex_state = 5},
 };
 
 enum {
@@ -25020,14 +25051,15 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-    ACTIONS(1166), 6,
+    ACTIONS(1166), 7,
       anon_sym_module,
       anon_sym_use,
       anon_sym_fn,
+      anon_sym_data,
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [26352] = 5,
+  [26353] = 5,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1170), 1,
@@ -25061,7 +25093,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [26391] = 3,
+  [26392] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(129), 6,
@@ -25093,7 +25125,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
       anon_sym_TILDE,
-  [26426] = 5,
+  [26427] = 5,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1174), 1,
@@ -25126,7 +25158,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [26464] = 5,
+  [26465] = 5,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1176), 1,
@@ -25159,7 +25191,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [26502] = 5,
+  [26503] = 5,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1179), 1,
@@ -25192,7 +25224,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [26540] = 6,
+  [26541] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1174), 1,
@@ -25226,7 +25258,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [26580] = 12,
+  [26581] = 12,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -25265,7 +25297,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [26631] = 3,
+  [26632] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(287), 6,
@@ -25295,7 +25327,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [26664] = 3,
+  [26665] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(173), 6,
@@ -25325,7 +25357,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [26697] = 3,
+  [26698] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(251), 6,
@@ -25355,7 +25387,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [26730] = 3,
+  [26731] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(299), 6,
@@ -25385,7 +25417,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [26763] = 3,
+  [26764] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(295), 6,
@@ -25415,7 +25447,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [26796] = 3,
+  [26797] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(303), 6,
@@ -25445,7 +25477,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [26829] = 3,
+  [26830] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(283), 6,
@@ -25475,7 +25507,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [26862] = 5,
+  [26863] = 5,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -25507,7 +25539,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [26899] = 7,
+  [26900] = 7,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -25541,7 +25573,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [26940] = 9,
+  [26941] = 9,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -25577,7 +25609,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [26985] = 11,
+  [26986] = 11,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -25615,7 +25647,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [27034] = 3,
+  [27035] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(239), 6,
@@ -25645,7 +25677,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [27067] = 10,
+  [27068] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -25682,7 +25714,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [27114] = 8,
+  [27115] = 8,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -25717,7 +25749,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [27157] = 14,
+  [27158] = 14,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(229), 1,
@@ -25758,7 +25790,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_COMMA,
       anon_sym_AMP_AMP,
       anon_sym_PIPE_PIPE,
-  [27212] = 15,
+  [27213] = 15,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(229), 1,
@@ -25800,7 +25832,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_as,
       anon_sym_COMMA,
       anon_sym_PIPE_PIPE,
-  [27269] = 3,
+  [27270] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(247), 6,
@@ -25830,7 +25862,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [27302] = 3,
+  [27303] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(291), 6,
@@ -25860,7 +25892,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [27335] = 3,
+  [27336] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(245), 6,
@@ -25890,7 +25922,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [27368] = 3,
+  [27369] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(265), 6,
@@ -25920,7 +25952,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [27401] = 17,
+  [27402] = 17,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(197), 1,
@@ -25964,7 +25996,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [27462] = 3,
+  [27463] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(279), 6,
@@ -25994,7 +26026,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [27495] = 3,
+  [27496] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(177), 6,
@@ -26024,7 +26056,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [27528] = 3,
+  [27529] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(271), 6,
@@ -26054,7 +26086,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [27561] = 3,
+  [27562] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(267), 6,
@@ -26084,7 +26116,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [27594] = 3,
+  [27595] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(255), 6,
@@ -26114,7 +26146,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [27627] = 3,
+  [27628] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(259), 6,
@@ -26144,7 +26176,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [27660] = 3,
+  [27661] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(275), 6,
@@ -26174,7 +26206,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [27693] = 3,
+  [27694] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(181), 6,
@@ -26204,7 +26236,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [27726] = 3,
+  [27727] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(185), 6,
@@ -26234,7 +26266,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [27759] = 5,
+  [27760] = 5,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -26266,7 +26298,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [27796] = 3,
+  [27797] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(189), 6,
@@ -26296,7 +26328,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [27829] = 3,
+  [27830] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(193), 6,
@@ -26326,7 +26358,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [27862] = 4,
+  [27863] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1181), 1,
@@ -26357,7 +26389,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [27897] = 18,
+  [27898] = 18,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(425), 1,
@@ -26401,7 +26433,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [27959] = 18,
+  [27960] = 18,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(425), 1,
@@ -26445,7 +26477,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [28021] = 18,
+  [28022] = 18,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(425), 1,
@@ -26489,7 +26521,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [28083] = 18,
+  [28084] = 18,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(425), 1,
@@ -26533,7 +26565,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [28145] = 17,
+  [28146] = 17,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -26576,7 +26608,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [28205] = 18,
+  [28206] = 18,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(425), 1,
@@ -26620,7 +26652,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [28267] = 18,
+  [28268] = 18,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(425), 1,
@@ -26664,7 +26696,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [28329] = 16,
+  [28330] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -26705,7 +26737,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [28386] = 16,
+  [28387] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -26746,7 +26778,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [28443] = 16,
+  [28444] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -26787,7 +26819,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [28500] = 16,
+  [28501] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -26828,7 +26860,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [28557] = 16,
+  [28558] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -26869,7 +26901,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [28614] = 16,
+  [28615] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -26910,7 +26942,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [28671] = 17,
+  [28672] = 17,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(425), 1,
@@ -26952,7 +26984,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [28730] = 16,
+  [28731] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -26993,7 +27025,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [28787] = 16,
+  [28788] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -27034,7 +27066,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [28844] = 16,
+  [28845] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -27075,7 +27107,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [28901] = 16,
+  [28902] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -27116,7 +27148,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [28958] = 16,
+  [28959] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -27157,7 +27189,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [29015] = 16,
+  [29016] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -27198,7 +27230,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [29072] = 16,
+  [29073] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -27239,7 +27271,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [29129] = 16,
+  [29130] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -27280,7 +27312,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [29186] = 16,
+  [29187] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -27321,7 +27353,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [29243] = 17,
+  [29244] = 17,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(425), 1,
@@ -27363,7 +27395,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [29302] = 16,
+  [29303] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -27404,7 +27436,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [29359] = 16,
+  [29360] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -27445,7 +27477,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [29416] = 16,
+  [29417] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -27486,7 +27518,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [29473] = 16,
+  [29474] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -27527,7 +27559,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [29530] = 16,
+  [29531] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -27568,7 +27600,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [29587] = 16,
+  [29588] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -27609,7 +27641,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [29644] = 16,
+  [29645] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -27650,7 +27682,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [29701] = 16,
+  [29702] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -27691,7 +27723,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [29758] = 16,
+  [29759] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -27732,7 +27764,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [29815] = 16,
+  [29816] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -27773,7 +27805,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [29872] = 16,
+  [29873] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -27814,7 +27846,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [29929] = 16,
+  [29930] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -27855,7 +27887,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [29986] = 16,
+  [29987] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -27896,7 +27928,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [30043] = 16,
+  [30044] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -27937,7 +27969,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [30100] = 16,
+  [30101] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -27978,7 +28010,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [30157] = 16,
+  [30158] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -28019,7 +28051,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [30214] = 16,
+  [30215] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -28060,7 +28092,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [30271] = 16,
+  [30272] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -28101,7 +28133,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [30328] = 17,
+  [30329] = 17,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(425), 1,
@@ -28143,7 +28175,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [30387] = 16,
+  [30388] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -28184,7 +28216,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [30444] = 16,
+  [30445] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -28225,7 +28257,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [30501] = 16,
+  [30502] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -28266,7 +28298,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [30558] = 16,
+  [30559] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -28307,7 +28339,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [30615] = 16,
+  [30616] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -28348,7 +28380,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [30672] = 16,
+  [30673] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -28389,7 +28421,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [30729] = 16,
+  [30730] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -28430,7 +28462,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [30786] = 16,
+  [30787] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -28471,7 +28503,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [30843] = 16,
+  [30844] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -28512,7 +28544,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [30900] = 16,
+  [30901] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -28553,7 +28585,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [30957] = 16,
+  [30958] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -28594,7 +28626,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [31014] = 16,
+  [31015] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -28635,7 +28667,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [31071] = 16,
+  [31072] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -28676,7 +28708,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [31128] = 16,
+  [31129] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -28717,7 +28749,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [31185] = 16,
+  [31186] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -28758,7 +28790,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [31242] = 16,
+  [31243] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -28799,7 +28831,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [31299] = 16,
+  [31300] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -28840,7 +28872,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [31356] = 16,
+  [31357] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -28881,7 +28913,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [31413] = 16,
+  [31414] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -28922,7 +28954,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [31470] = 16,
+  [31471] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -28963,7 +28995,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [31527] = 16,
+  [31528] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -29004,7 +29036,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [31584] = 16,
+  [31585] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -29045,7 +29077,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [31641] = 16,
+  [31642] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(425), 1,
@@ -29086,7 +29118,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [31698] = 16,
+  [31699] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(425), 1,
@@ -29127,7 +29159,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [31755] = 16,
+  [31756] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -29168,7 +29200,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [31812] = 16,
+  [31813] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -29209,7 +29241,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [31869] = 16,
+  [31870] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -29250,7 +29282,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [31926] = 16,
+  [31927] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -29291,7 +29323,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [31983] = 16,
+  [31984] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -29332,7 +29364,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [32040] = 16,
+  [32041] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -29373,7 +29405,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [32097] = 16,
+  [32098] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -29414,7 +29446,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [32154] = 16,
+  [32155] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -29455,7 +29487,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [32211] = 16,
+  [32212] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -29496,7 +29528,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [32268] = 16,
+  [32269] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -29537,7 +29569,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [32325] = 16,
+  [32326] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -29578,7 +29610,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [32382] = 16,
+  [32383] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -29619,7 +29651,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [32439] = 16,
+  [32440] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -29660,7 +29692,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [32496] = 16,
+  [32497] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1183), 1,
@@ -29701,7 +29733,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [32553] = 16,
+  [32554] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(425), 1,
@@ -29741,7 +29773,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [32609] = 3,
+  [32610] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(148), 5,
@@ -29768,7 +29800,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_DOLLAR,
       sym_string,
       sym_rune,
-  [32639] = 16,
+  [32640] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(425), 1,
@@ -29808,7 +29840,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [32695] = 5,
+  [32696] = 5,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1273), 1,
@@ -29837,7 +29869,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_DOLLAR,
       sym_string,
       sym_rune,
-  [32729] = 16,
+  [32730] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(425), 1,
@@ -29877,7 +29909,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [32785] = 16,
+  [32786] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(425), 1,
@@ -29917,7 +29949,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [32841] = 16,
+  [32842] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(425), 1,
@@ -29957,7 +29989,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [32897] = 16,
+  [32898] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(425), 1,
@@ -29997,7 +30029,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [32953] = 16,
+  [32954] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(425), 1,
@@ -30037,7 +30069,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [33009] = 5,
+  [33010] = 5,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1285), 1,
@@ -30066,7 +30098,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_DOLLAR,
       sym_string,
       sym_rune,
-  [33043] = 16,
+  [33044] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(425), 1,
@@ -30106,7 +30138,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [33099] = 16,
+  [33100] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(425), 1,
@@ -30146,7 +30178,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [33155] = 16,
+  [33156] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(425), 1,
@@ -30186,7 +30218,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [33211] = 16,
+  [33212] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(425), 1,
@@ -30226,7 +30258,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [33267] = 16,
+  [33268] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(425), 1,
@@ -30266,7 +30298,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [33323] = 16,
+  [33324] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(425), 1,
@@ -30306,7 +30338,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [33379] = 16,
+  [33380] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(425), 1,
@@ -30346,7 +30378,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [33435] = 16,
+  [33436] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(425), 1,
@@ -30386,7 +30418,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [33491] = 5,
+  [33492] = 5,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1273), 1,
@@ -30415,7 +30447,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_DOLLAR,
       sym_string,
       sym_rune,
-  [33525] = 16,
+  [33526] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(425), 1,
@@ -30455,7 +30487,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [33581] = 16,
+  [33582] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(425), 1,
@@ -30495,7 +30527,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [33637] = 16,
+  [33638] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(425), 1,
@@ -30535,7 +30567,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [33693] = 16,
+  [33694] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(425), 1,
@@ -30575,7 +30607,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [33749] = 3,
+  [33750] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(129), 5,
@@ -30601,7 +30633,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_DOLLAR,
       sym_string,
       sym_rune,
-  [33778] = 16,
+  [33779] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(7), 1,
@@ -30640,7 +30672,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_data,
       sym_function,
       sym_static,
-  [33833] = 16,
+  [33834] = 16,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1314), 1,
@@ -30679,7 +30711,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_data,
       sym_function,
       sym_static,
-  [33888] = 5,
+  [33889] = 5,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1347), 1,
@@ -30707,7 +30739,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_DOLLAR,
       sym_string,
       sym_rune,
-  [33921] = 4,
+  [33922] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1355), 1,
@@ -30733,7 +30765,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_DOLLAR,
       sym_string,
       sym_rune,
-  [33951] = 3,
+  [33952] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(287), 5,
@@ -30758,7 +30790,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_DOLLAR,
       sym_string,
       sym_rune,
-  [33979] = 3,
+  [33980] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(265), 5,
@@ -30783,7 +30815,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_DOLLAR,
       sym_string,
       sym_rune,
-  [34007] = 3,
+  [34008] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(245), 5,
@@ -30808,7 +30840,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_DOLLAR,
       sym_string,
       sym_rune,
-  [34035] = 3,
+  [34036] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1357), 5,
@@ -30832,7 +30864,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_DOLLAR,
       sym_string,
       sym_rune,
-  [34062] = 3,
+  [34063] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1361), 5,
@@ -30856,7 +30888,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_DOLLAR,
       sym_string,
       sym_rune,
-  [34089] = 3,
+  [34090] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1365), 5,
@@ -30880,7 +30912,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_DOLLAR,
       sym_string,
       sym_rune,
-  [34116] = 3,
+  [34117] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1369), 5,
@@ -30904,7 +30936,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_DOLLAR,
       sym_string,
       sym_rune,
-  [34143] = 3,
+  [34144] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1373), 5,
@@ -30928,7 +30960,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_DOLLAR,
       sym_string,
       sym_rune,
-  [34170] = 3,
+  [34171] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1377), 5,
@@ -30952,7 +30984,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_DOLLAR,
       sym_string,
       sym_rune,
-  [34197] = 3,
+  [34198] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1381), 5,
@@ -30976,7 +31008,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_DOLLAR,
       sym_string,
       sym_rune,
-  [34224] = 3,
+  [34225] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1385), 5,
@@ -31000,7 +31032,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_DOLLAR,
       sym_string,
       sym_rune,
-  [34251] = 3,
+  [34252] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1389), 5,
@@ -31024,7 +31056,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_DOLLAR,
       sym_string,
       sym_rune,
-  [34278] = 3,
+  [34279] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1393), 5,
@@ -31048,7 +31080,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_DOLLAR,
       sym_string,
       sym_rune,
-  [34305] = 3,
+  [34306] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1397), 5,
@@ -31072,7 +31104,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_DOLLAR,
       sym_string,
       sym_rune,
-  [34332] = 5,
+  [34333] = 5,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1401), 1,
@@ -31096,7 +31128,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_static,
       anon_sym_SEMI,
       sym__identifier,
-  [34361] = 12,
+  [34362] = 12,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(7), 1,
@@ -31127,7 +31159,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_data,
       sym_function,
       sym_static,
-  [34404] = 12,
+  [34405] = 12,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(7), 1,
@@ -31158,7 +31190,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_data,
       sym_function,
       sym_static,
-  [34447] = 12,
+  [34448] = 12,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(7), 1,
@@ -31189,7 +31221,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_data,
       sym_function,
       sym_static,
-  [34490] = 12,
+  [34491] = 12,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(7), 1,
@@ -31220,7 +31252,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_data,
       sym_function,
       sym_static,
-  [34533] = 5,
+  [34534] = 5,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1418), 1,
@@ -31244,7 +31276,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_static,
       anon_sym_SEMI,
       sym__identifier,
-  [34562] = 12,
+  [34563] = 12,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(7), 1,
@@ -31275,7 +31307,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_data,
       sym_function,
       sym_static,
-  [34605] = 5,
+  [34606] = 5,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1418), 1,
@@ -31299,7 +31331,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_static,
       anon_sym_SEMI,
       sym__identifier,
-  [34634] = 10,
+  [34635] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -31326,7 +31358,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [34671] = 10,
+  [34672] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -31353,7 +31385,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [34708] = 10,
+  [34709] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -31380,7 +31412,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [34745] = 10,
+  [34746] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
@@ -31407,7 +31439,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [34782] = 10,
+  [34783] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -31434,7 +31466,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [34819] = 10,
+  [34820] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
@@ -31461,7 +31493,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [34856] = 10,
+  [34857] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
@@ -31488,7 +31520,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [34893] = 10,
+  [34894] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -31515,7 +31547,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [34930] = 10,
+  [34931] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -31542,7 +31574,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [34967] = 10,
+  [34968] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
@@ -31569,7 +31601,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [35004] = 10,
+  [35005] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -31596,7 +31628,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [35041] = 10,
+  [35042] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -31623,7 +31655,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [35078] = 10,
+  [35079] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
@@ -31650,7 +31682,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [35115] = 10,
+  [35116] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
@@ -31677,7 +31709,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [35152] = 10,
+  [35153] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
@@ -31704,7 +31736,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [35189] = 10,
+  [35190] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
@@ -31731,7 +31763,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [35226] = 10,
+  [35227] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
@@ -31758,7 +31790,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [35263] = 10,
+  [35264] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
@@ -31785,7 +31817,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [35300] = 10,
+  [35301] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
@@ -31812,7 +31844,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [35337] = 10,
+  [35338] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
@@ -31839,7 +31871,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [35374] = 10,
+  [35375] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
@@ -31866,7 +31898,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [35411] = 10,
+  [35412] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
@@ -31893,7 +31925,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [35448] = 10,
+  [35449] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
@@ -31920,7 +31952,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [35485] = 10,
+  [35486] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
@@ -31947,7 +31979,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [35522] = 10,
+  [35523] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
@@ -31974,7 +32006,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [35559] = 10,
+  [35560] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
@@ -32001,7 +32033,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [35596] = 10,
+  [35597] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
@@ -32028,7 +32060,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [35633] = 10,
+  [35634] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -32055,7 +32087,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [35670] = 10,
+  [35671] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -32082,7 +32114,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [35707] = 10,
+  [35708] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
@@ -32109,7 +32141,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [35744] = 10,
+  [35745] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -32136,7 +32168,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [35781] = 10,
+  [35782] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
@@ -32163,7 +32195,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [35818] = 10,
+  [35819] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -32190,7 +32222,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [35855] = 10,
+  [35856] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
@@ -32217,7 +32249,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [35892] = 10,
+  [35893] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -32244,7 +32276,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [35929] = 10,
+  [35930] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -32271,7 +32303,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [35966] = 10,
+  [35967] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
@@ -32298,7 +32330,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [36003] = 10,
+  [36004] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
@@ -32325,7 +32357,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [36040] = 10,
+  [36041] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -32352,7 +32384,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [36077] = 10,
+  [36078] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
@@ -32379,7 +32411,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [36114] = 10,
+  [36115] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
@@ -32406,7 +32438,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [36151] = 10,
+  [36152] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
@@ -32433,7 +32465,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [36188] = 10,
+  [36189] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -32460,7 +32492,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [36225] = 10,
+  [36226] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -32487,7 +32519,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [36262] = 10,
+  [36263] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -32514,7 +32546,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [36299] = 10,
+  [36300] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -32541,7 +32573,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [36336] = 10,
+  [36337] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -32568,7 +32600,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [36373] = 10,
+  [36374] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -32595,7 +32627,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [36410] = 10,
+  [36411] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -32622,7 +32654,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [36447] = 10,
+  [36448] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
@@ -32649,7 +32681,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [36484] = 10,
+  [36485] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -32676,7 +32708,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [36521] = 10,
+  [36522] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -32703,7 +32735,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [36558] = 10,
+  [36559] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -32730,7 +32762,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [36595] = 10,
+  [36596] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
@@ -32757,7 +32789,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [36632] = 10,
+  [36633] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -32784,7 +32816,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [36669] = 10,
+  [36670] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -32811,7 +32843,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [36706] = 10,
+  [36707] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -32838,7 +32870,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [36743] = 10,
+  [36744] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -32865,7 +32897,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [36780] = 10,
+  [36781] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -32892,7 +32924,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [36817] = 10,
+  [36818] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
@@ -32919,7 +32951,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [36854] = 10,
+  [36855] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
@@ -32946,7 +32978,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [36891] = 10,
+  [36892] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -32973,7 +33005,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [36928] = 10,
+  [36929] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
@@ -33000,7 +33032,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [36965] = 10,
+  [36966] = 10,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -33027,7 +33059,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_array_type,
       sym_path,
       sym_register,
-  [37002] = 4,
+  [37003] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1562), 1,
@@ -33044,7 +33076,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37023] = 4,
+  [37024] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1568), 1,
@@ -33061,7 +33093,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37044] = 3,
+  [37045] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1570), 2,
@@ -33076,7 +33108,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37062] = 3,
+  [37063] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1574), 2,
@@ -33091,7 +33123,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37080] = 7,
+  [37081] = 7,
     ACTIONS(1578), 1,
       sym__identifier,
     ACTIONS(1580), 1,
@@ -33110,7 +33142,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [37106] = 3,
+  [37107] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1588), 2,
@@ -33125,7 +33157,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37124] = 3,
+  [37125] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(969), 2,
@@ -33140,7 +33172,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37142] = 3,
+  [37143] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1592), 2,
@@ -33155,7 +33187,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37160] = 3,
+  [37161] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1596), 2,
@@ -33170,7 +33202,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37178] = 3,
+  [37179] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1387), 2,
@@ -33185,7 +33217,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37196] = 7,
+  [37197] = 7,
     ACTIONS(1578), 1,
       sym__identifier,
     ACTIONS(1582), 1,
@@ -33204,7 +33236,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [37222] = 7,
+  [37223] = 7,
     ACTIONS(1578), 1,
       sym__identifier,
     ACTIONS(1582), 1,
@@ -33223,7 +33255,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [37248] = 3,
+  [37249] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1604), 2,
@@ -33238,7 +33270,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37266] = 3,
+  [37267] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1608), 2,
@@ -33253,7 +33285,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37284] = 7,
+  [37285] = 7,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
@@ -33272,7 +33304,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [37310] = 7,
+  [37311] = 7,
     ACTIONS(1578), 1,
       sym__identifier,
     ACTIONS(1582), 1,
@@ -33291,7 +33323,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [37336] = 3,
+  [37337] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1622), 2,
@@ -33306,7 +33338,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37354] = 7,
+  [37355] = 7,
     ACTIONS(1578), 1,
       sym__identifier,
     ACTIONS(1582), 1,
@@ -33325,7 +33357,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [37380] = 3,
+  [37381] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1628), 2,
@@ -33340,7 +33372,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37398] = 3,
+  [37399] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1632), 2,
@@ -33355,7 +33387,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37416] = 3,
+  [37417] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1636), 2,
@@ -33370,7 +33402,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37434] = 3,
+  [37435] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1640), 2,
@@ -33385,7 +33417,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37452] = 7,
+  [37453] = 7,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
@@ -33404,7 +33436,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [37478] = 3,
+  [37479] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1646), 2,
@@ -33419,7 +33451,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37496] = 3,
+  [37497] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1650), 2,
@@ -33434,7 +33466,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37514] = 3,
+  [37515] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1654), 2,
@@ -33449,7 +33481,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37532] = 7,
+  [37533] = 7,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
@@ -33468,7 +33500,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [37558] = 3,
+  [37559] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1660), 2,
@@ -33483,7 +33515,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37576] = 7,
+  [37577] = 7,
     ACTIONS(1578), 1,
       sym__identifier,
     ACTIONS(1582), 1,
@@ -33502,7 +33534,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [37602] = 7,
+  [37603] = 7,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
@@ -33521,7 +33553,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [37628] = 7,
+  [37629] = 7,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
@@ -33540,7 +33572,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [37654] = 3,
+  [37655] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1670), 2,
@@ -33555,7 +33587,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37672] = 3,
+  [37673] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1674), 2,
@@ -33570,7 +33602,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37690] = 3,
+  [37691] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1089), 2,
@@ -33585,7 +33617,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37708] = 3,
+  [37709] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1678), 2,
@@ -33600,7 +33632,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37726] = 3,
+  [37727] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1682), 2,
@@ -33615,7 +33647,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37744] = 3,
+  [37745] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1686), 2,
@@ -33630,7 +33662,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37762] = 3,
+  [37763] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1690), 2,
@@ -33645,7 +33677,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37780] = 3,
+  [37781] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1371), 2,
@@ -33660,7 +33692,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37798] = 3,
+  [37799] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1694), 2,
@@ -33675,7 +33707,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37816] = 3,
+  [37817] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1698), 2,
@@ -33690,7 +33722,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37834] = 3,
+  [37835] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1702), 2,
@@ -33705,7 +33737,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37852] = 3,
+  [37853] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1706), 2,
@@ -33720,7 +33752,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37870] = 3,
+  [37871] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1117), 2,
@@ -33735,7 +33767,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37888] = 3,
+  [37889] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1710), 2,
@@ -33750,7 +33782,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37906] = 3,
+  [37907] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1714), 2,
@@ -33765,7 +33797,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37924] = 10,
+  [37925] = 11,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(7), 1,
@@ -33778,6 +33810,8 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_use,
     ACTIONS(1722), 1,
       anon_sym_fn,
+    ACTIONS(2626), 1,
+      anon_sym_data,
     ACTIONS(1724), 1,
       anon_sym_root,
     ACTIONS(1726), 1,
@@ -33787,7 +33821,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(782), 2,
       sym_annotation,
       aux_sym_module_repeat1,
-  [37956] = 3,
+  [37960] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1728), 2,
@@ -33802,7 +33836,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37974] = 3,
+  [37978] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1732), 2,
@@ -33817,7 +33851,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [37992] = 3,
+  [37996] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1736), 2,
@@ -33832,7 +33866,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38010] = 3,
+  [38014] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1740), 2,
@@ -33847,7 +33881,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38028] = 3,
+  [38032] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1037), 2,
@@ -33862,7 +33896,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38046] = 3,
+  [38050] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1017), 2,
@@ -33877,7 +33911,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38064] = 3,
+  [38068] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1744), 2,
@@ -33892,7 +33926,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38082] = 3,
+  [38086] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1748), 2,
@@ -33907,7 +33941,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38100] = 3,
+  [38104] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1752), 2,
@@ -33922,7 +33956,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38118] = 3,
+  [38122] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1756), 2,
@@ -33937,7 +33971,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38136] = 3,
+  [38140] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1760), 2,
@@ -33952,7 +33986,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38154] = 3,
+  [38158] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1764), 2,
@@ -33967,7 +34001,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38172] = 3,
+  [38176] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1768), 2,
@@ -33982,7 +34016,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38190] = 3,
+  [38194] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1772), 2,
@@ -33997,7 +34031,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38208] = 3,
+  [38212] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1069), 2,
@@ -34012,7 +34046,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38226] = 3,
+  [38230] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1776), 2,
@@ -34027,7 +34061,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38244] = 3,
+  [38248] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1780), 2,
@@ -34042,7 +34076,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38262] = 3,
+  [38266] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1784), 2,
@@ -34057,7 +34091,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38280] = 3,
+  [38284] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1788), 2,
@@ -34072,7 +34106,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38298] = 3,
+  [38302] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1792), 2,
@@ -34087,7 +34121,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38316] = 3,
+  [38320] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1796), 2,
@@ -34102,7 +34136,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38334] = 3,
+  [38338] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1800), 2,
@@ -34117,7 +34151,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38352] = 3,
+  [38356] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1804), 2,
@@ -34132,7 +34166,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38370] = 3,
+  [38374] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1808), 2,
@@ -34147,7 +34181,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38388] = 3,
+  [38392] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1812), 2,
@@ -34162,7 +34196,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38406] = 3,
+  [38410] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1816), 2,
@@ -34177,7 +34211,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38424] = 7,
+  [38428] = 7,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
@@ -34196,7 +34230,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [38450] = 7,
+  [38454] = 7,
     ACTIONS(1578), 1,
       sym__identifier,
     ACTIONS(1582), 1,
@@ -34215,7 +34249,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [38476] = 3,
+  [38480] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1824), 2,
@@ -34230,7 +34264,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38494] = 7,
+  [38498] = 7,
     ACTIONS(1578), 1,
       sym__identifier,
     ACTIONS(1582), 1,
@@ -34249,7 +34283,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [38520] = 3,
+  [38524] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1830), 2,
@@ -34264,7 +34298,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38538] = 3,
+  [38542] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1834), 2,
@@ -34279,7 +34313,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38556] = 3,
+  [38560] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1838), 2,
@@ -34294,7 +34328,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38574] = 3,
+  [38578] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1842), 2,
@@ -34309,7 +34343,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38592] = 3,
+  [38596] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(929), 2,
@@ -34324,7 +34358,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38610] = 3,
+  [38614] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1846), 2,
@@ -34339,7 +34373,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38628] = 3,
+  [38632] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(893), 2,
@@ -34354,7 +34388,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38646] = 10,
+  [38650] = 11,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(7), 1,
@@ -34367,6 +34401,8 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_use,
     ACTIONS(1854), 1,
       anon_sym_fn,
+    ACTIONS(2624), 1,
+      anon_sym_data,
     ACTIONS(1856), 1,
       anon_sym_root,
     ACTIONS(1858), 1,
@@ -34376,7 +34412,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(782), 2,
       sym_annotation,
       aux_sym_module_repeat1,
-  [38678] = 3,
+  [38685] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1860), 2,
@@ -34391,7 +34427,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38696] = 3,
+  [38703] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1864), 2,
@@ -34406,7 +34442,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38714] = 3,
+  [38721] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1868), 2,
@@ -34421,7 +34457,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38732] = 3,
+  [38739] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1872), 2,
@@ -34436,7 +34472,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38750] = 3,
+  [38757] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1876), 2,
@@ -34451,7 +34487,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38768] = 3,
+  [38775] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1880), 2,
@@ -34466,7 +34502,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38786] = 3,
+  [38793] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1884), 2,
@@ -34481,7 +34517,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38804] = 3,
+  [38811] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1888), 2,
@@ -34496,7 +34532,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38822] = 3,
+  [38829] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1892), 2,
@@ -34511,7 +34547,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [38840] = 6,
+  [38847] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1896), 1,
@@ -34528,7 +34564,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [38863] = 6,
+  [38870] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
@@ -34545,7 +34581,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [38886] = 6,
+  [38893] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
@@ -34562,7 +34598,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [38909] = 6,
+  [38916] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
@@ -34579,7 +34615,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [38932] = 6,
+  [38939] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
@@ -34596,7 +34632,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [38955] = 6,
+  [38962] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
@@ -34613,7 +34649,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [38978] = 6,
+  [38985] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
@@ -34630,7 +34666,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [39001] = 6,
+  [39008] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1896), 1,
@@ -34647,7 +34683,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [39024] = 6,
+  [39031] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1896), 1,
@@ -34664,7 +34700,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [39047] = 6,
+  [39054] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1618), 1,
@@ -34681,7 +34717,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [39070] = 6,
+  [39077] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
@@ -34698,7 +34734,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [39093] = 6,
+  [39100] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1896), 1,
@@ -34715,7 +34751,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [39116] = 6,
+  [39123] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1896), 1,
@@ -34732,7 +34768,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [39139] = 6,
+  [39146] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1906), 1,
@@ -34749,7 +34785,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [39162] = 6,
+  [39169] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
@@ -34766,7 +34802,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [39185] = 6,
+  [39192] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
@@ -34783,7 +34819,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [39208] = 6,
+  [39215] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
@@ -34800,7 +34836,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [39231] = 6,
+  [39238] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1912), 1,
@@ -34817,7 +34853,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [39254] = 6,
+  [39261] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1912), 1,
@@ -34834,7 +34870,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [39277] = 6,
+  [39284] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1618), 1,
@@ -34851,7 +34887,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [39300] = 6,
+  [39307] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
@@ -34868,7 +34904,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [39323] = 6,
+  [39330] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1906), 1,
@@ -34885,7 +34921,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [39346] = 6,
+  [39353] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1918), 1,
@@ -34902,7 +34938,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [39369] = 6,
+  [39376] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
@@ -34919,7 +34955,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [39392] = 6,
+  [39399] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1896), 1,
@@ -34936,7 +34972,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [39415] = 6,
+  [39422] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1918), 1,
@@ -34953,7 +34989,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_slice_type,
       sym_array_type,
       sym_path,
-  [39438] = 4,
+  [39445] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1926), 1,
@@ -34961,14 +34997,15 @@ static uint16_t ts_small_parse_table[] = {
     STATE(782), 2,
       sym_annotation,
       aux_sym_module_repeat1,
-    ACTIONS(1924), 6,
+    ACTIONS(1924), 7,
       anon_sym_module,
       anon_sym_use,
       anon_sym_fn,
+      anon_sym_data,
       anon_sym_root,
       anon_sym_static,
       sym__identifier,
-  [39457] = 7,
+  [39465] = 7,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1906), 1,
@@ -34985,7 +35022,7 @@ static uint16_t ts_small_parse_table[] = {
       aux_sym_integral_token1,
       aux_sym_integral_token2,
       aux_sym_integral_token3,
-  [39481] = 7,
+  [39489] = 7,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1906), 1,
@@ -35002,7 +35039,7 @@ static uint16_t ts_small_parse_table[] = {
       aux_sym_integral_token1,
       aux_sym_integral_token2,
       aux_sym_integral_token3,
-  [39505] = 6,
+  [39513] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35016,7 +35053,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(862), 2,
       sym_parameter,
       sym_register,
-  [39525] = 6,
+  [39533] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35030,7 +35067,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(944), 2,
       sym_parameter,
       sym_register,
-  [39545] = 6,
+  [39553] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35044,7 +35081,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(866), 2,
       sym_parameter,
       sym_register,
-  [39565] = 6,
+  [39573] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35058,7 +35095,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(872), 2,
       sym_parameter,
       sym_register,
-  [39585] = 6,
+  [39593] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35072,7 +35109,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1054), 2,
       sym_parameter,
       sym_register,
-  [39605] = 6,
+  [39613] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35086,7 +35123,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(877), 2,
       sym_parameter,
       sym_register,
-  [39625] = 6,
+  [39633] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35100,7 +35137,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(880), 2,
       sym_parameter,
       sym_register,
-  [39645] = 6,
+  [39653] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35114,7 +35151,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(881), 2,
       sym_parameter,
       sym_register,
-  [39665] = 6,
+  [39673] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35128,7 +35165,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1054), 2,
       sym_parameter,
       sym_register,
-  [39685] = 6,
+  [39693] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35142,7 +35179,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(892), 2,
       sym_parameter,
       sym_register,
-  [39705] = 6,
+  [39713] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1906), 1,
@@ -35156,7 +35193,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1202), 2,
       sym__use_string,
       sym__use_identifier,
-  [39725] = 6,
+  [39733] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1906), 1,
@@ -35170,7 +35207,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1186), 2,
       sym__use_string,
       sym__use_identifier,
-  [39745] = 6,
+  [39753] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1906), 1,
@@ -35184,7 +35221,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1154), 2,
       sym__use_string,
       sym__use_identifier,
-  [39765] = 6,
+  [39773] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35198,7 +35235,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1054), 2,
       sym_parameter,
       sym_register,
-  [39785] = 6,
+  [39793] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35212,7 +35249,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1054), 2,
       sym_parameter,
       sym_register,
-  [39805] = 6,
+  [39813] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35226,7 +35263,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(972), 2,
       sym_parameter,
       sym_register,
-  [39825] = 6,
+  [39833] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1906), 1,
@@ -35240,7 +35277,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1156), 2,
       sym__use_string,
       sym__use_identifier,
-  [39845] = 6,
+  [39853] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35254,7 +35291,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1054), 2,
       sym_parameter,
       sym_register,
-  [39865] = 6,
+  [39873] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35268,7 +35305,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1054), 2,
       sym_parameter,
       sym_register,
-  [39885] = 6,
+  [39893] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35282,7 +35319,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1054), 2,
       sym_parameter,
       sym_register,
-  [39905] = 6,
+  [39913] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35296,7 +35333,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(858), 2,
       sym_parameter,
       sym_register,
-  [39925] = 6,
+  [39933] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35310,7 +35347,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1054), 2,
       sym_parameter,
       sym_register,
-  [39945] = 6,
+  [39953] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35324,7 +35361,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(981), 2,
       sym_parameter,
       sym_register,
-  [39965] = 6,
+  [39973] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35338,7 +35375,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1054), 2,
       sym_parameter,
       sym_register,
-  [39985] = 6,
+  [39993] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35352,7 +35389,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1054), 2,
       sym_parameter,
       sym_register,
-  [40005] = 6,
+  [40013] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35366,7 +35403,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(979), 2,
       sym_parameter,
       sym_register,
-  [40025] = 6,
+  [40033] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35380,7 +35417,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(974), 2,
       sym_parameter,
       sym_register,
-  [40045] = 6,
+  [40053] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35394,7 +35431,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1054), 2,
       sym_parameter,
       sym_register,
-  [40065] = 6,
+  [40073] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35408,7 +35445,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1054), 2,
       sym_parameter,
       sym_register,
-  [40085] = 6,
+  [40093] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35422,7 +35459,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1054), 2,
       sym_parameter,
       sym_register,
-  [40105] = 6,
+  [40113] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35436,7 +35473,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1054), 2,
       sym_parameter,
       sym_register,
-  [40125] = 6,
+  [40133] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35450,7 +35487,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1054), 2,
       sym_parameter,
       sym_register,
-  [40145] = 6,
+  [40153] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35464,7 +35501,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1054), 2,
       sym_parameter,
       sym_register,
-  [40165] = 6,
+  [40173] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35478,7 +35515,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(934), 2,
       sym_parameter,
       sym_register,
-  [40185] = 6,
+  [40193] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35492,7 +35529,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(929), 2,
       sym_parameter,
       sym_register,
-  [40205] = 6,
+  [40213] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35506,7 +35543,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1054), 2,
       sym_parameter,
       sym_register,
-  [40225] = 6,
+  [40233] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35520,7 +35557,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1054), 2,
       sym_parameter,
       sym_register,
-  [40245] = 6,
+  [40253] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35534,7 +35571,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1054), 2,
       sym_parameter,
       sym_register,
-  [40265] = 6,
+  [40273] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35548,7 +35585,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1054), 2,
       sym_parameter,
       sym_register,
-  [40285] = 6,
+  [40293] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35562,7 +35599,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1054), 2,
       sym_parameter,
       sym_register,
-  [40305] = 6,
+  [40313] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35576,7 +35613,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1054), 2,
       sym_parameter,
       sym_register,
-  [40325] = 6,
+  [40333] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35590,7 +35627,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(906), 2,
       sym_parameter,
       sym_register,
-  [40345] = 6,
+  [40353] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35604,7 +35641,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1054), 2,
       sym_parameter,
       sym_register,
-  [40365] = 6,
+  [40373] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35618,7 +35655,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1054), 2,
       sym_parameter,
       sym_register,
-  [40385] = 6,
+  [40393] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35632,7 +35669,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1054), 2,
       sym_parameter,
       sym_register,
-  [40405] = 6,
+  [40413] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35646,7 +35683,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1054), 2,
       sym_parameter,
       sym_register,
-  [40425] = 6,
+  [40433] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35660,7 +35697,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1054), 2,
       sym_parameter,
       sym_register,
-  [40445] = 6,
+  [40453] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35674,7 +35711,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1054), 2,
       sym_parameter,
       sym_register,
-  [40465] = 6,
+  [40473] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35688,7 +35725,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1054), 2,
       sym_parameter,
       sym_register,
-  [40485] = 6,
+  [40493] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35702,7 +35739,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1054), 2,
       sym_parameter,
       sym_register,
-  [40505] = 6,
+  [40513] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35716,7 +35753,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1054), 2,
       sym_parameter,
       sym_register,
-  [40525] = 6,
+  [40533] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35730,7 +35767,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1054), 2,
       sym_parameter,
       sym_register,
-  [40545] = 5,
+  [40553] = 5,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(362), 1,
@@ -35742,7 +35779,7 @@ static uint16_t ts_small_parse_table[] = {
     STATE(1054), 2,
       sym_parameter,
       sym_register,
-  [40562] = 3,
+  [40570] = 3,
     ACTIONS(150), 1,
       anon_sym_LF,
     ACTIONS(1586), 1,
@@ -35752,7 +35789,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_DOT,
       anon_sym_fn,
       anon_sym_COLON,
-  [40575] = 6,
+  [40583] = 6,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1578), 1,
@@ -35765,7 +35802,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_signature,
     STATE(1201), 1,
       sym_parameter,
-  [40594] = 4,
+  [40602] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2037), 1,
@@ -35775,7 +35812,7 @@ static uint16_t ts_small_parse_table[] = {
     ACTIONS(115), 2,
       sym__open,
       anon_sym_EQ,
-  [40608] = 4,
+  [40616] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2039), 1,
@@ -35785,7 +35822,7 @@ static uint16_t ts_small_parse_table[] = {
     ACTIONS(1345), 2,
       sym__close,
       sym__level,
-  [40622] = 4,
+  [40630] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2037), 1,
@@ -35795,7 +35832,7 @@ static uint16_t ts_small_parse_table[] = {
     ACTIONS(127), 2,
       sym__open,
       anon_sym_EQ,
-  [40636] = 4,
+  [40644] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2043), 1,
@@ -35805,7 +35842,7 @@ static uint16_t ts_small_parse_table[] = {
     ACTIONS(134), 2,
       sym__open,
       anon_sym_EQ,
-  [40650] = 5,
+  [40658] = 5,
     ACTIONS(129), 1,
       anon_sym_as,
     ACTIONS(134), 1,
@@ -35816,7 +35853,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_DOT,
     STATE(844), 1,
       aux_sym__use_path_repeat1,
-  [40666] = 5,
+  [40674] = 5,
     ACTIONS(1586), 1,
       sym__comment,
     ACTIONS(2049), 1,
@@ -35827,7 +35864,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_DOT,
     STATE(844), 1,
       aux_sym__use_path_repeat1,
-  [40682] = 5,
+  [40690] = 5,
     ACTIONS(1586), 1,
       sym__comment,
     ACTIONS(2055), 1,
@@ -35838,7 +35875,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_DOT,
     STATE(845), 1,
       aux_sym__use_path_repeat1,
-  [40698] = 4,
+  [40706] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -35847,7 +35884,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(1016), 1,
       sym_block,
-  [40711] = 4,
+  [40719] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -35856,7 +35893,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(1131), 1,
       sym_block,
-  [40724] = 4,
+  [40732] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(7), 1,
@@ -35865,7 +35902,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_fn,
     STATE(1216), 1,
       sym_identifier,
-  [40737] = 4,
+  [40745] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(7), 1,
@@ -35874,7 +35911,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_fn,
     STATE(1213), 1,
       sym_identifier,
-  [40750] = 4,
+  [40758] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1436), 1,
@@ -35883,7 +35920,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(686), 1,
       sym_block,
-  [40763] = 4,
+  [40771] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(7), 1,
@@ -35892,7 +35929,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_fn,
     STATE(1185), 1,
       sym_identifier,
-  [40776] = 4,
+  [40784] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1436), 1,
@@ -35901,7 +35938,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(674), 1,
       sym_block,
-  [40789] = 4,
+  [40797] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1436), 1,
@@ -35910,7 +35947,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(728), 1,
       sym_block,
-  [40802] = 4,
+  [40810] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1436), 1,
@@ -35919,7 +35956,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(678), 1,
       sym_block,
-  [40815] = 4,
+  [40823] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2025), 1,
@@ -35928,7 +35965,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_COMMA,
     STATE(950), 1,
       aux_sym_function_repeat1,
-  [40828] = 4,
+  [40836] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1995), 1,
@@ -35937,7 +35974,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_COMMA,
     STATE(950), 1,
       aux_sym_function_repeat1,
-  [40841] = 4,
+  [40849] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2083), 1,
@@ -35946,7 +35983,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_RPAREN,
     STATE(856), 1,
       aux_sym_function_repeat1,
-  [40854] = 4,
+  [40862] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1991), 1,
@@ -35955,7 +35992,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_COMMA,
     STATE(950), 1,
       aux_sym_function_repeat1,
-  [40867] = 4,
+  [40875] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1979), 1,
@@ -35964,7 +36001,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_COMMA,
     STATE(950), 1,
       aux_sym_function_repeat1,
-  [40880] = 4,
+  [40888] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1436), 1,
@@ -35973,7 +36010,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(685), 1,
       sym_block,
-  [40893] = 4,
+  [40901] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2093), 1,
@@ -35982,7 +36019,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_RPAREN,
     STATE(857), 1,
       aux_sym_function_repeat1,
-  [40906] = 4,
+  [40914] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1436), 1,
@@ -35991,7 +36028,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(689), 1,
       sym_block,
-  [40919] = 4,
+  [40927] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1436), 1,
@@ -36000,7 +36037,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(665), 1,
       sym_block,
-  [40932] = 4,
+  [40940] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1436), 1,
@@ -36009,7 +36046,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(711), 1,
       sym_block,
-  [40945] = 4,
+  [40953] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2103), 1,
@@ -36018,7 +36055,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_RPAREN,
     STATE(859), 1,
       aux_sym_function_repeat1,
-  [40958] = 4,
+  [40966] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1436), 1,
@@ -36027,7 +36064,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(716), 1,
       sym_block,
-  [40971] = 4,
+  [40979] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1436), 1,
@@ -36036,7 +36073,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(727), 1,
       sym_block,
-  [40984] = 4,
+  [40992] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1436), 1,
@@ -36045,7 +36082,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(729), 1,
       sym_block,
-  [40997] = 4,
+  [41005] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1969), 1,
@@ -36054,7 +36091,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_COMMA,
     STATE(950), 1,
       aux_sym_function_repeat1,
-  [41010] = 4,
+  [41018] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1967), 1,
@@ -36063,7 +36100,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_COMMA,
     STATE(950), 1,
       aux_sym_function_repeat1,
-  [41023] = 4,
+  [41031] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2117), 1,
@@ -36072,7 +36109,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_RPAREN,
     STATE(860), 1,
       aux_sym_function_repeat1,
-  [41036] = 4,
+  [41044] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1965), 1,
@@ -36081,7 +36118,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_COMMA,
     STATE(950), 1,
       aux_sym_function_repeat1,
-  [41049] = 3,
+  [41057] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2123), 1,
@@ -36089,7 +36126,7 @@ static uint16_t ts_small_parse_table[] = {
     ACTIONS(1353), 2,
       sym__close,
       sym__level,
-  [41060] = 4,
+  [41068] = 4,
     ACTIONS(1586), 1,
       sym__comment,
     ACTIONS(2125), 1,
@@ -36098,7 +36135,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_with,
     ACTIONS(2129), 1,
       anon_sym_as,
-  [41073] = 4,
+  [41081] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1616), 1,
@@ -36107,7 +36144,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_COMMA,
     STATE(879), 1,
       aux_sym_signature_repeat1,
-  [41086] = 4,
+  [41094] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2133), 1,
@@ -36116,7 +36153,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_RPAREN,
     STATE(870), 1,
       aux_sym_function_repeat1,
-  [41099] = 4,
+  [41107] = 4,
     ACTIONS(115), 1,
       anon_sym_LF,
     ACTIONS(1586), 1,
@@ -36125,7 +36162,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_DOT,
     STATE(937), 1,
       aux_sym__use_path_repeat1,
-  [41112] = 4,
+  [41120] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2139), 1,
@@ -36134,7 +36171,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_RPAREN,
     STATE(879), 1,
       aux_sym_signature_repeat1,
-  [41125] = 4,
+  [41133] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2144), 1,
@@ -36143,7 +36180,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_RPAREN,
     STATE(871), 1,
       aux_sym_function_repeat1,
-  [41138] = 4,
+  [41146] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2148), 1,
@@ -36152,7 +36189,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_RPAREN,
     STATE(873), 1,
       aux_sym_function_repeat1,
-  [41151] = 4,
+  [41159] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1436), 1,
@@ -36161,7 +36198,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(740), 1,
       sym_block,
-  [41164] = 4,
+  [41172] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1953), 1,
@@ -36170,7 +36207,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_COMMA,
     STATE(950), 1,
       aux_sym_function_repeat1,
-  [41177] = 4,
+  [41185] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1436), 1,
@@ -36179,7 +36216,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(753), 1,
       sym_block,
-  [41190] = 4,
+  [41198] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1436), 1,
@@ -36188,7 +36225,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(707), 1,
       sym_block,
-  [41203] = 4,
+  [41211] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1436), 1,
@@ -36197,7 +36234,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(733), 1,
       sym_block,
-  [41216] = 4,
+  [41224] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2162), 1,
@@ -36206,7 +36243,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_RPAREN,
     STATE(900), 1,
       aux_sym_signature_repeat1,
-  [41229] = 4,
+  [41237] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1945), 1,
@@ -36215,7 +36252,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_COMMA,
     STATE(950), 1,
       aux_sym_function_repeat1,
-  [41242] = 4,
+  [41250] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1436), 1,
@@ -36224,14 +36261,14 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(696), 1,
       sym_block,
-  [41255] = 2,
+  [41263] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(134), 3,
       sym__open,
       anon_sym_DOT,
       anon_sym_EQ,
-  [41264] = 4,
+  [41272] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1436), 1,
@@ -36240,7 +36277,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(719), 1,
       sym_block,
-  [41277] = 4,
+  [41285] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2172), 1,
@@ -36249,7 +36286,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_RPAREN,
     STATE(888), 1,
       aux_sym_function_repeat1,
-  [41290] = 4,
+  [41298] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1436), 1,
@@ -36258,7 +36295,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(697), 1,
       sym_block,
-  [41303] = 4,
+  [41311] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1436), 1,
@@ -36267,7 +36304,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(693), 1,
       sym_block,
-  [41316] = 4,
+  [41324] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
@@ -36276,7 +36313,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_parameter,
     STATE(1209), 1,
       sym_identifier,
-  [41329] = 4,
+  [41337] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2180), 1,
@@ -36285,7 +36322,7 @@ static uint16_t ts_small_parse_table[] = {
       sym__level,
     STATE(896), 1,
       aux_sym_block_repeat1,
-  [41342] = 4,
+  [41350] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1918), 1,
@@ -36294,7 +36331,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_field,
     STATE(40), 1,
       sym_identifier,
-  [41355] = 4,
+  [41363] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2185), 1,
@@ -36303,7 +36340,7 @@ static uint16_t ts_small_parse_table[] = {
       sym__level,
     STATE(896), 1,
       aux_sym_block_repeat1,
-  [41368] = 4,
+  [41376] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2189), 1,
@@ -36312,7 +36349,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_RPAREN,
     STATE(876), 1,
       aux_sym_signature_repeat1,
-  [41381] = 4,
+  [41389] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1666), 1,
@@ -36321,7 +36358,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_COMMA,
     STATE(879), 1,
       aux_sym_signature_repeat1,
-  [41394] = 4,
+  [41402] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2187), 1,
@@ -36330,7 +36367,7 @@ static uint16_t ts_small_parse_table[] = {
       sym__close,
     STATE(898), 1,
       aux_sym_block_repeat1,
-  [41407] = 4,
+  [41415] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1902), 1,
@@ -36339,7 +36376,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_identifier,
     STATE(120), 1,
       sym_field,
-  [41420] = 4,
+  [41428] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2031), 1,
@@ -36348,14 +36385,14 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_COMMA,
     STATE(950), 1,
       aux_sym_function_repeat1,
-  [41433] = 2,
+  [41441] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(150), 3,
       sym__open,
       anon_sym_DOT,
       anon_sym_EQ,
-  [41442] = 4,
+  [41450] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1436), 1,
@@ -36364,7 +36401,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(699), 1,
       sym_block,
-  [41455] = 4,
+  [41463] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2201), 1,
@@ -36373,7 +36410,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_RPAREN,
     STATE(883), 1,
       aux_sym_function_repeat1,
-  [41468] = 4,
+  [41476] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2033), 1,
@@ -36382,7 +36419,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_COMMA,
     STATE(950), 1,
       aux_sym_function_repeat1,
-  [41481] = 4,
+  [41489] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(415), 1,
@@ -36391,7 +36428,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_COMMA,
     STATE(930), 1,
       aux_sym_call_repeat1,
-  [41494] = 4,
+  [41502] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(7), 1,
@@ -36400,7 +36437,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_fn,
     STATE(1208), 1,
       sym_identifier,
-  [41507] = 4,
+  [41515] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1436), 1,
@@ -36409,7 +36446,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(747), 1,
       sym_block,
-  [41520] = 4,
+  [41528] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(485), 1,
@@ -36418,7 +36455,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_COMMA,
     STATE(965), 1,
       aux_sym_array_repeat1,
-  [41533] = 4,
+  [41541] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1436), 1,
@@ -36427,7 +36464,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(739), 1,
       sym_block,
-  [41546] = 4,
+  [41554] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
@@ -36436,7 +36473,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_parameter,
     STATE(1209), 1,
       sym_identifier,
-  [41559] = 4,
+  [41567] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1436), 1,
@@ -36445,7 +36482,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(720), 1,
       sym_block,
-  [41572] = 4,
+  [41580] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2023), 1,
@@ -36454,7 +36491,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_COMMA,
     STATE(950), 1,
       aux_sym_function_repeat1,
-  [41585] = 4,
+  [41593] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -36463,7 +36500,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(1152), 1,
       sym_block,
-  [41598] = 4,
+  [41606] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -36472,7 +36509,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(1126), 1,
       sym_block,
-  [41611] = 4,
+  [41619] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -36481,7 +36518,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(1097), 1,
       sym_block,
-  [41624] = 4,
+  [41632] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1436), 1,
@@ -36490,7 +36527,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(702), 1,
       sym_block,
-  [41637] = 4,
+  [41645] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -36499,7 +36536,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(1093), 1,
       sym_block,
-  [41650] = 4,
+  [41658] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1436), 1,
@@ -36508,7 +36545,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(709), 1,
       sym_block,
-  [41663] = 4,
+  [41671] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -36517,7 +36554,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(1003), 1,
       sym_block,
-  [41676] = 4,
+  [41684] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1436), 1,
@@ -36526,7 +36563,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(670), 1,
       sym_block,
-  [41689] = 4,
+  [41697] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2187), 1,
@@ -36535,7 +36572,7 @@ static uint16_t ts_small_parse_table[] = {
       sym__close,
     STATE(896), 1,
       aux_sym_block_repeat1,
-  [41702] = 4,
+  [41710] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -36544,7 +36581,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(1102), 1,
       sym_block,
-  [41715] = 4,
+  [41723] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -36553,7 +36590,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(1019), 1,
       sym_block,
-  [41728] = 4,
+  [41736] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -36562,7 +36599,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(1064), 1,
       sym_block,
-  [41741] = 4,
+  [41749] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -36571,7 +36608,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(1057), 1,
       sym_block,
-  [41754] = 4,
+  [41762] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2247), 1,
@@ -36580,7 +36617,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_RPAREN,
     STATE(903), 1,
       aux_sym_function_repeat1,
-  [41767] = 4,
+  [41775] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2251), 1,
@@ -36589,7 +36626,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_RPAREN,
     STATE(930), 1,
       aux_sym_call_repeat1,
-  [41780] = 4,
+  [41788] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2256), 1,
@@ -36598,7 +36635,7 @@ static uint16_t ts_small_parse_table[] = {
       sym__level,
     STATE(960), 1,
       aux_sym_module_repeat2,
-  [41793] = 4,
+  [41801] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -36607,7 +36644,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(1027), 1,
       sym_block,
-  [41806] = 4,
+  [41814] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -36616,7 +36653,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(1017), 1,
       sym_block,
-  [41819] = 4,
+  [41827] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2264), 1,
@@ -36625,7 +36662,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_RPAREN,
     STATE(907), 1,
       aux_sym_function_repeat1,
-  [41832] = 4,
+  [41840] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2011), 1,
@@ -36634,7 +36671,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_COMMA,
     STATE(950), 1,
       aux_sym_function_repeat1,
-  [41845] = 3,
+  [41853] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2270), 1,
@@ -36642,7 +36679,7 @@ static uint16_t ts_small_parse_table[] = {
     ACTIONS(1558), 2,
       sym__close,
       sym__level,
-  [41856] = 4,
+  [41864] = 4,
     ACTIONS(127), 1,
       anon_sym_LF,
     ACTIONS(1586), 1,
@@ -36651,7 +36688,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_DOT,
     STATE(844), 1,
       aux_sym__use_path_repeat1,
-  [41869] = 4,
+  [41877] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1436), 1,
@@ -36660,7 +36697,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(687), 1,
       sym_block,
-  [41882] = 4,
+  [41890] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -36669,7 +36706,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(1022), 1,
       sym_block,
-  [41895] = 4,
+  [41903] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2258), 1,
@@ -36678,7 +36715,7 @@ static uint16_t ts_small_parse_table[] = {
       sym__close,
     STATE(960), 1,
       aux_sym_module_repeat2,
-  [41908] = 4,
+  [41916] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2007), 1,
@@ -36687,7 +36724,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_COMMA,
     STATE(950), 1,
       aux_sym_function_repeat1,
-  [41921] = 4,
+  [41929] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -36696,7 +36733,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(1081), 1,
       sym_block,
-  [41934] = 4,
+  [41942] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1436), 1,
@@ -36705,7 +36742,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(712), 1,
       sym_block,
-  [41947] = 4,
+  [41955] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2284), 1,
@@ -36714,7 +36751,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_RPAREN,
     STATE(915), 1,
       aux_sym_function_repeat1,
-  [41960] = 4,
+  [41968] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2005), 1,
@@ -36723,7 +36760,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_COMMA,
     STATE(950), 1,
       aux_sym_function_repeat1,
-  [41973] = 4,
+  [41981] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -36732,7 +36769,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(999), 1,
       sym_block,
-  [41986] = 4,
+  [41994] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1436), 1,
@@ -36741,7 +36778,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(744), 1,
       sym_block,
-  [41999] = 4,
+  [42007] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2294), 1,
@@ -36750,7 +36787,7 @@ static uint16_t ts_small_parse_table[] = {
       sym__level,
     STATE(948), 1,
       aux_sym_data_repeat1,
-  [42012] = 4,
+  [42020] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -36759,7 +36796,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(1121), 1,
       sym_block,
-  [42025] = 4,
+  [42033] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2301), 1,
@@ -36768,7 +36805,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_RPAREN,
     STATE(950), 1,
       aux_sym_function_repeat1,
-  [42038] = 4,
+  [42046] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -36777,7 +36814,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(1140), 1,
       sym_block,
-  [42051] = 4,
+  [42059] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1436), 1,
@@ -36786,7 +36823,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(749), 1,
       sym_block,
-  [42064] = 4,
+  [42072] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -36795,7 +36832,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(1150), 1,
       sym_block,
-  [42077] = 4,
+  [42085] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2187), 1,
@@ -36804,7 +36841,7 @@ static uint16_t ts_small_parse_table[] = {
       sym__close,
     STATE(924), 1,
       aux_sym_block_repeat1,
-  [42090] = 4,
+  [42098] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -36813,7 +36850,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(1116), 1,
       sym_block,
-  [42103] = 4,
+  [42111] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -36822,7 +36859,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(1092), 1,
       sym_block,
-  [42116] = 4,
+  [42124] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -36831,7 +36868,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(1084), 1,
       sym_block,
-  [42129] = 4,
+  [42137] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2258), 1,
@@ -36840,7 +36877,7 @@ static uint16_t ts_small_parse_table[] = {
       sym__close,
     STATE(931), 1,
       aux_sym_module_repeat2,
-  [42142] = 4,
+  [42150] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1896), 1,
@@ -36849,7 +36886,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_field,
     STATE(466), 1,
       sym_identifier,
-  [42155] = 4,
+  [42163] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2322), 1,
@@ -36858,7 +36895,7 @@ static uint16_t ts_small_parse_table[] = {
       sym__level,
     STATE(960), 1,
       aux_sym_module_repeat2,
-  [42168] = 4,
+  [42176] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -36867,7 +36904,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(1078), 1,
       sym_block,
-  [42181] = 4,
+  [42189] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -36876,7 +36913,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(1062), 1,
       sym_block,
-  [42194] = 4,
+  [42202] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -36885,7 +36922,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(1048), 1,
       sym_block,
-  [42207] = 4,
+  [42215] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(533), 1,
@@ -36894,7 +36931,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_COMMA,
     STATE(930), 1,
       aux_sym_call_repeat1,
-  [42220] = 4,
+  [42228] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1265), 1,
@@ -36903,7 +36940,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_COMMA,
     STATE(965), 1,
       aux_sym_array_repeat1,
-  [42233] = 4,
+  [42241] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -36912,7 +36949,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(1046), 1,
       sym_block,
-  [42246] = 4,
+  [42254] = 4,
     ACTIONS(1586), 1,
       sym__comment,
     ACTIONS(2340), 1,
@@ -36921,7 +36958,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_fn,
     ACTIONS(2344), 1,
       anon_sym_COLON,
-  [42259] = 4,
+  [42267] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -36930,7 +36967,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(1125), 1,
       sym_block,
-  [42272] = 4,
+  [42280] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -36939,7 +36976,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(1136), 1,
       sym_block,
-  [42285] = 3,
+  [42293] = 3,
     ACTIONS(134), 1,
       anon_sym_LF,
     ACTIONS(1586), 1,
@@ -36947,7 +36984,7 @@ static uint16_t ts_small_parse_table[] = {
     ACTIONS(129), 2,
       anon_sym_as,
       anon_sym_DOT,
-  [42296] = 4,
+  [42304] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -36956,7 +36993,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(1144), 1,
       sym_block,
-  [42309] = 4,
+  [42317] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2352), 1,
@@ -36965,7 +37002,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_RPAREN,
     STATE(987), 1,
       aux_sym_function_repeat1,
-  [42322] = 4,
+  [42330] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2356), 1,
@@ -36974,7 +37011,7 @@ static uint16_t ts_small_parse_table[] = {
       sym__level,
     STATE(984), 1,
       aux_sym_data_repeat1,
-  [42335] = 4,
+  [42343] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2360), 1,
@@ -36983,7 +37020,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_RPAREN,
     STATE(935), 1,
       aux_sym_function_repeat1,
-  [42348] = 4,
+  [42356] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2187), 1,
@@ -36992,7 +37029,7 @@ static uint16_t ts_small_parse_table[] = {
       sym__close,
     STATE(896), 1,
       aux_sym_block_repeat1,
-  [42361] = 4,
+  [42369] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2258), 1,
@@ -37001,7 +37038,7 @@ static uint16_t ts_small_parse_table[] = {
       sym__close,
     STATE(982), 1,
       aux_sym_module_repeat2,
-  [42374] = 4,
+  [42382] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2258), 1,
@@ -37010,7 +37047,7 @@ static uint16_t ts_small_parse_table[] = {
       sym__close,
     STATE(940), 1,
       aux_sym_module_repeat2,
-  [42387] = 4,
+  [42395] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2358), 1,
@@ -37019,7 +37056,7 @@ static uint16_t ts_small_parse_table[] = {
       sym__close,
     STATE(985), 1,
       aux_sym_data_repeat1,
-  [42400] = 4,
+  [42408] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2372), 1,
@@ -37028,7 +37065,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_RPAREN,
     STATE(941), 1,
       aux_sym_function_repeat1,
-  [42413] = 4,
+  [42421] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(509), 1,
@@ -37037,7 +37074,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_COMMA,
     STATE(965), 1,
       aux_sym_array_repeat1,
-  [42426] = 4,
+  [42434] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2378), 1,
@@ -37046,7 +37083,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_RPAREN,
     STATE(945), 1,
       aux_sym_function_repeat1,
-  [42439] = 4,
+  [42447] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2258), 1,
@@ -37055,7 +37092,7 @@ static uint16_t ts_small_parse_table[] = {
       sym__close,
     STATE(960), 1,
       aux_sym_module_repeat2,
-  [42452] = 3,
+  [42460] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2384), 1,
@@ -37063,7 +37100,7 @@ static uint16_t ts_small_parse_table[] = {
     ACTIONS(1564), 2,
       sym__close,
       sym__level,
-  [42463] = 4,
+  [42471] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2358), 1,
@@ -37072,7 +37109,7 @@ static uint16_t ts_small_parse_table[] = {
       sym__close,
     STATE(948), 1,
       aux_sym_data_repeat1,
-  [42476] = 4,
+  [42484] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2358), 1,
@@ -37081,7 +37118,7 @@ static uint16_t ts_small_parse_table[] = {
       sym__close,
     STATE(948), 1,
       aux_sym_data_repeat1,
-  [42489] = 4,
+  [42497] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
@@ -37090,7 +37127,7 @@ static uint16_t ts_small_parse_table[] = {
       sym_parameter,
     STATE(1209), 1,
       sym_identifier,
-  [42502] = 4,
+  [42510] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1989), 1,
@@ -37099,7 +37136,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_COMMA,
     STATE(950), 1,
       aux_sym_function_repeat1,
-  [42515] = 4,
+  [42523] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2258), 1,
@@ -37108,7 +37145,7 @@ static uint16_t ts_small_parse_table[] = {
       sym__close,
     STATE(997), 1,
       aux_sym_module_repeat2,
-  [42528] = 4,
+  [42536] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1436), 1,
@@ -37117,7 +37154,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(675), 1,
       sym_block,
-  [42541] = 4,
+  [42549] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1436), 1,
@@ -37126,7 +37163,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(717), 1,
       sym_block,
-  [42554] = 4,
+  [42562] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(515), 1,
@@ -37135,7 +37172,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_COMMA,
     STATE(930), 1,
       aux_sym_call_repeat1,
-  [42567] = 4,
+  [42575] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2187), 1,
@@ -37144,7 +37181,7 @@ static uint16_t ts_small_parse_table[] = {
       sym__close,
     STATE(975), 1,
       aux_sym_block_repeat1,
-  [42580] = 4,
+  [42588] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -37153,7 +37190,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(1087), 1,
       sym_block,
-  [42593] = 4,
+  [42601] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -37162,7 +37199,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(1089), 1,
       sym_block,
-  [42606] = 4,
+  [42614] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(57), 1,
@@ -37171,7 +37208,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_EQ,
     STATE(1117), 1,
       sym_block,
-  [42619] = 4,
+  [42627] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(489), 1,
@@ -37180,7 +37217,7 @@ static uint16_t ts_small_parse_table[] = {
       anon_sym_COMMA,
     STATE(965), 1,
       aux_sym_array_repeat1,
-  [42632] = 4,
+  [42640] = 4,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2258), 1,
@@ -37189,1317 +37226,1437 @@ static uint16_t ts_small_parse_table[] = {
       sym__close,
     STATE(960), 1,
       aux_sym_module_repeat2,
-  [42645] = 3,
+  [42653] = 3,
     ACTIONS(1586), 1,
       sym__comment,
     ACTIONS(2412), 1,
       anon_sym_LF,
     ACTIONS(2414), 1,
       anon_sym_as,
-  [42655] = 2,
+  [42663] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1884), 2,
       sym__close,
       sym__level,
-  [42663] = 3,
+  [42671] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1912), 1,
       sym__identifier,
     STATE(574), 1,
       sym_identifier,
-  [42673] = 3,
+  [42681] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
       sym__identifier,
     STATE(1212), 1,
       sym_identifier,
-  [42683] = 2,
+  [42691] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(243), 2,
       sym__open,
       anon_sym_EQ,
-  [42691] = 2,
+  [42699] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1622), 2,
       sym__close,
       sym__level,
-  [42699] = 3,
+  [42707] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
       sym__identifier,
     STATE(1197), 1,
       sym_identifier,
-  [42709] = 3,
+  [42717] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2416), 1,
       anon_sym_SEMI_RBRACK,
     ACTIONS(2418), 1,
       anon_sym_SEMI,
-  [42719] = 3,
+  [42727] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1906), 1,
       sym__identifier,
     STATE(1179), 1,
       sym_identifier,
-  [42729] = 3,
+  [42737] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
       sym__identifier,
     STATE(1196), 1,
       sym_identifier,
-  [42739] = 2,
+  [42747] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(289), 2,
       sym__open,
       anon_sym_EQ,
-  [42747] = 3,
+  [42755] = 3,
     ACTIONS(1586), 1,
       sym__comment,
     ACTIONS(2420), 1,
       anon_sym_LF,
     ACTIONS(2422), 1,
       anon_sym_as,
-  [42757] = 3,
+  [42765] = 3,
     ACTIONS(1586), 1,
       sym__comment,
     ACTIONS(2424), 1,
       anon_sym_LF,
     ACTIONS(2426), 1,
       anon_sym_as,
-  [42767] = 2,
+  [42775] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1772), 2,
       sym__close,
       sym__level,
-  [42775] = 3,
+  [42783] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
       sym__identifier,
     STATE(1195), 1,
       sym_identifier,
-  [42785] = 2,
+  [42793] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2428), 2,
       anon_sym_COMMA,
       anon_sym_RPAREN,
-  [42793] = 3,
+  [42801] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
       sym__identifier,
     STATE(1203), 1,
       sym_identifier,
-  [42803] = 3,
+  [42811] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
       sym__identifier,
     STATE(1194), 1,
       sym_identifier,
-  [42813] = 2,
+  [42821] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1752), 2,
       sym__close,
       sym__level,
-  [42821] = 2,
+  [42829] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1788), 2,
       sym__close,
       sym__level,
-  [42829] = 2,
+  [42837] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1800), 2,
       sym__close,
       sym__level,
-  [42837] = 2,
+  [42845] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1660), 2,
       sym__close,
       sym__level,
-  [42845] = 2,
+  [42853] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1588), 2,
       sym__close,
       sym__level,
-  [42853] = 3,
+  [42861] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
       sym__identifier,
     STATE(1193), 1,
       sym_identifier,
-  [42863] = 2,
+  [42871] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1796), 2,
       sym__close,
       sym__level,
-  [42871] = 3,
+  [42879] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1906), 1,
       sym__identifier,
     STATE(970), 1,
       sym_identifier,
-  [42881] = 3,
+  [42889] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
       sym__identifier,
     STATE(1192), 1,
       sym_identifier,
-  [42891] = 3,
+  [42899] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1896), 1,
       sym__identifier,
     STATE(439), 1,
       sym_identifier,
-  [42901] = 2,
+  [42909] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1838), 2,
       sym__close,
       sym__level,
-  [42909] = 2,
+  [42917] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1748), 2,
       sym__close,
       sym__level,
-  [42917] = 2,
+  [42925] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(263), 2,
       sym__open,
       anon_sym_EQ,
-  [42925] = 2,
+  [42933] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1892), 2,
       sym__close,
       sym__level,
-  [42933] = 3,
+  [42941] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
       sym__identifier,
     STATE(1191), 1,
       sym_identifier,
-  [42943] = 3,
+  [42951] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2430), 1,
       anon_sym_EQ,
     ACTIONS(2432), 1,
       anon_sym_COLON,
-  [42953] = 2,
+  [42961] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1359), 2,
       sym__close,
       sym__level,
-  [42961] = 3,
+  [42969] = 3,
     ACTIONS(1586), 1,
       sym__comment,
     ACTIONS(2434), 1,
       anon_sym_LF,
     ACTIONS(2436), 1,
       anon_sym_as,
-  [42971] = 3,
+  [42979] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
       sym__identifier,
     STATE(1190), 1,
       sym_identifier,
-  [42981] = 2,
+  [42989] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1628), 2,
       sym__close,
       sym__level,
-  [42989] = 3,
+  [42997] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
       sym__identifier,
     STATE(1189), 1,
       sym_identifier,
-  [42999] = 3,
+  [43007] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
       sym__identifier,
     STATE(1188), 1,
       sym_identifier,
-  [43009] = 3,
+  [43017] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2438), 1,
       anon_sym_EQ,
     ACTIONS(2440), 1,
       anon_sym_COLON,
-  [43019] = 3,
+  [43027] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
       sym__identifier,
     STATE(1187), 1,
       sym_identifier,
-  [43029] = 3,
+  [43037] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
       sym__identifier,
     STATE(1211), 1,
       sym_identifier,
-  [43039] = 3,
+  [43047] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
       sym__identifier,
     STATE(327), 1,
       sym_identifier,
-  [43049] = 3,
+  [43057] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
       sym__identifier,
     STATE(1159), 1,
       sym_identifier,
-  [43059] = 2,
+  [43067] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2442), 2,
       sym__close,
       sym__level,
-  [43067] = 3,
+  [43075] = 3,
     ACTIONS(1586), 1,
       sym__comment,
     ACTIONS(2444), 1,
       anon_sym_LF,
     ACTIONS(2446), 1,
       anon_sym_as,
-  [43077] = 2,
+  [43085] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1888), 2,
       sym__close,
       sym__level,
-  [43085] = 2,
+  [43093] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1764), 2,
       sym__close,
       sym__level,
-  [43093] = 2,
+  [43101] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1069), 2,
       sym__close,
       sym__level,
-  [43101] = 2,
+  [43109] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1830), 2,
       sym__close,
       sym__level,
-  [43109] = 3,
+  [43117] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1902), 1,
       sym__identifier,
     STATE(23), 1,
       sym_identifier,
-  [43119] = 3,
+  [43127] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1902), 1,
       sym__identifier,
     STATE(105), 1,
       sym_identifier,
-  [43129] = 3,
+  [43137] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1896), 1,
       sym__identifier,
     STATE(841), 1,
       sym_identifier,
-  [43139] = 3,
+  [43147] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
       sym__identifier,
     STATE(1157), 1,
       sym_identifier,
-  [43149] = 2,
+  [43157] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1808), 2,
       sym__close,
       sym__level,
-  [43157] = 2,
+  [43165] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2448), 2,
       anon_sym_COMMA,
       anon_sym_RPAREN,
-  [43165] = 3,
+  [43173] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
       sym__identifier,
     STATE(1207), 1,
       sym_identifier,
-  [43175] = 3,
+  [43183] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2450), 1,
       anon_sym_EQ,
     ACTIONS(2452), 1,
       anon_sym_COLON,
-  [43185] = 2,
+  [43193] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1736), 2,
       sym__close,
       sym__level,
-  [43193] = 2,
+  [43201] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2454), 2,
       sym__close,
       sym__level,
-  [43201] = 3,
+  [43209] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
       sym__identifier,
     STATE(328), 1,
       sym_identifier,
-  [43211] = 3,
+  [43219] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
       sym__identifier,
     STATE(1206), 1,
       sym_identifier,
-  [43221] = 2,
+  [43229] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1816), 2,
       sym__close,
       sym__level,
-  [43229] = 2,
+  [43237] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1860), 2,
       sym__close,
       sym__level,
-  [43237] = 2,
+  [43245] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1686), 2,
       sym__close,
       sym__level,
-  [43245] = 2,
+  [43253] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1574), 2,
       sym__close,
       sym__level,
-  [43253] = 3,
+  [43261] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
       sym__identifier,
     STATE(1158), 1,
       sym_identifier,
-  [43263] = 2,
+  [43271] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1636), 2,
       sym__close,
       sym__level,
-  [43271] = 2,
+  [43279] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1383), 2,
       sym__close,
       sym__level,
-  [43279] = 3,
+  [43287] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
       sym__identifier,
     STATE(23), 1,
       sym_identifier,
-  [43289] = 2,
+  [43297] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1872), 2,
       sym__close,
       sym__level,
-  [43297] = 3,
+  [43305] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
       sym__identifier,
     STATE(1112), 1,
       sym_identifier,
-  [43307] = 2,
+  [43315] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1395), 2,
       sym__close,
       sym__level,
-  [43315] = 2,
+  [43323] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1744), 2,
       sym__close,
       sym__level,
-  [43323] = 2,
+  [43331] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1037), 2,
       sym__close,
       sym__level,
-  [43331] = 3,
+  [43339] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
       sym__identifier,
     STATE(1205), 1,
       sym_identifier,
-  [43341] = 2,
+  [43349] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1399), 2,
       sym__close,
       sym__level,
-  [43349] = 2,
+  [43357] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(261), 2,
       sym__open,
       anon_sym_EQ,
-  [43357] = 2,
+  [43365] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1089), 2,
       sym__close,
       sym__level,
-  [43365] = 2,
+  [43373] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1690), 2,
       sym__close,
       sym__level,
-  [43373] = 2,
+  [43381] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1674), 2,
       sym__close,
       sym__level,
-  [43381] = 2,
+  [43389] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2428), 2,
       sym__close,
       sym__level,
-  [43389] = 2,
+  [43397] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1834), 2,
       sym__close,
       sym__level,
-  [43397] = 2,
+  [43405] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1756), 2,
       sym__close,
       sym__level,
-  [43405] = 2,
+  [43413] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1371), 2,
       sym__close,
       sym__level,
-  [43413] = 2,
+  [43421] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1670), 2,
       sym__close,
       sym__level,
-  [43421] = 2,
+  [43429] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1117), 2,
       sym__close,
       sym__level,
-  [43429] = 2,
+  [43437] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(929), 2,
       sym__close,
       sym__level,
-  [43437] = 2,
+  [43445] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1868), 2,
       sym__close,
       sym__level,
-  [43445] = 3,
+  [43453] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1918), 1,
       sym__identifier,
     STATE(61), 1,
       sym_identifier,
-  [43455] = 2,
+  [43463] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1846), 2,
       sym__close,
       sym__level,
-  [43463] = 3,
+  [43471] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
       sym__identifier,
     STATE(890), 1,
       sym_identifier,
-  [43473] = 3,
+  [43481] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1906), 1,
       sym__identifier,
     STATE(1153), 1,
       sym_identifier,
-  [43483] = 2,
+  [43491] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1682), 2,
       sym__close,
       sym__level,
-  [43491] = 2,
+  [43499] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1608), 2,
       sym__close,
       sym__level,
-  [43499] = 2,
+  [43507] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1732), 2,
       sym__close,
       sym__level,
-  [43507] = 2,
+  [43515] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1864), 2,
       sym__close,
       sym__level,
-  [43515] = 3,
+  [43523] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2456), 1,
       anon_sym_SEMI_RBRACK,
     ACTIONS(2458), 1,
       anon_sym_SEMI,
-  [43525] = 2,
+  [43533] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1604), 2,
       sym__close,
       sym__level,
-  [43533] = 3,
+  [43541] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
       sym__identifier,
     STATE(1056), 1,
       sym_identifier,
-  [43543] = 3,
+  [43551] = 3,
     ACTIONS(255), 1,
       anon_sym_as,
     ACTIONS(257), 1,
       anon_sym_LF,
     ACTIONS(1586), 1,
       sym__comment,
-  [43553] = 3,
+  [43561] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
       sym__identifier,
     STATE(1180), 1,
       sym_identifier,
-  [43563] = 2,
+  [43571] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1017), 2,
       sym__close,
       sym__level,
-  [43571] = 2,
+  [43579] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1646), 2,
       sym__close,
       sym__level,
-  [43579] = 2,
+  [43587] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1824), 2,
       sym__close,
       sym__level,
-  [43587] = 3,
+  [43595] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1420), 1,
       sym__identifier,
     STATE(1177), 1,
       sym_identifier,
-  [43597] = 2,
+  [43605] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1780), 2,
       sym__close,
       sym__level,
-  [43605] = 3,
+  [43613] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
       sym__identifier,
     STATE(1176), 1,
       sym_identifier,
-  [43615] = 2,
+  [43623] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1570), 2,
       sym__close,
       sym__level,
-  [43623] = 3,
+  [43631] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2460), 1,
       anon_sym_SEMI_RBRACK,
     ACTIONS(2462), 1,
       anon_sym_SEMI,
-  [43633] = 2,
+  [43641] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1640), 2,
       sym__close,
       sym__level,
-  [43641] = 3,
+  [43649] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1896), 1,
       sym__identifier,
     STATE(427), 1,
       sym_identifier,
-  [43651] = 3,
+  [43659] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2464), 1,
       anon_sym_SEMI_RBRACK,
     ACTIONS(2466), 1,
       anon_sym_SEMI,
-  [43661] = 3,
+  [43669] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2468), 1,
       anon_sym_EQ,
     ACTIONS(2470), 1,
       anon_sym_COLON,
-  [43671] = 2,
+  [43679] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1768), 2,
       sym__close,
       sym__level,
-  [43679] = 3,
+  [43687] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1918), 1,
       sym__identifier,
     STATE(33), 1,
       sym_identifier,
-  [43689] = 3,
+  [43697] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2472), 1,
       anon_sym_SEMI_RBRACK,
     ACTIONS(2474), 1,
       anon_sym_SEMI,
-  [43699] = 2,
+  [43707] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1760), 2,
       sym__close,
       sym__level,
-  [43707] = 2,
+  [43715] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1740), 2,
       sym__close,
       sym__level,
-  [43715] = 3,
+  [43723] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1912), 1,
       sym__identifier,
     STATE(571), 1,
       sym_identifier,
-  [43725] = 3,
+  [43733] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2476), 1,
       anon_sym_SEMI_RBRACK,
     ACTIONS(2478), 1,
       anon_sym_SEMI,
-  [43735] = 3,
+  [43743] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
       sym__identifier,
     STATE(1173), 1,
       sym_identifier,
-  [43745] = 2,
+  [43753] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1714), 2,
       sym__close,
       sym__level,
-  [43753] = 3,
+  [43761] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
       sym__identifier,
     STATE(1038), 1,
       sym_identifier,
-  [43763] = 2,
+  [43771] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1706), 2,
       sym__close,
       sym__level,
-  [43771] = 3,
+  [43779] = 3,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1612), 1,
       sym__identifier,
     STATE(1031), 1,
       sym_identifier,
-  [43781] = 2,
+  [43789] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1698), 2,
       sym__close,
       sym__level,
-  [43789] = 2,
+  [43797] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1650), 2,
       sym__close,
       sym__level,
-  [43797] = 2,
+  [43805] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1702), 2,
       sym__close,
       sym__level,
-  [43805] = 2,
+  [43813] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2180), 2,
       sym__close,
       sym__level,
-  [43813] = 2,
+  [43821] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(893), 2,
       sym__close,
       sym__level,
-  [43821] = 2,
+  [43829] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1367), 2,
       sym__close,
       sym__level,
-  [43829] = 2,
+  [43837] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1654), 2,
       sym__close,
       sym__level,
-  [43837] = 2,
+  [43845] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1784), 2,
       sym__close,
       sym__level,
-  [43845] = 2,
+  [43853] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1842), 2,
       sym__close,
       sym__level,
-  [43853] = 3,
+  [43861] = 3,
     ACTIONS(1586), 1,
       sym__comment,
     ACTIONS(2480), 1,
       anon_sym_LF,
     ACTIONS(2482), 1,
       anon_sym_as,
-  [43863] = 2,
+  [43871] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1776), 2,
       sym__close,
       sym__level,
-  [43871] = 2,
+  [43879] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1728), 2,
       sym__close,
       sym__level,
-  [43879] = 2,
+  [43887] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1710), 2,
       sym__close,
       sym__level,
-  [43887] = 2,
+  [43895] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1632), 2,
       sym__close,
       sym__level,
-  [43895] = 2,
+  [43903] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1694), 2,
       sym__close,
       sym__level,
-  [43903] = 2,
+  [43911] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1812), 2,
       sym__close,
       sym__level,
-  [43911] = 2,
+  [43919] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2484), 2,
       anon_sym_COMMA,
       anon_sym_RPAREN,
-  [43919] = 2,
+  [43927] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1379), 2,
       sym__close,
       sym__level,
-  [43927] = 2,
+  [43935] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1387), 2,
       sym__close,
       sym__level,
-  [43935] = 2,
+  [43943] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1596), 2,
       sym__close,
       sym__level,
-  [43943] = 2,
+  [43951] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1375), 2,
       sym__close,
       sym__level,
-  [43951] = 2,
+  [43959] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1804), 2,
       sym__close,
       sym__level,
-  [43959] = 2,
+  [43967] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1592), 2,
       sym__close,
       sym__level,
-  [43967] = 2,
+  [43975] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1363), 2,
       sym__close,
       sym__level,
-  [43975] = 2,
+  [43983] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1391), 2,
       sym__close,
       sym__level,
-  [43983] = 2,
+  [43991] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1792), 2,
       sym__close,
       sym__level,
-  [43991] = 2,
+  [43999] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(969), 2,
       sym__close,
       sym__level,
-  [43999] = 2,
+  [44007] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(1678), 2,
       sym__close,
       sym__level,
-  [44007] = 2,
+  [44015] = 2,
     ACTIONS(1586), 1,
       sym__comment,
     ACTIONS(2486), 1,
       anon_sym_LF,
-  [44014] = 2,
+  [44022] = 2,
     ACTIONS(1586), 1,
       sym__comment,
     ACTIONS(2488), 1,
       anon_sym_LF,
-  [44021] = 2,
+  [44029] = 2,
     ACTIONS(1586), 1,
       sym__comment,
     ACTIONS(2490), 1,
       anon_sym_LF,
-  [44028] = 2,
+  [44036] = 2,
     ACTIONS(1586), 1,
       sym__comment,
     ACTIONS(2492), 1,
       anon_sym_LF,
-  [44035] = 2,
+  [44043] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2494), 1,
       anon_sym_LPAREN,
-  [44042] = 2,
+  [44050] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2496), 1,
       sym__open,
-  [44049] = 2,
+  [44057] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2498), 1,
       sym__open,
-  [44056] = 2,
+  [44064] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2500), 1,
       sym__identifier,
-  [44063] = 2,
+  [44071] = 2,
     ACTIONS(1586), 1,
       sym__comment,
     ACTIONS(2502), 1,
       anon_sym_LF,
-  [44070] = 2,
+  [44078] = 2,
     ACTIONS(1586), 1,
       sym__comment,
     ACTIONS(2504), 1,
       anon_sym_LF,
-  [44077] = 2,
+  [44085] = 2,
     ACTIONS(289), 1,
       anon_sym_LF,
     ACTIONS(1586), 1,
       sym__comment,
-  [44084] = 2,
+  [44092] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2506), 1,
       anon_sym_fn,
-  [44091] = 2,
+  [44099] = 2,
     ACTIONS(1586), 1,
       sym__comment,
     ACTIONS(2508), 1,
       anon_sym_LF,
-  [44098] = 2,
+  [44106] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2510), 1,
       sym__identifier,
-  [44105] = 2,
+  [44113] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2512), 1,
       sym_string,
-  [44112] = 2,
+  [44120] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2514), 1,
       anon_sym_fn,
-  [44119] = 2,
+  [44127] = 2,
     ACTIONS(1586), 1,
       sym__comment,
     ACTIONS(2516), 1,
       anon_sym_LF,
-  [44126] = 2,
+  [44134] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2518), 1,
       sym__identifier,
-  [44133] = 2,
+  [44141] = 2,
     ACTIONS(263), 1,
       anon_sym_LF,
     ACTIONS(1586), 1,
       sym__comment,
-  [44140] = 2,
+  [44148] = 2,
     ACTIONS(1586), 1,
       sym__comment,
     ACTIONS(2520), 1,
       anon_sym_LF,
-  [44147] = 2,
+  [44155] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2522), 1,
       anon_sym_LPAREN,
-  [44154] = 2,
+  [44162] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2524), 1,
       sym__open,
-  [44161] = 2,
+  [44169] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2526), 1,
       ts_builtin_sym_end,
-  [44168] = 2,
+  [44176] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2528), 1,
       anon_sym_LPAREN,
-  [44175] = 2,
+  [44183] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2530), 1,
       sym__open,
-  [44182] = 2,
+  [44190] = 2,
     ACTIONS(243), 1,
       anon_sym_LF,
     ACTIONS(1586), 1,
       sym__comment,
-  [44189] = 2,
+  [44197] = 2,
     ACTIONS(1586), 1,
       sym__comment,
     ACTIONS(2532), 1,
       anon_sym_LF,
-  [44196] = 2,
+  [44204] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2534), 1,
       anon_sym_LPAREN,
-  [44203] = 2,
+  [44211] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2536), 1,
       anon_sym_COLON,
-  [44210] = 2,
+  [44218] = 2,
     ACTIONS(1586), 1,
       sym__comment,
     ACTIONS(2428), 1,
       anon_sym_LF,
-  [44217] = 2,
+  [44225] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2538), 1,
       anon_sym_fn,
-  [44224] = 2,
+  [44232] = 2,
     ACTIONS(1586), 1,
       sym__comment,
     ACTIONS(2540), 1,
       anon_sym_LF,
-  [44231] = 2,
+  [44239] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2542), 1,
       anon_sym_fn,
-  [44238] = 2,
+  [44246] = 2,
     ACTIONS(1586), 1,
       sym__comment,
     ACTIONS(2544), 1,
       anon_sym_LF,
-  [44245] = 2,
+  [44253] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2546), 1,
       sym__open,
-  [44252] = 2,
+  [44260] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2548), 1,
       anon_sym_LPAREN,
-  [44259] = 2,
+  [44267] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2550), 1,
       sym__open,
-  [44266] = 2,
+  [44274] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2552), 1,
       anon_sym_LPAREN,
-  [44273] = 2,
+  [44281] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2554), 1,
       anon_sym_LPAREN,
-  [44280] = 2,
+  [44288] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2556), 1,
       sym__open,
-  [44287] = 2,
+  [44295] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2558), 1,
       anon_sym_LPAREN,
-  [44294] = 2,
+  [44302] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2560), 1,
       anon_sym_LPAREN,
-  [44301] = 2,
+  [44309] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2562), 1,
       anon_sym_LPAREN,
-  [44308] = 2,
+  [44316] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2564), 1,
       anon_sym_LPAREN,
-  [44315] = 2,
+  [44323] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2566), 1,
       anon_sym_LPAREN,
-  [44322] = 2,
+  [44330] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2568), 1,
       sym__open,
-  [44329] = 2,
+  [44337] = 2,
     ACTIONS(1586), 1,
       sym__comment,
     ACTIONS(2340), 1,
       anon_sym_LF,
-  [44336] = 2,
+  [44344] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2570), 1,
       sym__identifier,
-  [44343] = 2,
+  [44351] = 2,
     ACTIONS(1586), 1,
       sym__comment,
     ACTIONS(2572), 1,
       anon_sym_LF,
-  [44350] = 2,
+  [44358] = 2,
     ACTIONS(1586), 1,
       sym__comment,
     ACTIONS(2574), 1,
       anon_sym_LF,
-  [44357] = 2,
+  [44365] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2576), 1,
       anon_sym_LPAREN,
-  [44364] = 2,
+  [44372] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2578), 1,
       anon_sym_COLON,
-  [44371] = 2,
+  [44379] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2580), 1,
       anon_sym_LPAREN,
-  [44378] = 2,
+  [44386] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2582), 1,
       anon_sym_LPAREN,
-  [44385] = 2,
+  [44393] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2584), 1,
       anon_sym_LPAREN,
-  [44392] = 2,
+  [44400] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2586), 1,
       anon_sym_fn,
-  [44399] = 2,
+  [44407] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2588), 1,
       anon_sym_COLON,
-  [44406] = 2,
+  [44414] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2590), 1,
       anon_sym_COLON,
-  [44413] = 2,
+  [44421] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2592), 1,
       anon_sym_LPAREN,
-  [44420] = 2,
+  [44428] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2594), 1,
       anon_sym_LPAREN,
-  [44427] = 2,
+  [44435] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2596), 1,
       anon_sym_fn,
-  [44434] = 2,
+  [44442] = 2,
     ACTIONS(1586), 1,
       sym__comment,
     ACTIONS(2598), 1,
       anon_sym_LF,
-  [44441] = 2,
+  [44449] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2600), 1,
       anon_sym_fn,
-  [44448] = 2,
+  [44456] = 2,
     ACTIONS(3), 1,
       sym__comment,
     ACTIONS(2602), 1,
       anon_sym_fn,
+  [44463] = 3,
+    ACTIONS(3), 1,
+      sym__comment,
+    ACTIONS(1420), 1,
+      sym__identifier,
+    STATE(1218), 1,
+      sym_identifier,
+  [44473] = 2,
+    ACTIONS(3), 1,
+      sym__comment,
+    ACTIONS(2604), 1,
+      sym__open,
+  [44480] = 4,
+    ACTIONS(3), 1,
+      sym__comment,
+    ACTIONS(1612), 1,
+      sym__identifier,
+    STATE(1220), 1,
+      sym_parameter,
+    STATE(1209), 1,
+      sym_identifier,
+  [44493] = 4,
+    ACTIONS(3), 1,
+      sym__comment,
+    ACTIONS(2606), 1,
+      sym__close,
+    ACTIONS(2358), 1,
+      sym__level,
+    STATE(1221), 1,
+      aux_sym_data_repeat1,
+  [44506] = 4,
+    ACTIONS(3), 1,
+      sym__comment,
+    ACTIONS(2358), 1,
+      sym__level,
+    ACTIONS(2608), 1,
+      sym__close,
+    STATE(948), 1,
+      aux_sym_data_repeat1,
+  [44519] = 3,
+    ACTIONS(3), 1,
+      sym__comment,
+    ACTIONS(2610), 2,
+      ts_builtin_sym_end,
+      anon_sym_AT_AT,
+    ACTIONS(2612), 8,
+      anon_sym_AT,
+      anon_sym_module,
+      anon_sym_use,
+      anon_sym_fn,
+      anon_sym_data,
+      anon_sym_root,
+      anon_sym_static,
+      sym__identifier,
+  [44537] = 3,
+    ACTIONS(3), 1,
+      sym__comment,
+    ACTIONS(2614), 2,
+      ts_builtin_sym_end,
+      anon_sym_AT_AT,
+    ACTIONS(2616), 8,
+      anon_sym_AT,
+      anon_sym_module,
+      anon_sym_use,
+      anon_sym_fn,
+      anon_sym_data,
+      anon_sym_root,
+      anon_sym_static,
+      sym__identifier,
+  [44555] = 3,
+    ACTIONS(3), 1,
+      sym__comment,
+    ACTIONS(1420), 1,
+      sym__identifier,
+    STATE(1225), 1,
+      sym_identifier,
+  [44565] = 2,
+    ACTIONS(3), 1,
+      sym__comment,
+    ACTIONS(2618), 1,
+      sym__open,
+  [44572] = 4,
+    ACTIONS(3), 1,
+      sym__comment,
+    ACTIONS(1612), 1,
+      sym__identifier,
+    STATE(1227), 1,
+      sym_parameter,
+    STATE(1209), 1,
+      sym_identifier,
+  [44585] = 4,
+    ACTIONS(3), 1,
+      sym__comment,
+    ACTIONS(2358), 1,
+      sym__level,
+    ACTIONS(2620), 1,
+      sym__close,
+    STATE(1228), 1,
+      aux_sym_data_repeat1,
+  [44598] = 4,
+    ACTIONS(3), 1,
+      sym__comment,
+    ACTIONS(2358), 1,
+      sym__level,
+    ACTIONS(2622), 1,
+      sym__close,
+    STATE(948), 1,
+      aux_sym_data_repeat1,
+  [44611] = 2,
+    ACTIONS(3), 1,
+      sym__comment,
+    ACTIONS(2610), 2,
+      sym__close,
+      sym__level,
+  [44619] = 2,
+    ACTIONS(3), 1,
+      sym__comment,
+    ACTIONS(2614), 2,
+      sym__close,
+      sym__level,
 };
 
 static uint32_t ts_small_parse_table_map[] = {
@@ -38927,797 +39084,811 @@ static uint32_t ts_small_parse_table_map[] = {
   [SMALL_STATE(423)] = 26206,
   [SMALL_STATE(424)] = 26248,
   [SMALL_STATE(425)] = 26288,
-  [SMALL_STATE(426)] = 26352,
-  [SMALL_STATE(427)] = 26391,
-  [SMALL_STATE(428)] = 26426,
-  [SMALL_STATE(429)] = 26464,
-  [SMALL_STATE(430)] = 26502,
-  [SMALL_STATE(431)] = 26540,
-  [SMALL_STATE(432)] = 26580,
-  [SMALL_STATE(433)] = 26631,
-  [SMALL_STATE(434)] = 26664,
-  [SMALL_STATE(435)] = 26697,
-  [SMALL_STATE(436)] = 26730,
-  [SMALL_STATE(437)] = 26763,
-  [SMALL_STATE(438)] = 26796,
-  [SMALL_STATE(439)] = 26829,
-  [SMALL_STATE(440)] = 26862,
-  [SMALL_STATE(441)] = 26899,
-  [SMALL_STATE(442)] = 26940,
-  [SMALL_STATE(443)] = 26985,
-  [SMALL_STATE(444)] = 27034,
-  [SMALL_STATE(445)] = 27067,
-  [SMALL_STATE(446)] = 27114,
-  [SMALL_STATE(447)] = 27157,
-  [SMALL_STATE(448)] = 27212,
-  [SMALL_STATE(449)] = 27269,
-  [SMALL_STATE(450)] = 27302,
-  [SMALL_STATE(451)] = 27335,
-  [SMALL_STATE(452)] = 27368,
-  [SMALL_STATE(453)] = 27401,
-  [SMALL_STATE(454)] = 27462,
-  [SMALL_STATE(455)] = 27495,
-  [SMALL_STATE(456)] = 27528,
-  [SMALL_STATE(457)] = 27561,
-  [SMALL_STATE(458)] = 27594,
-  [SMALL_STATE(459)] = 27627,
-  [SMALL_STATE(460)] = 27660,
-  [SMALL_STATE(461)] = 27693,
-  [SMALL_STATE(462)] = 27726,
-  [SMALL_STATE(463)] = 27759,
-  [SMALL_STATE(464)] = 27796,
-  [SMALL_STATE(465)] = 27829,
-  [SMALL_STATE(466)] = 27862,
-  [SMALL_STATE(467)] = 27897,
-  [SMALL_STATE(468)] = 27959,
-  [SMALL_STATE(469)] = 28021,
-  [SMALL_STATE(470)] = 28083,
-  [SMALL_STATE(471)] = 28145,
-  [SMALL_STATE(472)] = 28205,
-  [SMALL_STATE(473)] = 28267,
-  [SMALL_STATE(474)] = 28329,
-  [SMALL_STATE(475)] = 28386,
-  [SMALL_STATE(476)] = 28443,
-  [SMALL_STATE(477)] = 28500,
-  [SMALL_STATE(478)] = 28557,
-  [SMALL_STATE(479)] = 28614,
-  [SMALL_STATE(480)] = 28671,
-  [SMALL_STATE(481)] = 28730,
-  [SMALL_STATE(482)] = 28787,
-  [SMALL_STATE(483)] = 28844,
-  [SMALL_STATE(484)] = 28901,
-  [SMALL_STATE(485)] = 28958,
-  [SMALL_STATE(486)] = 29015,
-  [SMALL_STATE(487)] = 29072,
-  [SMALL_STATE(488)] = 29129,
-  [SMALL_STATE(489)] = 29186,
-  [SMALL_STATE(490)] = 29243,
-  [SMALL_STATE(491)] = 29302,
-  [SMALL_STATE(492)] = 29359,
-  [SMALL_STATE(493)] = 29416,
-  [SMALL_STATE(494)] = 29473,
-  [SMALL_STATE(495)] = 29530,
-  [SMALL_STATE(496)] = 29587,
-  [SMALL_STATE(497)] = 29644,
-  [SMALL_STATE(498)] = 29701,
-  [SMALL_STATE(499)] = 29758,
-  [SMALL_STATE(500)] = 29815,
-  [SMALL_STATE(501)] = 29872,
-  [SMALL_STATE(502)] = 29929,
-  [SMALL_STATE(503)] = 29986,
-  [SMALL_STATE(504)] = 30043,
-  [SMALL_STATE(505)] = 30100,
-  [SMALL_STATE(506)] = 30157,
-  [SMALL_STATE(507)] = 30214,
-  [SMALL_STATE(508)] = 30271,
-  [SMALL_STATE(509)] = 30328,
-  [SMALL_STATE(510)] = 30387,
-  [SMALL_STATE(511)] = 30444,
-  [SMALL_STATE(512)] = 30501,
-  [SMALL_STATE(513)] = 30558,
-  [SMALL_STATE(514)] = 30615,
-  [SMALL_STATE(515)] = 30672,
-  [SMALL_STATE(516)] = 30729,
-  [SMALL_STATE(517)] = 30786,
-  [SMALL_STATE(518)] = 30843,
-  [SMALL_STATE(519)] = 30900,
-  [SMALL_STATE(520)] = 30957,
-  [SMALL_STATE(521)] = 31014,
-  [SMALL_STATE(522)] = 31071,
-  [SMALL_STATE(523)] = 31128,
-  [SMALL_STATE(524)] = 31185,
-  [SMALL_STATE(525)] = 31242,
-  [SMALL_STATE(526)] = 31299,
-  [SMALL_STATE(527)] = 31356,
-  [SMALL_STATE(528)] = 31413,
-  [SMALL_STATE(529)] = 31470,
-  [SMALL_STATE(530)] = 31527,
-  [SMALL_STATE(531)] = 31584,
-  [SMALL_STATE(532)] = 31641,
-  [SMALL_STATE(533)] = 31698,
-  [SMALL_STATE(534)] = 31755,
-  [SMALL_STATE(535)] = 31812,
-  [SMALL_STATE(536)] = 31869,
-  [SMALL_STATE(537)] = 31926,
-  [SMALL_STATE(538)] = 31983,
-  [SMALL_STATE(539)] = 32040,
-  [SMALL_STATE(540)] = 32097,
-  [SMALL_STATE(541)] = 32154,
-  [SMALL_STATE(542)] = 32211,
-  [SMALL_STATE(543)] = 32268,
-  [SMALL_STATE(544)] = 32325,
-  [SMALL_STATE(545)] = 32382,
-  [SMALL_STATE(546)] = 32439,
-  [SMALL_STATE(547)] = 32496,
-  [SMALL_STATE(548)] = 32553,
-  [SMALL_STATE(549)] = 32609,
-  [SMALL_STATE(550)] = 32639,
-  [SMALL_STATE(551)] = 32695,
-  [SMALL_STATE(552)] = 32729,
-  [SMALL_STATE(553)] = 32785,
-  [SMALL_STATE(554)] = 32841,
-  [SMALL_STATE(555)] = 32897,
-  [SMALL_STATE(556)] = 32953,
-  [SMALL_STATE(557)] = 33009,
-  [SMALL_STATE(558)] = 33043,
-  [SMALL_STATE(559)] = 33099,
-  [SMALL_STATE(560)] = 33155,
-  [SMALL_STATE(561)] = 33211,
-  [SMALL_STATE(562)] = 33267,
-  [SMALL_STATE(563)] = 33323,
-  [SMALL_STATE(564)] = 33379,
-  [SMALL_STATE(565)] = 33435,
-  [SMALL_STATE(566)] = 33491,
-  [SMALL_STATE(567)] = 33525,
-  [SMALL_STATE(568)] = 33581,
-  [SMALL_STATE(569)] = 33637,
-  [SMALL_STATE(570)] = 33693,
-  [SMALL_STATE(571)] = 33749,
-  [SMALL_STATE(572)] = 33778,
-  [SMALL_STATE(573)] = 33833,
-  [SMALL_STATE(574)] = 33888,
-  [SMALL_STATE(575)] = 33921,
-  [SMALL_STATE(576)] = 33951,
-  [SMALL_STATE(577)] = 33979,
-  [SMALL_STATE(578)] = 34007,
-  [SMALL_STATE(579)] = 34035,
-  [SMALL_STATE(580)] = 34062,
-  [SMALL_STATE(581)] = 34089,
-  [SMALL_STATE(582)] = 34116,
-  [SMALL_STATE(583)] = 34143,
-  [SMALL_STATE(584)] = 34170,
-  [SMALL_STATE(585)] = 34197,
-  [SMALL_STATE(586)] = 34224,
-  [SMALL_STATE(587)] = 34251,
-  [SMALL_STATE(588)] = 34278,
-  [SMALL_STATE(589)] = 34305,
-  [SMALL_STATE(590)] = 34332,
-  [SMALL_STATE(591)] = 34361,
-  [SMALL_STATE(592)] = 34404,
-  [SMALL_STATE(593)] = 34447,
-  [SMALL_STATE(594)] = 34490,
-  [SMALL_STATE(595)] = 34533,
-  [SMALL_STATE(596)] = 34562,
-  [SMALL_STATE(597)] = 34605,
-  [SMALL_STATE(598)] = 34634,
-  [SMALL_STATE(599)] = 34671,
-  [SMALL_STATE(600)] = 34708,
-  [SMALL_STATE(601)] = 34745,
-  [SMALL_STATE(602)] = 34782,
-  [SMALL_STATE(603)] = 34819,
-  [SMALL_STATE(604)] = 34856,
-  [SMALL_STATE(605)] = 34893,
-  [SMALL_STATE(606)] = 34930,
-  [SMALL_STATE(607)] = 34967,
-  [SMALL_STATE(608)] = 35004,
-  [SMALL_STATE(609)] = 35041,
-  [SMALL_STATE(610)] = 35078,
-  [SMALL_STATE(611)] = 35115,
-  [SMALL_STATE(612)] = 35152,
-  [SMALL_STATE(613)] = 35189,
-  [SMALL_STATE(614)] = 35226,
-  [SMALL_STATE(615)] = 35263,
-  [SMALL_STATE(616)] = 35300,
-  [SMALL_STATE(617)] = 35337,
-  [SMALL_STATE(618)] = 35374,
-  [SMALL_STATE(619)] = 35411,
-  [SMALL_STATE(620)] = 35448,
-  [SMALL_STATE(621)] = 35485,
-  [SMALL_STATE(622)] = 35522,
-  [SMALL_STATE(623)] = 35559,
-  [SMALL_STATE(624)] = 35596,
-  [SMALL_STATE(625)] = 35633,
-  [SMALL_STATE(626)] = 35670,
-  [SMALL_STATE(627)] = 35707,
-  [SMALL_STATE(628)] = 35744,
-  [SMALL_STATE(629)] = 35781,
-  [SMALL_STATE(630)] = 35818,
-  [SMALL_STATE(631)] = 35855,
-  [SMALL_STATE(632)] = 35892,
-  [SMALL_STATE(633)] = 35929,
-  [SMALL_STATE(634)] = 35966,
-  [SMALL_STATE(635)] = 36003,
-  [SMALL_STATE(636)] = 36040,
-  [SMALL_STATE(637)] = 36077,
-  [SMALL_STATE(638)] = 36114,
-  [SMALL_STATE(639)] = 36151,
-  [SMALL_STATE(640)] = 36188,
-  [SMALL_STATE(641)] = 36225,
-  [SMALL_STATE(642)] = 36262,
-  [SMALL_STATE(643)] = 36299,
-  [SMALL_STATE(644)] = 36336,
-  [SMALL_STATE(645)] = 36373,
-  [SMALL_STATE(646)] = 36410,
-  [SMALL_STATE(647)] = 36447,
-  [SMALL_STATE(648)] = 36484,
-  [SMALL_STATE(649)] = 36521,
-  [SMALL_STATE(650)] = 36558,
-  [SMALL_STATE(651)] = 36595,
-  [SMALL_STATE(652)] = 36632,
-  [SMALL_STATE(653)] = 36669,
-  [SMALL_STATE(654)] = 36706,
-  [SMALL_STATE(655)] = 36743,
-  [SMALL_STATE(656)] = 36780,
-  [SMALL_STATE(657)] = 36817,
-  [SMALL_STATE(658)] = 36854,
-  [SMALL_STATE(659)] = 36891,
-  [SMALL_STATE(660)] = 36928,
-  [SMALL_STATE(661)] = 36965,
-  [SMALL_STATE(662)] = 37002,
-  [SMALL_STATE(663)] = 37023,
-  [SMALL_STATE(664)] = 37044,
-  [SMALL_STATE(665)] = 37062,
-  [SMALL_STATE(666)] = 37080,
-  [SMALL_STATE(667)] = 37106,
-  [SMALL_STATE(668)] = 37124,
-  [SMALL_STATE(669)] = 37142,
-  [SMALL_STATE(670)] = 37160,
-  [SMALL_STATE(671)] = 37178,
-  [SMALL_STATE(672)] = 37196,
-  [SMALL_STATE(673)] = 37222,
-  [SMALL_STATE(674)] = 37248,
-  [SMALL_STATE(675)] = 37266,
-  [SMALL_STATE(676)] = 37284,
-  [SMALL_STATE(677)] = 37310,
-  [SMALL_STATE(678)] = 37336,
-  [SMALL_STATE(679)] = 37354,
-  [SMALL_STATE(680)] = 37380,
-  [SMALL_STATE(681)] = 37398,
-  [SMALL_STATE(682)] = 37416,
-  [SMALL_STATE(683)] = 37434,
-  [SMALL_STATE(684)] = 37452,
-  [SMALL_STATE(685)] = 37478,
-  [SMALL_STATE(686)] = 37496,
-  [SMALL_STATE(687)] = 37514,
-  [SMALL_STATE(688)] = 37532,
-  [SMALL_STATE(689)] = 37558,
-  [SMALL_STATE(690)] = 37576,
-  [SMALL_STATE(691)] = 37602,
-  [SMALL_STATE(692)] = 37628,
-  [SMALL_STATE(693)] = 37654,
-  [SMALL_STATE(694)] = 37672,
-  [SMALL_STATE(695)] = 37690,
-  [SMALL_STATE(696)] = 37708,
-  [SMALL_STATE(697)] = 37726,
-  [SMALL_STATE(698)] = 37744,
-  [SMALL_STATE(699)] = 37762,
-  [SMALL_STATE(700)] = 37780,
-  [SMALL_STATE(701)] = 37798,
-  [SMALL_STATE(702)] = 37816,
-  [SMALL_STATE(703)] = 37834,
-  [SMALL_STATE(704)] = 37852,
-  [SMALL_STATE(705)] = 37870,
-  [SMALL_STATE(706)] = 37888,
-  [SMALL_STATE(707)] = 37906,
-  [SMALL_STATE(708)] = 37924,
-  [SMALL_STATE(709)] = 37956,
-  [SMALL_STATE(710)] = 37974,
-  [SMALL_STATE(711)] = 37992,
-  [SMALL_STATE(712)] = 38010,
-  [SMALL_STATE(713)] = 38028,
-  [SMALL_STATE(714)] = 38046,
-  [SMALL_STATE(715)] = 38064,
-  [SMALL_STATE(716)] = 38082,
-  [SMALL_STATE(717)] = 38100,
-  [SMALL_STATE(718)] = 38118,
-  [SMALL_STATE(719)] = 38136,
-  [SMALL_STATE(720)] = 38154,
-  [SMALL_STATE(721)] = 38172,
-  [SMALL_STATE(722)] = 38190,
-  [SMALL_STATE(723)] = 38208,
-  [SMALL_STATE(724)] = 38226,
-  [SMALL_STATE(725)] = 38244,
-  [SMALL_STATE(726)] = 38262,
-  [SMALL_STATE(727)] = 38280,
-  [SMALL_STATE(728)] = 38298,
-  [SMALL_STATE(729)] = 38316,
-  [SMALL_STATE(730)] = 38334,
-  [SMALL_STATE(731)] = 38352,
-  [SMALL_STATE(732)] = 38370,
-  [SMALL_STATE(733)] = 38388,
-  [SMALL_STATE(734)] = 38406,
-  [SMALL_STATE(735)] = 38424,
-  [SMALL_STATE(736)] = 38450,
-  [SMALL_STATE(737)] = 38476,
-  [SMALL_STATE(738)] = 38494,
-  [SMALL_STATE(739)] = 38520,
-  [SMALL_STATE(740)] = 38538,
-  [SMALL_STATE(741)] = 38556,
-  [SMALL_STATE(742)] = 38574,
-  [SMALL_STATE(743)] = 38592,
-  [SMALL_STATE(744)] = 38610,
-  [SMALL_STATE(745)] = 38628,
-  [SMALL_STATE(746)] = 38646,
-  [SMALL_STATE(747)] = 38678,
-  [SMALL_STATE(748)] = 38696,
-  [SMALL_STATE(749)] = 38714,
-  [SMALL_STATE(750)] = 38732,
-  [SMALL_STATE(751)] = 38750,
-  [SMALL_STATE(752)] = 38768,
-  [SMALL_STATE(753)] = 38786,
-  [SMALL_STATE(754)] = 38804,
-  [SMALL_STATE(755)] = 38822,
-  [SMALL_STATE(756)] = 38840,
-  [SMALL_STATE(757)] = 38863,
-  [SMALL_STATE(758)] = 38886,
-  [SMALL_STATE(759)] = 38909,
-  [SMALL_STATE(760)] = 38932,
-  [SMALL_STATE(761)] = 38955,
-  [SMALL_STATE(762)] = 38978,
-  [SMALL_STATE(763)] = 39001,
-  [SMALL_STATE(764)] = 39024,
-  [SMALL_STATE(765)] = 39047,
-  [SMALL_STATE(766)] = 39070,
-  [SMALL_STATE(767)] = 39093,
-  [SMALL_STATE(768)] = 39116,
-  [SMALL_STATE(769)] = 39139,
-  [SMALL_STATE(770)] = 39162,
-  [SMALL_STATE(771)] = 39185,
-  [SMALL_STATE(772)] = 39208,
-  [SMALL_STATE(773)] = 39231,
-  [SMALL_STATE(774)] = 39254,
-  [SMALL_STATE(775)] = 39277,
-  [SMALL_STATE(776)] = 39300,
-  [SMALL_STATE(777)] = 39323,
-  [SMALL_STATE(778)] = 39346,
-  [SMALL_STATE(779)] = 39369,
-  [SMALL_STATE(780)] = 39392,
-  [SMALL_STATE(781)] = 39415,
-  [SMALL_STATE(782)] = 39438,
-  [SMALL_STATE(783)] = 39457,
-  [SMALL_STATE(784)] = 39481,
-  [SMALL_STATE(785)] = 39505,
-  [SMALL_STATE(786)] = 39525,
-  [SMALL_STATE(787)] = 39545,
-  [SMALL_STATE(788)] = 39565,
-  [SMALL_STATE(789)] = 39585,
-  [SMALL_STATE(790)] = 39605,
-  [SMALL_STATE(791)] = 39625,
-  [SMALL_STATE(792)] = 39645,
-  [SMALL_STATE(793)] = 39665,
-  [SMALL_STATE(794)] = 39685,
-  [SMALL_STATE(795)] = 39705,
-  [SMALL_STATE(796)] = 39725,
-  [SMALL_STATE(797)] = 39745,
-  [SMALL_STATE(798)] = 39765,
-  [SMALL_STATE(799)] = 39785,
-  [SMALL_STATE(800)] = 39805,
-  [SMALL_STATE(801)] = 39825,
-  [SMALL_STATE(802)] = 39845,
-  [SMALL_STATE(803)] = 39865,
-  [SMALL_STATE(804)] = 39885,
-  [SMALL_STATE(805)] = 39905,
-  [SMALL_STATE(806)] = 39925,
-  [SMALL_STATE(807)] = 39945,
-  [SMALL_STATE(808)] = 39965,
-  [SMALL_STATE(809)] = 39985,
-  [SMALL_STATE(810)] = 40005,
-  [SMALL_STATE(811)] = 40025,
-  [SMALL_STATE(812)] = 40045,
-  [SMALL_STATE(813)] = 40065,
-  [SMALL_STATE(814)] = 40085,
-  [SMALL_STATE(815)] = 40105,
-  [SMALL_STATE(816)] = 40125,
-  [SMALL_STATE(817)] = 40145,
-  [SMALL_STATE(818)] = 40165,
-  [SMALL_STATE(819)] = 40185,
-  [SMALL_STATE(820)] = 40205,
-  [SMALL_STATE(821)] = 40225,
-  [SMALL_STATE(822)] = 40245,
-  [SMALL_STATE(823)] = 40265,
-  [SMALL_STATE(824)] = 40285,
-  [SMALL_STATE(825)] = 40305,
-  [SMALL_STATE(826)] = 40325,
-  [SMALL_STATE(827)] = 40345,
-  [SMALL_STATE(828)] = 40365,
-  [SMALL_STATE(829)] = 40385,
-  [SMALL_STATE(830)] = 40405,
-  [SMALL_STATE(831)] = 40425,
-  [SMALL_STATE(832)] = 40445,
-  [SMALL_STATE(833)] = 40465,
-  [SMALL_STATE(834)] = 40485,
-  [SMALL_STATE(835)] = 40505,
-  [SMALL_STATE(836)] = 40525,
-  [SMALL_STATE(837)] = 40545,
-  [SMALL_STATE(838)] = 40562,
-  [SMALL_STATE(839)] = 40575,
-  [SMALL_STATE(840)] = 40594,
-  [SMALL_STATE(841)] = 40608,
-  [SMALL_STATE(842)] = 40622,
-  [SMALL_STATE(843)] = 40636,
-  [SMALL_STATE(844)] = 40650,
-  [SMALL_STATE(845)] = 40666,
-  [SMALL_STATE(846)] = 40682,
-  [SMALL_STATE(847)] = 40698,
-  [SMALL_STATE(848)] = 40711,
-  [SMALL_STATE(849)] = 40724,
-  [SMALL_STATE(850)] = 40737,
-  [SMALL_STATE(851)] = 40750,
-  [SMALL_STATE(852)] = 40763,
-  [SMALL_STATE(853)] = 40776,
-  [SMALL_STATE(854)] = 40789,
-  [SMALL_STATE(855)] = 40802,
-  [SMALL_STATE(856)] = 40815,
-  [SMALL_STATE(857)] = 40828,
-  [SMALL_STATE(858)] = 40841,
-  [SMALL_STATE(859)] = 40854,
-  [SMALL_STATE(860)] = 40867,
-  [SMALL_STATE(861)] = 40880,
-  [SMALL_STATE(862)] = 40893,
-  [SMALL_STATE(863)] = 40906,
-  [SMALL_STATE(864)] = 40919,
-  [SMALL_STATE(865)] = 40932,
-  [SMALL_STATE(866)] = 40945,
-  [SMALL_STATE(867)] = 40958,
-  [SMALL_STATE(868)] = 40971,
-  [SMALL_STATE(869)] = 40984,
-  [SMALL_STATE(870)] = 40997,
-  [SMALL_STATE(871)] = 41010,
-  [SMALL_STATE(872)] = 41023,
-  [SMALL_STATE(873)] = 41036,
-  [SMALL_STATE(874)] = 41049,
-  [SMALL_STATE(875)] = 41060,
-  [SMALL_STATE(876)] = 41073,
-  [SMALL_STATE(877)] = 41086,
-  [SMALL_STATE(878)] = 41099,
-  [SMALL_STATE(879)] = 41112,
-  [SMALL_STATE(880)] = 41125,
-  [SMALL_STATE(881)] = 41138,
-  [SMALL_STATE(882)] = 41151,
-  [SMALL_STATE(883)] = 41164,
-  [SMALL_STATE(884)] = 41177,
-  [SMALL_STATE(885)] = 41190,
-  [SMALL_STATE(886)] = 41203,
-  [SMALL_STATE(887)] = 41216,
-  [SMALL_STATE(888)] = 41229,
-  [SMALL_STATE(889)] = 41242,
-  [SMALL_STATE(890)] = 41255,
-  [SMALL_STATE(891)] = 41264,
-  [SMALL_STATE(892)] = 41277,
-  [SMALL_STATE(893)] = 41290,
-  [SMALL_STATE(894)] = 41303,
-  [SMALL_STATE(895)] = 41316,
-  [SMALL_STATE(896)] = 41329,
-  [SMALL_STATE(897)] = 41342,
-  [SMALL_STATE(898)] = 41355,
-  [SMALL_STATE(899)] = 41368,
-  [SMALL_STATE(900)] = 41381,
-  [SMALL_STATE(901)] = 41394,
-  [SMALL_STATE(902)] = 41407,
-  [SMALL_STATE(903)] = 41420,
-  [SMALL_STATE(904)] = 41433,
-  [SMALL_STATE(905)] = 41442,
-  [SMALL_STATE(906)] = 41455,
-  [SMALL_STATE(907)] = 41468,
-  [SMALL_STATE(908)] = 41481,
-  [SMALL_STATE(909)] = 41494,
-  [SMALL_STATE(910)] = 41507,
-  [SMALL_STATE(911)] = 41520,
-  [SMALL_STATE(912)] = 41533,
-  [SMALL_STATE(913)] = 41546,
-  [SMALL_STATE(914)] = 41559,
-  [SMALL_STATE(915)] = 41572,
-  [SMALL_STATE(916)] = 41585,
-  [SMALL_STATE(917)] = 41598,
-  [SMALL_STATE(918)] = 41611,
-  [SMALL_STATE(919)] = 41624,
-  [SMALL_STATE(920)] = 41637,
-  [SMALL_STATE(921)] = 41650,
-  [SMALL_STATE(922)] = 41663,
-  [SMALL_STATE(923)] = 41676,
-  [SMALL_STATE(924)] = 41689,
-  [SMALL_STATE(925)] = 41702,
-  [SMALL_STATE(926)] = 41715,
-  [SMALL_STATE(927)] = 41728,
-  [SMALL_STATE(928)] = 41741,
-  [SMALL_STATE(929)] = 41754,
-  [SMALL_STATE(930)] = 41767,
-  [SMALL_STATE(931)] = 41780,
-  [SMALL_STATE(932)] = 41793,
-  [SMALL_STATE(933)] = 41806,
-  [SMALL_STATE(934)] = 41819,
-  [SMALL_STATE(935)] = 41832,
-  [SMALL_STATE(936)] = 41845,
-  [SMALL_STATE(937)] = 41856,
-  [SMALL_STATE(938)] = 41869,
-  [SMALL_STATE(939)] = 41882,
-  [SMALL_STATE(940)] = 41895,
-  [SMALL_STATE(941)] = 41908,
-  [SMALL_STATE(942)] = 41921,
-  [SMALL_STATE(943)] = 41934,
-  [SMALL_STATE(944)] = 41947,
-  [SMALL_STATE(945)] = 41960,
-  [SMALL_STATE(946)] = 41973,
-  [SMALL_STATE(947)] = 41986,
-  [SMALL_STATE(948)] = 41999,
-  [SMALL_STATE(949)] = 42012,
-  [SMALL_STATE(950)] = 42025,
-  [SMALL_STATE(951)] = 42038,
-  [SMALL_STATE(952)] = 42051,
-  [SMALL_STATE(953)] = 42064,
-  [SMALL_STATE(954)] = 42077,
-  [SMALL_STATE(955)] = 42090,
-  [SMALL_STATE(956)] = 42103,
-  [SMALL_STATE(957)] = 42116,
-  [SMALL_STATE(958)] = 42129,
-  [SMALL_STATE(959)] = 42142,
-  [SMALL_STATE(960)] = 42155,
-  [SMALL_STATE(961)] = 42168,
-  [SMALL_STATE(962)] = 42181,
-  [SMALL_STATE(963)] = 42194,
-  [SMALL_STATE(964)] = 42207,
-  [SMALL_STATE(965)] = 42220,
-  [SMALL_STATE(966)] = 42233,
-  [SMALL_STATE(967)] = 42246,
-  [SMALL_STATE(968)] = 42259,
-  [SMALL_STATE(969)] = 42272,
-  [SMALL_STATE(970)] = 42285,
-  [SMALL_STATE(971)] = 42296,
-  [SMALL_STATE(972)] = 42309,
-  [SMALL_STATE(973)] = 42322,
-  [SMALL_STATE(974)] = 42335,
-  [SMALL_STATE(975)] = 42348,
-  [SMALL_STATE(976)] = 42361,
-  [SMALL_STATE(977)] = 42374,
-  [SMALL_STATE(978)] = 42387,
-  [SMALL_STATE(979)] = 42400,
-  [SMALL_STATE(980)] = 42413,
-  [SMALL_STATE(981)] = 42426,
-  [SMALL_STATE(982)] = 42439,
-  [SMALL_STATE(983)] = 42452,
-  [SMALL_STATE(984)] = 42463,
-  [SMALL_STATE(985)] = 42476,
-  [SMALL_STATE(986)] = 42489,
-  [SMALL_STATE(987)] = 42502,
-  [SMALL_STATE(988)] = 42515,
-  [SMALL_STATE(989)] = 42528,
-  [SMALL_STATE(990)] = 42541,
-  [SMALL_STATE(991)] = 42554,
-  [SMALL_STATE(992)] = 42567,
-  [SMALL_STATE(993)] = 42580,
-  [SMALL_STATE(994)] = 42593,
-  [SMALL_STATE(995)] = 42606,
-  [SMALL_STATE(996)] = 42619,
-  [SMALL_STATE(997)] = 42632,
-  [SMALL_STATE(998)] = 42645,
-  [SMALL_STATE(999)] = 42655,
-  [SMALL_STATE(1000)] = 42663,
-  [SMALL_STATE(1001)] = 42673,
-  [SMALL_STATE(1002)] = 42683,
-  [SMALL_STATE(1003)] = 42691,
-  [SMALL_STATE(1004)] = 42699,
-  [SMALL_STATE(1005)] = 42709,
-  [SMALL_STATE(1006)] = 42719,
-  [SMALL_STATE(1007)] = 42729,
-  [SMALL_STATE(1008)] = 42739,
-  [SMALL_STATE(1009)] = 42747,
-  [SMALL_STATE(1010)] = 42757,
-  [SMALL_STATE(1011)] = 42767,
-  [SMALL_STATE(1012)] = 42775,
-  [SMALL_STATE(1013)] = 42785,
-  [SMALL_STATE(1014)] = 42793,
-  [SMALL_STATE(1015)] = 42803,
-  [SMALL_STATE(1016)] = 42813,
-  [SMALL_STATE(1017)] = 42821,
-  [SMALL_STATE(1018)] = 42829,
-  [SMALL_STATE(1019)] = 42837,
-  [SMALL_STATE(1020)] = 42845,
-  [SMALL_STATE(1021)] = 42853,
-  [SMALL_STATE(1022)] = 42863,
-  [SMALL_STATE(1023)] = 42871,
-  [SMALL_STATE(1024)] = 42881,
-  [SMALL_STATE(1025)] = 42891,
-  [SMALL_STATE(1026)] = 42901,
-  [SMALL_STATE(1027)] = 42909,
-  [SMALL_STATE(1028)] = 42917,
-  [SMALL_STATE(1029)] = 42925,
-  [SMALL_STATE(1030)] = 42933,
-  [SMALL_STATE(1031)] = 42943,
-  [SMALL_STATE(1032)] = 42953,
-  [SMALL_STATE(1033)] = 42961,
-  [SMALL_STATE(1034)] = 42971,
-  [SMALL_STATE(1035)] = 42981,
-  [SMALL_STATE(1036)] = 42989,
-  [SMALL_STATE(1037)] = 42999,
-  [SMALL_STATE(1038)] = 43009,
-  [SMALL_STATE(1039)] = 43019,
-  [SMALL_STATE(1040)] = 43029,
-  [SMALL_STATE(1041)] = 43039,
-  [SMALL_STATE(1042)] = 43049,
-  [SMALL_STATE(1043)] = 43059,
-  [SMALL_STATE(1044)] = 43067,
-  [SMALL_STATE(1045)] = 43077,
-  [SMALL_STATE(1046)] = 43085,
-  [SMALL_STATE(1047)] = 43093,
-  [SMALL_STATE(1048)] = 43101,
-  [SMALL_STATE(1049)] = 43109,
-  [SMALL_STATE(1050)] = 43119,
-  [SMALL_STATE(1051)] = 43129,
-  [SMALL_STATE(1052)] = 43139,
-  [SMALL_STATE(1053)] = 43149,
-  [SMALL_STATE(1054)] = 43157,
-  [SMALL_STATE(1055)] = 43165,
-  [SMALL_STATE(1056)] = 43175,
-  [SMALL_STATE(1057)] = 43185,
-  [SMALL_STATE(1058)] = 43193,
-  [SMALL_STATE(1059)] = 43201,
-  [SMALL_STATE(1060)] = 43211,
-  [SMALL_STATE(1061)] = 43221,
-  [SMALL_STATE(1062)] = 43229,
-  [SMALL_STATE(1063)] = 43237,
-  [SMALL_STATE(1064)] = 43245,
-  [SMALL_STATE(1065)] = 43253,
-  [SMALL_STATE(1066)] = 43263,
-  [SMALL_STATE(1067)] = 43271,
-  [SMALL_STATE(1068)] = 43279,
-  [SMALL_STATE(1069)] = 43289,
-  [SMALL_STATE(1070)] = 43297,
-  [SMALL_STATE(1071)] = 43307,
-  [SMALL_STATE(1072)] = 43315,
-  [SMALL_STATE(1073)] = 43323,
-  [SMALL_STATE(1074)] = 43331,
-  [SMALL_STATE(1075)] = 43341,
-  [SMALL_STATE(1076)] = 43349,
-  [SMALL_STATE(1077)] = 43357,
-  [SMALL_STATE(1078)] = 43365,
-  [SMALL_STATE(1079)] = 43373,
-  [SMALL_STATE(1080)] = 43381,
-  [SMALL_STATE(1081)] = 43389,
-  [SMALL_STATE(1082)] = 43397,
-  [SMALL_STATE(1083)] = 43405,
-  [SMALL_STATE(1084)] = 43413,
-  [SMALL_STATE(1085)] = 43421,
-  [SMALL_STATE(1086)] = 43429,
-  [SMALL_STATE(1087)] = 43437,
-  [SMALL_STATE(1088)] = 43445,
-  [SMALL_STATE(1089)] = 43455,
-  [SMALL_STATE(1090)] = 43463,
-  [SMALL_STATE(1091)] = 43473,
-  [SMALL_STATE(1092)] = 43483,
-  [SMALL_STATE(1093)] = 43491,
-  [SMALL_STATE(1094)] = 43499,
-  [SMALL_STATE(1095)] = 43507,
-  [SMALL_STATE(1096)] = 43515,
-  [SMALL_STATE(1097)] = 43525,
-  [SMALL_STATE(1098)] = 43533,
-  [SMALL_STATE(1099)] = 43543,
-  [SMALL_STATE(1100)] = 43553,
-  [SMALL_STATE(1101)] = 43563,
-  [SMALL_STATE(1102)] = 43571,
-  [SMALL_STATE(1103)] = 43579,
-  [SMALL_STATE(1104)] = 43587,
-  [SMALL_STATE(1105)] = 43597,
-  [SMALL_STATE(1106)] = 43605,
-  [SMALL_STATE(1107)] = 43615,
-  [SMALL_STATE(1108)] = 43623,
-  [SMALL_STATE(1109)] = 43633,
-  [SMALL_STATE(1110)] = 43641,
-  [SMALL_STATE(1111)] = 43651,
-  [SMALL_STATE(1112)] = 43661,
-  [SMALL_STATE(1113)] = 43671,
-  [SMALL_STATE(1114)] = 43679,
-  [SMALL_STATE(1115)] = 43689,
-  [SMALL_STATE(1116)] = 43699,
-  [SMALL_STATE(1117)] = 43707,
-  [SMALL_STATE(1118)] = 43715,
-  [SMALL_STATE(1119)] = 43725,
-  [SMALL_STATE(1120)] = 43735,
-  [SMALL_STATE(1121)] = 43745,
-  [SMALL_STATE(1122)] = 43753,
-  [SMALL_STATE(1123)] = 43763,
-  [SMALL_STATE(1124)] = 43771,
-  [SMALL_STATE(1125)] = 43781,
-  [SMALL_STATE(1126)] = 43789,
-  [SMALL_STATE(1127)] = 43797,
-  [SMALL_STATE(1128)] = 43805,
-  [SMALL_STATE(1129)] = 43813,
-  [SMALL_STATE(1130)] = 43821,
-  [SMALL_STATE(1131)] = 43829,
-  [SMALL_STATE(1132)] = 43837,
-  [SMALL_STATE(1133)] = 43845,
-  [SMALL_STATE(1134)] = 43853,
-  [SMALL_STATE(1135)] = 43863,
-  [SMALL_STATE(1136)] = 43871,
-  [SMALL_STATE(1137)] = 43879,
-  [SMALL_STATE(1138)] = 43887,
-  [SMALL_STATE(1139)] = 43895,
-  [SMALL_STATE(1140)] = 43903,
-  [SMALL_STATE(1141)] = 43911,
-  [SMALL_STATE(1142)] = 43919,
-  [SMALL_STATE(1143)] = 43927,
-  [SMALL_STATE(1144)] = 43935,
-  [SMALL_STATE(1145)] = 43943,
-  [SMALL_STATE(1146)] = 43951,
-  [SMALL_STATE(1147)] = 43959,
-  [SMALL_STATE(1148)] = 43967,
-  [SMALL_STATE(1149)] = 43975,
-  [SMALL_STATE(1150)] = 43983,
-  [SMALL_STATE(1151)] = 43991,
-  [SMALL_STATE(1152)] = 43999,
-  [SMALL_STATE(1153)] = 44007,
-  [SMALL_STATE(1154)] = 44014,
-  [SMALL_STATE(1155)] = 44021,
-  [SMALL_STATE(1156)] = 44028,
-  [SMALL_STATE(1157)] = 44035,
-  [SMALL_STATE(1158)] = 44042,
-  [SMALL_STATE(1159)] = 44049,
-  [SMALL_STATE(1160)] = 44056,
-  [SMALL_STATE(1161)] = 44063,
-  [SMALL_STATE(1162)] = 44070,
-  [SMALL_STATE(1163)] = 44077,
-  [SMALL_STATE(1164)] = 44084,
-  [SMALL_STATE(1165)] = 44091,
-  [SMALL_STATE(1166)] = 44098,
-  [SMALL_STATE(1167)] = 44105,
-  [SMALL_STATE(1168)] = 44112,
-  [SMALL_STATE(1169)] = 44119,
-  [SMALL_STATE(1170)] = 44126,
-  [SMALL_STATE(1171)] = 44133,
-  [SMALL_STATE(1172)] = 44140,
-  [SMALL_STATE(1173)] = 44147,
-  [SMALL_STATE(1174)] = 44154,
-  [SMALL_STATE(1175)] = 44161,
-  [SMALL_STATE(1176)] = 44168,
-  [SMALL_STATE(1177)] = 44175,
-  [SMALL_STATE(1178)] = 44182,
-  [SMALL_STATE(1179)] = 44189,
-  [SMALL_STATE(1180)] = 44196,
-  [SMALL_STATE(1181)] = 44203,
-  [SMALL_STATE(1182)] = 44210,
-  [SMALL_STATE(1183)] = 44217,
-  [SMALL_STATE(1184)] = 44224,
-  [SMALL_STATE(1185)] = 44231,
-  [SMALL_STATE(1186)] = 44238,
-  [SMALL_STATE(1187)] = 44245,
-  [SMALL_STATE(1188)] = 44252,
-  [SMALL_STATE(1189)] = 44259,
-  [SMALL_STATE(1190)] = 44266,
-  [SMALL_STATE(1191)] = 44273,
-  [SMALL_STATE(1192)] = 44280,
-  [SMALL_STATE(1193)] = 44287,
-  [SMALL_STATE(1194)] = 44294,
-  [SMALL_STATE(1195)] = 44301,
-  [SMALL_STATE(1196)] = 44308,
-  [SMALL_STATE(1197)] = 44315,
-  [SMALL_STATE(1198)] = 44322,
-  [SMALL_STATE(1199)] = 44329,
-  [SMALL_STATE(1200)] = 44336,
-  [SMALL_STATE(1201)] = 44343,
-  [SMALL_STATE(1202)] = 44350,
-  [SMALL_STATE(1203)] = 44357,
-  [SMALL_STATE(1204)] = 44364,
-  [SMALL_STATE(1205)] = 44371,
-  [SMALL_STATE(1206)] = 44378,
-  [SMALL_STATE(1207)] = 44385,
-  [SMALL_STATE(1208)] = 44392,
-  [SMALL_STATE(1209)] = 44399,
-  [SMALL_STATE(1210)] = 44406,
-  [SMALL_STATE(1211)] = 44413,
-  [SMALL_STATE(1212)] = 44420,
-  [SMALL_STATE(1213)] = 44427,
-  [SMALL_STATE(1214)] = 44434,
-  [SMALL_STATE(1215)] = 44441,
-  [SMALL_STATE(1216)] = 44448,
+  [SMALL_STATE(426)] = 26353,
+  [SMALL_STATE(427)] = 26392,
+  [SMALL_STATE(428)] = 26427,
+  [SMALL_STATE(429)] = 26465,
+  [SMALL_STATE(430)] = 26503,
+  [SMALL_STATE(431)] = 26541,
+  [SMALL_STATE(432)] = 26581,
+  [SMALL_STATE(433)] = 26632,
+  [SMALL_STATE(434)] = 26665,
+  [SMALL_STATE(435)] = 26698,
+  [SMALL_STATE(436)] = 26731,
+  [SMALL_STATE(437)] = 26764,
+  [SMALL_STATE(438)] = 26797,
+  [SMALL_STATE(439)] = 26830,
+  [SMALL_STATE(440)] = 26863,
+  [SMALL_STATE(441)] = 26900,
+  [SMALL_STATE(442)] = 26941,
+  [SMALL_STATE(443)] = 26986,
+  [SMALL_STATE(444)] = 27035,
+  [SMALL_STATE(445)] = 27068,
+  [SMALL_STATE(446)] = 27115,
+  [SMALL_STATE(447)] = 27158,
+  [SMALL_STATE(448)] = 27213,
+  [SMALL_STATE(449)] = 27270,
+  [SMALL_STATE(450)] = 27303,
+  [SMALL_STATE(451)] = 27336,
+  [SMALL_STATE(452)] = 27369,
+  [SMALL_STATE(453)] = 27402,
+  [SMALL_STATE(454)] = 27463,
+  [SMALL_STATE(455)] = 27496,
+  [SMALL_STATE(456)] = 27529,
+  [SMALL_STATE(457)] = 27562,
+  [SMALL_STATE(458)] = 27595,
+  [SMALL_STATE(459)] = 27628,
+  [SMALL_STATE(460)] = 27661,
+  [SMALL_STATE(461)] = 27694,
+  [SMALL_STATE(462)] = 27727,
+  [SMALL_STATE(463)] = 27760,
+  [SMALL_STATE(464)] = 27797,
+  [SMALL_STATE(465)] = 27830,
+  [SMALL_STATE(466)] = 27863,
+  [SMALL_STATE(467)] = 27898,
+  [SMALL_STATE(468)] = 27960,
+  [SMALL_STATE(469)] = 28022,
+  [SMALL_STATE(470)] = 28084,
+  [SMALL_STATE(471)] = 28146,
+  [SMALL_STATE(472)] = 28206,
+  [SMALL_STATE(473)] = 28268,
+  [SMALL_STATE(474)] = 28330,
+  [SMALL_STATE(475)] = 28387,
+  [SMALL_STATE(476)] = 28444,
+  [SMALL_STATE(477)] = 28501,
+  [SMALL_STATE(478)] = 28558,
+  [SMALL_STATE(479)] = 28615,
+  [SMALL_STATE(480)] = 28672,
+  [SMALL_STATE(481)] = 28731,
+  [SMALL_STATE(482)] = 28788,
+  [SMALL_STATE(483)] = 28845,
+  [SMALL_STATE(484)] = 28902,
+  [SMALL_STATE(485)] = 28959,
+  [SMALL_STATE(486)] = 29016,
+  [SMALL_STATE(487)] = 29073,
+  [SMALL_STATE(488)] = 29130,
+  [SMALL_STATE(489)] = 29187,
+  [SMALL_STATE(490)] = 29244,
+  [SMALL_STATE(491)] = 29303,
+  [SMALL_STATE(492)] = 29360,
+  [SMALL_STATE(493)] = 29417,
+  [SMALL_STATE(494)] = 29474,
+  [SMALL_STATE(495)] = 29531,
+  [SMALL_STATE(496)] = 29588,
+  [SMALL_STATE(497)] = 29645,
+  [SMALL_STATE(498)] = 29702,
+  [SMALL_STATE(499)] = 29759,
+  [SMALL_STATE(500)] = 29816,
+  [SMALL_STATE(501)] = 29873,
+  [SMALL_STATE(502)] = 29930,
+  [SMALL_STATE(503)] = 29987,
+  [SMALL_STATE(504)] = 30044,
+  [SMALL_STATE(505)] = 30101,
+  [SMALL_STATE(506)] = 30158,
+  [SMALL_STATE(507)] = 30215,
+  [SMALL_STATE(508)] = 30272,
+  [SMALL_STATE(509)] = 30329,
+  [SMALL_STATE(510)] = 30388,
+  [SMALL_STATE(511)] = 30445,
+  [SMALL_STATE(512)] = 30502,
+  [SMALL_STATE(513)] = 30559,
+  [SMALL_STATE(514)] = 30616,
+  [SMALL_STATE(515)] = 30673,
+  [SMALL_STATE(516)] = 30730,
+  [SMALL_STATE(517)] = 30787,
+  [SMALL_STATE(518)] = 30844,
+  [SMALL_STATE(519)] = 30901,
+  [SMALL_STATE(520)] = 30958,
+  [SMALL_STATE(521)] = 31015,
+  [SMALL_STATE(522)] = 31072,
+  [SMALL_STATE(523)] = 31129,
+  [SMALL_STATE(524)] = 31186,
+  [SMALL_STATE(525)] = 31243,
+  [SMALL_STATE(526)] = 31300,
+  [SMALL_STATE(527)] = 31357,
+  [SMALL_STATE(528)] = 31414,
+  [SMALL_STATE(529)] = 31471,
+  [SMALL_STATE(530)] = 31528,
+  [SMALL_STATE(531)] = 31585,
+  [SMALL_STATE(532)] = 31642,
+  [SMALL_STATE(533)] = 31699,
+  [SMALL_STATE(534)] = 31756,
+  [SMALL_STATE(535)] = 31813,
+  [SMALL_STATE(536)] = 31870,
+  [SMALL_STATE(537)] = 31927,
+  [SMALL_STATE(538)] = 31984,
+  [SMALL_STATE(539)] = 32041,
+  [SMALL_STATE(540)] = 32098,
+  [SMALL_STATE(541)] = 32155,
+  [SMALL_STATE(542)] = 32212,
+  [SMALL_STATE(543)] = 32269,
+  [SMALL_STATE(544)] = 32326,
+  [SMALL_STATE(545)] = 32383,
+  [SMALL_STATE(546)] = 32440,
+  [SMALL_STATE(547)] = 32497,
+  [SMALL_STATE(548)] = 32554,
+  [SMALL_STATE(549)] = 32610,
+  [SMALL_STATE(550)] = 32640,
+  [SMALL_STATE(551)] = 32696,
+  [SMALL_STATE(552)] = 32730,
+  [SMALL_STATE(553)] = 32786,
+  [SMALL_STATE(554)] = 32842,
+  [SMALL_STATE(555)] = 32898,
+  [SMALL_STATE(556)] = 32954,
+  [SMALL_STATE(557)] = 33010,
+  [SMALL_STATE(558)] = 33044,
+  [SMALL_STATE(559)] = 33100,
+  [SMALL_STATE(560)] = 33156,
+  [SMALL_STATE(561)] = 33212,
+  [SMALL_STATE(562)] = 33268,
+  [SMALL_STATE(563)] = 33324,
+  [SMALL_STATE(564)] = 33380,
+  [SMALL_STATE(565)] = 33436,
+  [SMALL_STATE(566)] = 33492,
+  [SMALL_STATE(567)] = 33526,
+  [SMALL_STATE(568)] = 33582,
+  [SMALL_STATE(569)] = 33638,
+  [SMALL_STATE(570)] = 33694,
+  [SMALL_STATE(571)] = 33750,
+  [SMALL_STATE(572)] = 33779,
+  [SMALL_STATE(573)] = 33834,
+  [SMALL_STATE(574)] = 33889,
+  [SMALL_STATE(575)] = 33922,
+  [SMALL_STATE(576)] = 33952,
+  [SMALL_STATE(577)] = 33980,
+  [SMALL_STATE(578)] = 34008,
+  [SMALL_STATE(579)] = 34036,
+  [SMALL_STATE(580)] = 34063,
+  [SMALL_STATE(581)] = 34090,
+  [SMALL_STATE(582)] = 34117,
+  [SMALL_STATE(583)] = 34144,
+  [SMALL_STATE(584)] = 34171,
+  [SMALL_STATE(585)] = 34198,
+  [SMALL_STATE(586)] = 34225,
+  [SMALL_STATE(587)] = 34252,
+  [SMALL_STATE(588)] = 34279,
+  [SMALL_STATE(589)] = 34306,
+  [SMALL_STATE(590)] = 34333,
+  [SMALL_STATE(591)] = 34362,
+  [SMALL_STATE(592)] = 34405,
+  [SMALL_STATE(593)] = 34448,
+  [SMALL_STATE(594)] = 34491,
+  [SMALL_STATE(595)] = 34534,
+  [SMALL_STATE(596)] = 34563,
+  [SMALL_STATE(597)] = 34606,
+  [SMALL_STATE(598)] = 34635,
+  [SMALL_STATE(599)] = 34672,
+  [SMALL_STATE(600)] = 34709,
+  [SMALL_STATE(601)] = 34746,
+  [SMALL_STATE(602)] = 34783,
+  [SMALL_STATE(603)] = 34820,
+  [SMALL_STATE(604)] = 34857,
+  [SMALL_STATE(605)] = 34894,
+  [SMALL_STATE(606)] = 34931,
+  [SMALL_STATE(607)] = 34968,
+  [SMALL_STATE(608)] = 35005,
+  [SMALL_STATE(609)] = 35042,
+  [SMALL_STATE(610)] = 35079,
+  [SMALL_STATE(611)] = 35116,
+  [SMALL_STATE(612)] = 35153,
+  [SMALL_STATE(613)] = 35190,
+  [SMALL_STATE(614)] = 35227,
+  [SMALL_STATE(615)] = 35264,
+  [SMALL_STATE(616)] = 35301,
+  [SMALL_STATE(617)] = 35338,
+  [SMALL_STATE(618)] = 35375,
+  [SMALL_STATE(619)] = 35412,
+  [SMALL_STATE(620)] = 35449,
+  [SMALL_STATE(621)] = 35486,
+  [SMALL_STATE(622)] = 35523,
+  [SMALL_STATE(623)] = 35560,
+  [SMALL_STATE(624)] = 35597,
+  [SMALL_STATE(625)] = 35634,
+  [SMALL_STATE(626)] = 35671,
+  [SMALL_STATE(627)] = 35708,
+  [SMALL_STATE(628)] = 35745,
+  [SMALL_STATE(629)] = 35782,
+  [SMALL_STATE(630)] = 35819,
+  [SMALL_STATE(631)] = 35856,
+  [SMALL_STATE(632)] = 35893,
+  [SMALL_STATE(633)] = 35930,
+  [SMALL_STATE(634)] = 35967,
+  [SMALL_STATE(635)] = 36004,
+  [SMALL_STATE(636)] = 36041,
+  [SMALL_STATE(637)] = 36078,
+  [SMALL_STATE(638)] = 36115,
+  [SMALL_STATE(639)] = 36152,
+  [SMALL_STATE(640)] = 36189,
+  [SMALL_STATE(641)] = 36226,
+  [SMALL_STATE(642)] = 36263,
+  [SMALL_STATE(643)] = 36300,
+  [SMALL_STATE(644)] = 36337,
+  [SMALL_STATE(645)] = 36374,
+  [SMALL_STATE(646)] = 36411,
+  [SMALL_STATE(647)] = 36448,
+  [SMALL_STATE(648)] = 36485,
+  [SMALL_STATE(649)] = 36522,
+  [SMALL_STATE(650)] = 36559,
+  [SMALL_STATE(651)] = 36596,
+  [SMALL_STATE(652)] = 36633,
+  [SMALL_STATE(653)] = 36670,
+  [SMALL_STATE(654)] = 36707,
+  [SMALL_STATE(655)] = 36744,
+  [SMALL_STATE(656)] = 36781,
+  [SMALL_STATE(657)] = 36818,
+  [SMALL_STATE(658)] = 36855,
+  [SMALL_STATE(659)] = 36892,
+  [SMALL_STATE(660)] = 36929,
+  [SMALL_STATE(661)] = 36966,
+  [SMALL_STATE(662)] = 37003,
+  [SMALL_STATE(663)] = 37024,
+  [SMALL_STATE(664)] = 37045,
+  [SMALL_STATE(665)] = 37063,
+  [SMALL_STATE(666)] = 37081,
+  [SMALL_STATE(667)] = 37107,
+  [SMALL_STATE(668)] = 37125,
+  [SMALL_STATE(669)] = 37143,
+  [SMALL_STATE(670)] = 37161,
+  [SMALL_STATE(671)] = 37179,
+  [SMALL_STATE(672)] = 37197,
+  [SMALL_STATE(673)] = 37223,
+  [SMALL_STATE(674)] = 37249,
+  [SMALL_STATE(675)] = 37267,
+  [SMALL_STATE(676)] = 37285,
+  [SMALL_STATE(677)] = 37311,
+  [SMALL_STATE(678)] = 37337,
+  [SMALL_STATE(679)] = 37355,
+  [SMALL_STATE(680)] = 37381,
+  [SMALL_STATE(681)] = 37399,
+  [SMALL_STATE(682)] = 37417,
+  [SMALL_STATE(683)] = 37435,
+  [SMALL_STATE(684)] = 37453,
+  [SMALL_STATE(685)] = 37479,
+  [SMALL_STATE(686)] = 37497,
+  [SMALL_STATE(687)] = 37515,
+  [SMALL_STATE(688)] = 37533,
+  [SMALL_STATE(689)] = 37559,
+  [SMALL_STATE(690)] = 37577,
+  [SMALL_STATE(691)] = 37603,
+  [SMALL_STATE(692)] = 37629,
+  [SMALL_STATE(693)] = 37655,
+  [SMALL_STATE(694)] = 37673,
+  [SMALL_STATE(695)] = 37691,
+  [SMALL_STATE(696)] = 37709,
+  [SMALL_STATE(697)] = 37727,
+  [SMALL_STATE(698)] = 37745,
+  [SMALL_STATE(699)] = 37763,
+  [SMALL_STATE(700)] = 37781,
+  [SMALL_STATE(701)] = 37799,
+  [SMALL_STATE(702)] = 37817,
+  [SMALL_STATE(703)] = 37835,
+  [SMALL_STATE(704)] = 37853,
+  [SMALL_STATE(705)] = 37871,
+  [SMALL_STATE(706)] = 37889,
+  [SMALL_STATE(707)] = 37907,
+  [SMALL_STATE(708)] = 37925,
+  [SMALL_STATE(709)] = 37960,
+  [SMALL_STATE(710)] = 37978,
+  [SMALL_STATE(711)] = 37996,
+  [SMALL_STATE(712)] = 38014,
+  [SMALL_STATE(713)] = 38032,
+  [SMALL_STATE(714)] = 38050,
+  [SMALL_STATE(715)] = 38068,
+  [SMALL_STATE(716)] = 38086,
+  [SMALL_STATE(717)] = 38104,
+  [SMALL_STATE(718)] = 38122,
+  [SMALL_STATE(719)] = 38140,
+  [SMALL_STATE(720)] = 38158,
+  [SMALL_STATE(721)] = 38176,
+  [SMALL_STATE(722)] = 38194,
+  [SMALL_STATE(723)] = 38212,
+  [SMALL_STATE(724)] = 38230,
+  [SMALL_STATE(725)] = 38248,
+  [SMALL_STATE(726)] = 38266,
+  [SMALL_STATE(727)] = 38284,
+  [SMALL_STATE(728)] = 38302,
+  [SMALL_STATE(729)] = 38320,
+  [SMALL_STATE(730)] = 38338,
+  [SMALL_STATE(731)] = 38356,
+  [SMALL_STATE(732)] = 38374,
+  [SMALL_STATE(733)] = 38392,
+  [SMALL_STATE(734)] = 38410,
+  [SMALL_STATE(735)] = 38428,
+  [SMALL_STATE(736)] = 38454,
+  [SMALL_STATE(737)] = 38480,
+  [SMALL_STATE(738)] = 38498,
+  [SMALL_STATE(739)] = 38524,
+  [SMALL_STATE(740)] = 38542,
+  [SMALL_STATE(741)] = 38560,
+  [SMALL_STATE(742)] = 38578,
+  [SMALL_STATE(743)] = 38596,
+  [SMALL_STATE(744)] = 38614,
+  [SMALL_STATE(745)] = 38632,
+  [SMALL_STATE(746)] = 38650,
+  [SMALL_STATE(747)] = 38685,
+  [SMALL_STATE(748)] = 38703,
+  [SMALL_STATE(749)] = 38721,
+  [SMALL_STATE(750)] = 38739,
+  [SMALL_STATE(751)] = 38757,
+  [SMALL_STATE(752)] = 38775,
+  [SMALL_STATE(753)] = 38793,
+  [SMALL_STATE(754)] = 38811,
+  [SMALL_STATE(755)] = 38829,
+  [SMALL_STATE(756)] = 38847,
+  [SMALL_STATE(757)] = 38870,
+  [SMALL_STATE(758)] = 38893,
+  [SMALL_STATE(759)] = 38916,
+  [SMALL_STATE(760)] = 38939,
+  [SMALL_STATE(761)] = 38962,
+  [SMALL_STATE(762)] = 38985,
+  [SMALL_STATE(763)] = 39008,
+  [SMALL_STATE(764)] = 39031,
+  [SMALL_STATE(765)] = 39054,
+  [SMALL_STATE(766)] = 39077,
+  [SMALL_STATE(767)] = 39100,
+  [SMALL_STATE(768)] = 39123,
+  [SMALL_STATE(769)] = 39146,
+  [SMALL_STATE(770)] = 39169,
+  [SMALL_STATE(771)] = 39192,
+  [SMALL_STATE(772)] = 39215,
+  [SMALL_STATE(773)] = 39238,
+  [SMALL_STATE(774)] = 39261,
+  [SMALL_STATE(775)] = 39284,
+  [SMALL_STATE(776)] = 39307,
+  [SMALL_STATE(777)] = 39330,
+  [SMALL_STATE(778)] = 39353,
+  [SMALL_STATE(779)] = 39376,
+  [SMALL_STATE(780)] = 39399,
+  [SMALL_STATE(781)] = 39422,
+  [SMALL_STATE(782)] = 39445,
+  [SMALL_STATE(783)] = 39465,
+  [SMALL_STATE(784)] = 39489,
+  [SMALL_STATE(785)] = 39513,
+  [SMALL_STATE(786)] = 39533,
+  [SMALL_STATE(787)] = 39553,
+  [SMALL_STATE(788)] = 39573,
+  [SMALL_STATE(789)] = 39593,
+  [SMALL_STATE(790)] = 39613,
+  [SMALL_STATE(791)] = 39633,
+  [SMALL_STATE(792)] = 39653,
+  [SMALL_STATE(793)] = 39673,
+  [SMALL_STATE(794)] = 39693,
+  [SMALL_STATE(795)] = 39713,
+  [SMALL_STATE(796)] = 39733,
+  [SMALL_STATE(797)] = 39753,
+  [SMALL_STATE(798)] = 39773,
+  [SMALL_STATE(799)] = 39793,
+  [SMALL_STATE(800)] = 39813,
+  [SMALL_STATE(801)] = 39833,
+  [SMALL_STATE(802)] = 39853,
+  [SMALL_STATE(803)] = 39873,
+  [SMALL_STATE(804)] = 39893,
+  [SMALL_STATE(805)] = 39913,
+  [SMALL_STATE(806)] = 39933,
+  [SMALL_STATE(807)] = 39953,
+  [SMALL_STATE(808)] = 39973,
+  [SMALL_STATE(809)] = 39993,
+  [SMALL_STATE(810)] = 40013,
+  [SMALL_STATE(811)] = 40033,
+  [SMALL_STATE(812)] = 40053,
+  [SMALL_STATE(813)] = 40073,
+  [SMALL_STATE(814)] = 40093,
+  [SMALL_STATE(815)] = 40113,
+  [SMALL_STATE(816)] = 40133,
+  [SMALL_STATE(817)] = 40153,
+  [SMALL_STATE(818)] = 40173,
+  [SMALL_STATE(819)] = 40193,
+  [SMALL_STATE(820)] = 40213,
+  [SMALL_STATE(821)] = 40233,
+  [SMALL_STATE(822)] = 40253,
+  [SMALL_STATE(823)] = 40273,
+  [SMALL_STATE(824)] = 40293,
+  [SMALL_STATE(825)] = 40313,
+  [SMALL_STATE(826)] = 40333,
+  [SMALL_STATE(827)] = 40353,
+  [SMALL_STATE(828)] = 40373,
+  [SMALL_STATE(829)] = 40393,
+  [SMALL_STATE(830)] = 40413,
+  [SMALL_STATE(831)] = 40433,
+  [SMALL_STATE(832)] = 40453,
+  [SMALL_STATE(833)] = 40473,
+  [SMALL_STATE(834)] = 40493,
+  [SMALL_STATE(835)] = 40513,
+  [SMALL_STATE(836)] = 40533,
+  [SMALL_STATE(837)] = 40553,
+  [SMALL_STATE(838)] = 40570,
+  [SMALL_STATE(839)] = 40583,
+  [SMALL_STATE(840)] = 40602,
+  [SMALL_STATE(841)] = 40616,
+  [SMALL_STATE(842)] = 40630,
+  [SMALL_STATE(843)] = 40644,
+  [SMALL_STATE(844)] = 40658,
+  [SMALL_STATE(845)] = 40674,
+  [SMALL_STATE(846)] = 40690,
+  [SMALL_STATE(847)] = 40706,
+  [SMALL_STATE(848)] = 40719,
+  [SMALL_STATE(849)] = 40732,
+  [SMALL_STATE(850)] = 40745,
+  [SMALL_STATE(851)] = 40758,
+  [SMALL_STATE(852)] = 40771,
+  [SMALL_STATE(853)] = 40784,
+  [SMALL_STATE(854)] = 40797,
+  [SMALL_STATE(855)] = 40810,
+  [SMALL_STATE(856)] = 40823,
+  [SMALL_STATE(857)] = 40836,
+  [SMALL_STATE(858)] = 40849,
+  [SMALL_STATE(859)] = 40862,
+  [SMALL_STATE(860)] = 40875,
+  [SMALL_STATE(861)] = 40888,
+  [SMALL_STATE(862)] = 40901,
+  [SMALL_STATE(863)] = 40914,
+  [SMALL_STATE(864)] = 40927,
+  [SMALL_STATE(865)] = 40940,
+  [SMALL_STATE(866)] = 40953,
+  [SMALL_STATE(867)] = 40966,
+  [SMALL_STATE(868)] = 40979,
+  [SMALL_STATE(869)] = 40992,
+  [SMALL_STATE(870)] = 41005,
+  [SMALL_STATE(871)] = 41018,
+  [SMALL_STATE(872)] = 41031,
+  [SMALL_STATE(873)] = 41044,
+  [SMALL_STATE(874)] = 41057,
+  [SMALL_STATE(875)] = 41068,
+  [SMALL_STATE(876)] = 41081,
+  [SMALL_STATE(877)] = 41094,
+  [SMALL_STATE(878)] = 41107,
+  [SMALL_STATE(879)] = 41120,
+  [SMALL_STATE(880)] = 41133,
+  [SMALL_STATE(881)] = 41146,
+  [SMALL_STATE(882)] = 41159,
+  [SMALL_STATE(883)] = 41172,
+  [SMALL_STATE(884)] = 41185,
+  [SMALL_STATE(885)] = 41198,
+  [SMALL_STATE(886)] = 41211,
+  [SMALL_STATE(887)] = 41224,
+  [SMALL_STATE(888)] = 41237,
+  [SMALL_STATE(889)] = 41250,
+  [SMALL_STATE(890)] = 41263,
+  [SMALL_STATE(891)] = 41272,
+  [SMALL_STATE(892)] = 41285,
+  [SMALL_STATE(893)] = 41298,
+  [SMALL_STATE(894)] = 41311,
+  [SMALL_STATE(895)] = 41324,
+  [SMALL_STATE(896)] = 41337,
+  [SMALL_STATE(897)] = 41350,
+  [SMALL_STATE(898)] = 41363,
+  [SMALL_STATE(899)] = 41376,
+  [SMALL_STATE(900)] = 41389,
+  [SMALL_STATE(901)] = 41402,
+  [SMALL_STATE(902)] = 41415,
+  [SMALL_STATE(903)] = 41428,
+  [SMALL_STATE(904)] = 41441,
+  [SMALL_STATE(905)] = 41450,
+  [SMALL_STATE(906)] = 41463,
+  [SMALL_STATE(907)] = 41476,
+  [SMALL_STATE(908)] = 41489,
+  [SMALL_STATE(909)] = 41502,
+  [SMALL_STATE(910)] = 41515,
+  [SMALL_STATE(911)] = 41528,
+  [SMALL_STATE(912)] = 41541,
+  [SMALL_STATE(913)] = 41554,
+  [SMALL_STATE(914)] = 41567,
+  [SMALL_STATE(915)] = 41580,
+  [SMALL_STATE(916)] = 41593,
+  [SMALL_STATE(917)] = 41606,
+  [SMALL_STATE(918)] = 41619,
+  [SMALL_STATE(919)] = 41632,
+  [SMALL_STATE(920)] = 41645,
+  [SMALL_STATE(921)] = 41658,
+  [SMALL_STATE(922)] = 41671,
+  [SMALL_STATE(923)] = 41684,
+  [SMALL_STATE(924)] = 41697,
+  [SMALL_STATE(925)] = 41710,
+  [SMALL_STATE(926)] = 41723,
+  [SMALL_STATE(927)] = 41736,
+  [SMALL_STATE(928)] = 41749,
+  [SMALL_STATE(929)] = 41762,
+  [SMALL_STATE(930)] = 41775,
+  [SMALL_STATE(931)] = 41788,
+  [SMALL_STATE(932)] = 41801,
+  [SMALL_STATE(933)] = 41814,
+  [SMALL_STATE(934)] = 41827,
+  [SMALL_STATE(935)] = 41840,
+  [SMALL_STATE(936)] = 41853,
+  [SMALL_STATE(937)] = 41864,
+  [SMALL_STATE(938)] = 41877,
+  [SMALL_STATE(939)] = 41890,
+  [SMALL_STATE(940)] = 41903,
+  [SMALL_STATE(941)] = 41916,
+  [SMALL_STATE(942)] = 41929,
+  [SMALL_STATE(943)] = 41942,
+  [SMALL_STATE(944)] = 41955,
+  [SMALL_STATE(945)] = 41968,
+  [SMALL_STATE(946)] = 41981,
+  [SMALL_STATE(947)] = 41994,
+  [SMALL_STATE(948)] = 42007,
+  [SMALL_STATE(949)] = 42020,
+  [SMALL_STATE(950)] = 42033,
+  [SMALL_STATE(951)] = 42046,
+  [SMALL_STATE(952)] = 42059,
+  [SMALL_STATE(953)] = 42072,
+  [SMALL_STATE(954)] = 42085,
+  [SMALL_STATE(955)] = 42098,
+  [SMALL_STATE(956)] = 42111,
+  [SMALL_STATE(957)] = 42124,
+  [SMALL_STATE(958)] = 42137,
+  [SMALL_STATE(959)] = 42150,
+  [SMALL_STATE(960)] = 42163,
+  [SMALL_STATE(961)] = 42176,
+  [SMALL_STATE(962)] = 42189,
+  [SMALL_STATE(963)] = 42202,
+  [SMALL_STATE(964)] = 42215,
+  [SMALL_STATE(965)] = 42228,
+  [SMALL_STATE(966)] = 42241,
+  [SMALL_STATE(967)] = 42254,
+  [SMALL_STATE(968)] = 42267,
+  [SMALL_STATE(969)] = 42280,
+  [SMALL_STATE(970)] = 42293,
+  [SMALL_STATE(971)] = 42304,
+  [SMALL_STATE(972)] = 42317,
+  [SMALL_STATE(973)] = 42330,
+  [SMALL_STATE(974)] = 42343,
+  [SMALL_STATE(975)] = 42356,
+  [SMALL_STATE(976)] = 42369,
+  [SMALL_STATE(977)] = 42382,
+  [SMALL_STATE(978)] = 42395,
+  [SMALL_STATE(979)] = 42408,
+  [SMALL_STATE(980)] = 42421,
+  [SMALL_STATE(981)] = 42434,
+  [SMALL_STATE(982)] = 42447,
+  [SMALL_STATE(983)] = 42460,
+  [SMALL_STATE(984)] = 42471,
+  [SMALL_STATE(985)] = 42484,
+  [SMALL_STATE(986)] = 42497,
+  [SMALL_STATE(987)] = 42510,
+  [SMALL_STATE(988)] = 42523,
+  [SMALL_STATE(989)] = 42536,
+  [SMALL_STATE(990)] = 42549,
+  [SMALL_STATE(991)] = 42562,
+  [SMALL_STATE(992)] = 42575,
+  [SMALL_STATE(993)] = 42588,
+  [SMALL_STATE(994)] = 42601,
+  [SMALL_STATE(995)] = 42614,
+  [SMALL_STATE(996)] = 42627,
+  [SMALL_STATE(997)] = 42640,
+  [SMALL_STATE(998)] = 42653,
+  [SMALL_STATE(999)] = 42663,
+  [SMALL_STATE(1000)] = 42671,
+  [SMALL_STATE(1001)] = 42681,
+  [SMALL_STATE(1002)] = 42691,
+  [SMALL_STATE(1003)] = 42699,
+  [SMALL_STATE(1004)] = 42707,
+  [SMALL_STATE(1005)] = 42717,
+  [SMALL_STATE(1006)] = 42727,
+  [SMALL_STATE(1007)] = 42737,
+  [SMALL_STATE(1008)] = 42747,
+  [SMALL_STATE(1009)] = 42755,
+  [SMALL_STATE(1010)] = 42765,
+  [SMALL_STATE(1011)] = 42775,
+  [SMALL_STATE(1012)] = 42783,
+  [SMALL_STATE(1013)] = 42793,
+  [SMALL_STATE(1014)] = 42801,
+  [SMALL_STATE(1015)] = 42811,
+  [SMALL_STATE(1016)] = 42821,
+  [SMALL_STATE(1017)] = 42829,
+  [SMALL_STATE(1018)] = 42837,
+  [SMALL_STATE(1019)] = 42845,
+  [SMALL_STATE(1020)] = 42853,
+  [SMALL_STATE(1021)] = 42861,
+  [SMALL_STATE(1022)] = 42871,
+  [SMALL_STATE(1023)] = 42879,
+  [SMALL_STATE(1024)] = 42889,
+  [SMALL_STATE(1025)] = 42899,
+  [SMALL_STATE(1026)] = 42909,
+  [SMALL_STATE(1027)] = 42917,
+  [SMALL_STATE(1028)] = 42925,
+  [SMALL_STATE(1029)] = 42933,
+  [SMALL_STATE(1030)] = 42941,
+  [SMALL_STATE(1031)] = 42951,
+  [SMALL_STATE(1032)] = 42961,
+  [SMALL_STATE(1033)] = 42969,
+  [SMALL_STATE(1034)] = 42979,
+  [SMALL_STATE(1035)] = 42989,
+  [SMALL_STATE(1036)] = 42997,
+  [SMALL_STATE(1037)] = 43007,
+  [SMALL_STATE(1038)] = 43017,
+  [SMALL_STATE(1039)] = 43027,
+  [SMALL_STATE(1040)] = 43037,
+  [SMALL_STATE(1041)] = 43047,
+  [SMALL_STATE(1042)] = 43057,
+  [SMALL_STATE(1043)] = 43067,
+  [SMALL_STATE(1044)] = 43075,
+  [SMALL_STATE(1045)] = 43085,
+  [SMALL_STATE(1046)] = 43093,
+  [SMALL_STATE(1047)] = 43101,
+  [SMALL_STATE(1048)] = 43109,
+  [SMALL_STATE(1049)] = 43117,
+  [SMALL_STATE(1050)] = 43127,
+  [SMALL_STATE(1051)] = 43137,
+  [SMALL_STATE(1052)] = 43147,
+  [SMALL_STATE(1053)] = 43157,
+  [SMALL_STATE(1054)] = 43165,
+  [SMALL_STATE(1055)] = 43173,
+  [SMALL_STATE(1056)] = 43183,
+  [SMALL_STATE(1057)] = 43193,
+  [SMALL_STATE(1058)] = 43201,
+  [SMALL_STATE(1059)] = 43209,
+  [SMALL_STATE(1060)] = 43219,
+  [SMALL_STATE(1061)] = 43229,
+  [SMALL_STATE(1062)] = 43237,
+  [SMALL_STATE(1063)] = 43245,
+  [SMALL_STATE(1064)] = 43253,
+  [SMALL_STATE(1065)] = 43261,
+  [SMALL_STATE(1066)] = 43271,
+  [SMALL_STATE(1067)] = 43279,
+  [SMALL_STATE(1068)] = 43287,
+  [SMALL_STATE(1069)] = 43297,
+  [SMALL_STATE(1070)] = 43305,
+  [SMALL_STATE(1071)] = 43315,
+  [SMALL_STATE(1072)] = 43323,
+  [SMALL_STATE(1073)] = 43331,
+  [SMALL_STATE(1074)] = 43339,
+  [SMALL_STATE(1075)] = 43349,
+  [SMALL_STATE(1076)] = 43357,
+  [SMALL_STATE(1077)] = 43365,
+  [SMALL_STATE(1078)] = 43373,
+  [SMALL_STATE(1079)] = 43381,
+  [SMALL_STATE(1080)] = 43389,
+  [SMALL_STATE(1081)] = 43397,
+  [SMALL_STATE(1082)] = 43405,
+  [SMALL_STATE(1083)] = 43413,
+  [SMALL_STATE(1084)] = 43421,
+  [SMALL_STATE(1085)] = 43429,
+  [SMALL_STATE(1086)] = 43437,
+  [SMALL_STATE(1087)] = 43445,
+  [SMALL_STATE(1088)] = 43453,
+  [SMALL_STATE(1089)] = 43463,
+  [SMALL_STATE(1090)] = 43471,
+  [SMALL_STATE(1091)] = 43481,
+  [SMALL_STATE(1092)] = 43491,
+  [SMALL_STATE(1093)] = 43499,
+  [SMALL_STATE(1094)] = 43507,
+  [SMALL_STATE(1095)] = 43515,
+  [SMALL_STATE(1096)] = 43523,
+  [SMALL_STATE(1097)] = 43533,
+  [SMALL_STATE(1098)] = 43541,
+  [SMALL_STATE(1099)] = 43551,
+  [SMALL_STATE(1100)] = 43561,
+  [SMALL_STATE(1101)] = 43571,
+  [SMALL_STATE(1102)] = 43579,
+  [SMALL_STATE(1103)] = 43587,
+  [SMALL_STATE(1104)] = 43595,
+  [SMALL_STATE(1105)] = 43605,
+  [SMALL_STATE(1106)] = 43613,
+  [SMALL_STATE(1107)] = 43623,
+  [SMALL_STATE(1108)] = 43631,
+  [SMALL_STATE(1109)] = 43641,
+  [SMALL_STATE(1110)] = 43649,
+  [SMALL_STATE(1111)] = 43659,
+  [SMALL_STATE(1112)] = 43669,
+  [SMALL_STATE(1113)] = 43679,
+  [SMALL_STATE(1114)] = 43687,
+  [SMALL_STATE(1115)] = 43697,
+  [SMALL_STATE(1116)] = 43707,
+  [SMALL_STATE(1117)] = 43715,
+  [SMALL_STATE(1118)] = 43723,
+  [SMALL_STATE(1119)] = 43733,
+  [SMALL_STATE(1120)] = 43743,
+  [SMALL_STATE(1121)] = 43753,
+  [SMALL_STATE(1122)] = 43761,
+  [SMALL_STATE(1123)] = 43771,
+  [SMALL_STATE(1124)] = 43779,
+  [SMALL_STATE(1125)] = 43789,
+  [SMALL_STATE(1126)] = 43797,
+  [SMALL_STATE(1127)] = 43805,
+  [SMALL_STATE(1128)] = 43813,
+  [SMALL_STATE(1129)] = 43821,
+  [SMALL_STATE(1130)] = 43829,
+  [SMALL_STATE(1131)] = 43837,
+  [SMALL_STATE(1132)] = 43845,
+  [SMALL_STATE(1133)] = 43853,
+  [SMALL_STATE(1134)] = 43861,
+  [SMALL_STATE(1135)] = 43871,
+  [SMALL_STATE(1136)] = 43879,
+  [SMALL_STATE(1137)] = 43887,
+  [SMALL_STATE(1138)] = 43895,
+  [SMALL_STATE(1139)] = 43903,
+  [SMALL_STATE(1140)] = 43911,
+  [SMALL_STATE(1141)] = 43919,
+  [SMALL_STATE(1142)] = 43927,
+  [SMALL_STATE(1143)] = 43935,
+  [SMALL_STATE(1144)] = 43943,
+  [SMALL_STATE(1145)] = 43951,
+  [SMALL_STATE(1146)] = 43959,
+  [SMALL_STATE(1147)] = 43967,
+  [SMALL_STATE(1148)] = 43975,
+  [SMALL_STATE(1149)] = 43983,
+  [SMALL_STATE(1150)] = 43991,
+  [SMALL_STATE(1151)] = 43999,
+  [SMALL_STATE(1152)] = 44007,
+  [SMALL_STATE(1153)] = 44015,
+  [SMALL_STATE(1154)] = 44022,
+  [SMALL_STATE(1155)] = 44029,
+  [SMALL_STATE(1156)] = 44036,
+  [SMALL_STATE(1157)] = 44043,
+  [SMALL_STATE(1158)] = 44050,
+  [SMALL_STATE(1159)] = 44057,
+  [SMALL_STATE(1160)] = 44064,
+  [SMALL_STATE(1161)] = 44071,
+  [SMALL_STATE(1162)] = 44078,
+  [SMALL_STATE(1163)] = 44085,
+  [SMALL_STATE(1164)] = 44092,
+  [SMALL_STATE(1165)] = 44099,
+  [SMALL_STATE(1166)] = 44106,
+  [SMALL_STATE(1167)] = 44113,
+  [SMALL_STATE(1168)] = 44120,
+  [SMALL_STATE(1169)] = 44127,
+  [SMALL_STATE(1170)] = 44134,
+  [SMALL_STATE(1171)] = 44141,
+  [SMALL_STATE(1172)] = 44148,
+  [SMALL_STATE(1173)] = 44155,
+  [SMALL_STATE(1174)] = 44162,
+  [SMALL_STATE(1175)] = 44169,
+  [SMALL_STATE(1176)] = 44176,
+  [SMALL_STATE(1177)] = 44183,
+  [SMALL_STATE(1178)] = 44190,
+  [SMALL_STATE(1179)] = 44197,
+  [SMALL_STATE(1180)] = 44204,
+  [SMALL_STATE(1181)] = 44211,
+  [SMALL_STATE(1182)] = 44218,
+  [SMALL_STATE(1183)] = 44225,
+  [SMALL_STATE(1184)] = 44232,
+  [SMALL_STATE(1185)] = 44239,
+  [SMALL_STATE(1186)] = 44246,
+  [SMALL_STATE(1187)] = 44253,
+  [SMALL_STATE(1188)] = 44260,
+  [SMALL_STATE(1189)] = 44267,
+  [SMALL_STATE(1190)] = 44274,
+  [SMALL_STATE(1191)] = 44281,
+  [SMALL_STATE(1192)] = 44288,
+  [SMALL_STATE(1193)] = 44295,
+  [SMALL_STATE(1194)] = 44302,
+  [SMALL_STATE(1195)] = 44309,
+  [SMALL_STATE(1196)] = 44316,
+  [SMALL_STATE(1197)] = 44323,
+  [SMALL_STATE(1198)] = 44330,
+  [SMALL_STATE(1199)] = 44337,
+  [SMALL_STATE(1200)] = 44344,
+  [SMALL_STATE(1201)] = 44351,
+  [SMALL_STATE(1202)] = 44358,
+  [SMALL_STATE(1203)] = 44365,
+  [SMALL_STATE(1204)] = 44372,
+  [SMALL_STATE(1205)] = 44379,
+  [SMALL_STATE(1206)] = 44386,
+  [SMALL_STATE(1207)] = 44393,
+  [SMALL_STATE(1208)] = 44400,
+  [SMALL_STATE(1209)] = 44407,
+  [SMALL_STATE(1210)] = 44414,
+  [SMALL_STATE(1211)] = 44421,
+  [SMALL_STATE(1212)] = 44428,
+  [SMALL_STATE(1213)] = 44435,
+  [SMALL_STATE(1214)] = 44442,
+  [SMALL_STATE(1215)] = 44449,
+  [SMALL_STATE(1216)] = 44456,
+  [SMALL_STATE(1217)] = 44463,
+  [SMALL_STATE(1218)] = 44473,
+  [SMALL_STATE(1219)] = 44480,
+  [SMALL_STATE(1220)] = 44493,
+  [SMALL_STATE(1221)] = 44506,
+  [SMALL_STATE(1222)] = 44519,
+  [SMALL_STATE(1223)] = 44537,
+  [SMALL_STATE(1224)] = 44555,
+  [SMALL_STATE(1225)] = 44565,
+  [SMALL_STATE(1226)] = 44572,
+  [SMALL_STATE(1227)] = 44585,
+  [SMALL_STATE(1228)] = 44598,
+  [SMALL_STATE(1229)] = 44611,
+  [SMALL_STATE(1230)] = 44619,
 };
 
 static TSParseActionEntry ts_parse_actions[] = {
@@ -41005,6 +41176,18 @@ static TSParseActionEntry ts_parse_actions[] = {
   [2598] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_signature, 8, .production_id = 166),
   [2600] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1007),
   [2602] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1004),
+  [2604] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1219),
+  [2606] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1222),
+  [2608] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1223),
+  [2610] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_data, 6, .production_id = 193),
+  [2612] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_data, 6, .production_id = 193),
+  [2614] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_data, 7, .production_id = 194),
+  [2616] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_data, 7, .production_id = 194),
+  [2618] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1226),
+  [2620] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1229),
+  [2622] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1230),
+  [2624] = {.entry = {.count = 1, .reusable = false}}, SHIFT(1217),
+  [2626] = {.entry = {.count = 1, .reusable = false}}, SHIFT(1224),
 };
 
 #ifdef __cplusplus